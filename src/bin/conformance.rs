@@ -0,0 +1,44 @@
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use typeshell::harness::conformance::{run_conformance, ConformanceOptions};
+
+const USAGE: &str = "usage: conformance [--accept] [--cases <dir>] [--reference <dir>] [--local <dir>]";
+
+fn main() {
+    let mut options = ConformanceOptions::default();
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        let directory = match arg.as_str() {
+            "--accept" => {
+                options.accept = true;
+                continue;
+            }
+            "--cases" => &mut options.cases,
+            "--reference" => &mut options.reference,
+            "--local" => &mut options.local,
+            _ => {
+                println!("unknown option {}\n{}", arg, USAGE);
+                process::exit(1);
+            }
+        };
+        match iter.next() {
+            Some(value) => *directory = PathBuf::from(value),
+            None => {
+                println!("{}", USAGE);
+                process::exit(1);
+            }
+        }
+    }
+    let summary = match run_conformance(&options) {
+        Ok(summary) => summary,
+        Err(e) => {
+            println!("unable to run the conformance cases: {}", e);
+            process::exit(1);
+        }
+    };
+    print!("{}", summary.report());
+    if !summary.is_success() {
+        process::exit(1);
+    }
+}
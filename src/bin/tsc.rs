@@ -1,24 +1,31 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::process;
 use std::{env, fs};
-use typeshell::compiler::scanner::Scanner;
+use typeshell::compiler::checker::{create_checker, CheckerOptions};
+use typeshell::compiler::dump::{program_diagnostics_to_json, source_file_to_json, tokens_to_json};
+use typeshell::compiler::grammar::check_grammar;
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::scanner::{compute_line_starts, get_line_and_character_of_position, Scanner};
+use typeshell::compiler::syntax::SyntaxNode;
 use typeshell::compiler::types::*;
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let file = match args.get(1) {
-        Some(arg) => arg,
-        None => {
-            println!("missing file");
-            return;
-        }
-    };
-    let src = match fs::read_to_string(Path::new(file)) {
-        Ok(src) => src,
-        Err(e) => {
-            println!("unable to read file: {}", e);
-            return;
-        }
-    };
-    let mut s = Scanner::create_scanner(src.as_str());
+
+const USAGE: &str = "usage: tsc [--tokens | --ast] [--format text|json] [--noUnusedLocals] [--noUnusedParameters]
+           [--noUnusedImports] [--noUnusedPrivateMembers] [--noWriteOnlyVariables] <file>...";
+
+enum Mode {
+    Check,
+    Tokens,
+    Ast,
+}
+
+enum Format {
+    Text,
+    Json,
+}
+
+fn print_tokens(src: &str) {
+    let mut s = Scanner::create_scanner(src);
     while s.get_token() != SyntaxKind::EndOfFileToken {
         s.scan();
         println!(
@@ -29,3 +36,113 @@ fn main() {
         );
     }
 }
+
+fn print_tree(node: &SyntaxNode, depth: usize) {
+    if node.is_token() {
+        println!("{}{:?} {:?}", "  ".repeat(depth), node, node.token_text());
+        return;
+    }
+    println!("{}{:?}", "  ".repeat(depth), node);
+    for child in node.children() {
+        print_tree(&child, depth + 1);
+    }
+}
+
+fn print_diagnostics(line_starts: &HashMap<String, Vec<usize>>, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        let (line, character) =
+            get_line_and_character_of_position(&line_starts[&diagnostic.file_name], diagnostic.start);
+        let category = match diagnostic.category {
+            DiagnosticCategory::Warning => "warning",
+            DiagnosticCategory::Error => "error",
+            DiagnosticCategory::Message => "message",
+        };
+        println!(
+            "{}({},{}): {} TS{}: {}",
+            diagnostic.file_name, line, character, category, diagnostic.code, diagnostic.message_text
+        );
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let mut mode = Mode::Check;
+    let mut format = Format::Text;
+    let mut options = CheckerOptions::default();
+    let mut files = Vec::new();
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--tokens" => mode = Mode::Tokens,
+            "--ast" => mode = Mode::Ast,
+            "--noUnusedLocals" => options.no_unused_locals = true,
+            "--noUnusedParameters" => options.no_unused_parameters = true,
+            "--noUnusedImports" => options.no_unused_imports = true,
+            "--noUnusedPrivateMembers" => options.no_unused_private_members = true,
+            "--noWriteOnlyVariables" => options.no_write_only_variables = true,
+            "--format" => {
+                format = match iter.next().map(|value| value.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => {
+                        println!("{}", USAGE);
+                        process::exit(1);
+                    }
+                }
+            }
+            _ if arg.starts_with("--") => {
+                println!("unknown option {}\n{}", arg, USAGE);
+                process::exit(1);
+            }
+            _ => files.push(arg),
+        }
+    }
+    if files.is_empty() {
+        println!("missing file");
+        return;
+    }
+    // Dumps describe one file, errors included, they succeed even when it has errors
+    if !matches!(mode, Mode::Check) && files.len() > 1 {
+        println!("--tokens and --ast take one file\n{}", USAGE);
+        process::exit(1);
+    }
+    let mut source_files = Vec::new();
+    let mut line_starts = HashMap::new();
+    for file in files {
+        let src = match fs::read_to_string(Path::new(file)) {
+            Ok(src) => src,
+            Err(e) => {
+                println!("unable to read file: {}", e);
+                return;
+            }
+        };
+        match (&mode, &format) {
+            (Mode::Tokens, Format::Json) => return print!("{}", tokens_to_json(file, &src)),
+            (Mode::Tokens, Format::Text) => return print_tokens(&src),
+            (Mode::Ast, Format::Json) => return print!("{}", source_file_to_json(&create_source_file(file, &src))),
+            (Mode::Ast, Format::Text) => return print_tree(&create_source_file(file, &src).root, 0),
+            (Mode::Check, _) => {}
+        }
+        line_starts.insert(file.clone(), compute_line_starts(&src));
+        source_files.push(create_source_file(file, &src));
+    }
+    // The files are checked together, the global declarations of each merge with those of the others
+    let mut diagnostics = Vec::new();
+    for source_file in &source_files {
+        diagnostics.extend_from_slice(&source_file.parse_diagnostics);
+        diagnostics.extend(check_grammar(source_file));
+    }
+    diagnostics.extend_from_slice(create_checker(&source_files, &options).get_diagnostics());
+    sort_and_deduplicate_diagnostics(&mut diagnostics);
+    match format {
+        Format::Json => print!("{}", program_diagnostics_to_json(&source_files, &diagnostics)),
+        Format::Text => print_diagnostics(&line_starts, &diagnostics),
+    }
+    // Warnings are reported without failing the run
+    if diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.category == DiagnosticCategory::Error)
+    {
+        process::exit(1);
+    }
+}
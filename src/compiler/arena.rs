@@ -0,0 +1,213 @@
+use super::scanner::sub_str;
+use super::syntax::{GreenNode, SyntaxNode};
+use super::types::{SourceFile, SyntaxKind};
+
+// A flat copy of the syntax tree of a file for tooling that asks many questions about the same file.
+// Nodes are numbered in source order and keep their parent, so walking up or finding the node at a
+// position allocates nothing, and dropping the arena frees the whole tree at once.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        return self.0 as usize;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ArenaNode {
+    kind: SyntaxKind,
+    is_token: bool,
+    pos: usize,       // Start position of the trivia before the node
+    token_pos: usize, // Start position of the first token of the node
+    end: usize,
+    parent: Option<NodeId>,
+    index: usize,       // Index in the children of parent
+    first_child: usize, // Index of the first child in NodeArena::children
+    child_count: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct NodeArena {
+    text: String,
+    nodes: Vec<ArenaNode>,
+    children: Vec<NodeId>, // Children of each node, contiguous
+}
+
+impl NodeArena {
+    pub fn new(file: &SourceFile) -> Self {
+        let mut arena = NodeArena {
+            text: file.text.clone(),
+            nodes: Vec::new(),
+            children: Vec::new(),
+        };
+        arena.add(file.root.green(), None, 0, file.root.pos());
+        return arena;
+    }
+
+    fn add(&mut self, green: &GreenNode, parent: Option<NodeId>, index: usize, pos: usize) -> NodeId {
+        let id = NodeId(self.nodes.len() as u32);
+        let first_child = self.children.len();
+        let child_count = green.children().len();
+        self.nodes.push(ArenaNode {
+            kind: green.kind(),
+            is_token: green.is_token(),
+            pos,
+            token_pos: pos + green.leading_trivia_width(),
+            end: pos + green.width(),
+            parent,
+            index,
+            first_child,
+            child_count,
+        });
+        // Reserved here so the children of a node stay together, filled in as they are added
+        self.children.resize(first_child + child_count, id);
+        let mut child_pos = pos;
+        for (child_index, child) in green.children().iter().enumerate() {
+            self.children[first_child + child_index] = self.add(child, Some(id), child_index, child_pos);
+            child_pos += child.width();
+        }
+        return id;
+    }
+
+    fn node(&self, id: NodeId) -> &ArenaNode {
+        return &self.nodes[id.index()];
+    }
+
+    // The SourceFile node
+    pub fn root(&self) -> NodeId {
+        return NodeId(0);
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    pub fn kind(&self, id: NodeId) -> SyntaxKind {
+        return self.node(id).kind;
+    }
+
+    pub fn is_token(&self, id: NodeId) -> bool {
+        return self.node(id).is_token;
+    }
+
+    pub fn pos(&self, id: NodeId) -> usize {
+        return self.node(id).pos;
+    }
+
+    pub fn token_pos(&self, id: NodeId) -> usize {
+        return self.node(id).token_pos;
+    }
+
+    pub fn end(&self, id: NodeId) -> usize {
+        return self.node(id).end;
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        return self.node(id).parent;
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        let node = self.node(id);
+        return &self.children[node.first_child..node.first_child + node.child_count];
+    }
+
+    // Parent, grandparent... up to the root
+    pub fn ancestors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        return std::iter::successors(self.parent(id), move |&ancestor| self.parent(ancestor));
+    }
+
+    // Text of the node without the trivia before it
+    pub fn node_text(&self, id: NodeId) -> String {
+        let node = self.node(id);
+        return sub_str(self.text.as_bytes(), node.token_pos, node.end);
+    }
+
+    // The child of id that holds position, counting the trivia before the child when with_trivia is set
+    fn child_at(&self, id: NodeId, position: usize, with_trivia: bool) -> Option<NodeId> {
+        let children = self.children(id);
+        // Children are in source order, empty ones never cover a position
+        let index = children.partition_point(|&child| self.end(child) <= position);
+        let child = *children.get(index)?;
+        let start = if with_trivia {
+            self.pos(child)
+        } else {
+            self.token_pos(child)
+        };
+        if start <= position {
+            return Some(child);
+        }
+        return None;
+    }
+
+    // The token whose text or the trivia before it holds position, the end of the text belongs to
+    // the EndOfFileToken
+    pub fn find_token_at(&self, position: usize) -> Option<NodeId> {
+        if position == self.text.len() {
+            return self
+                .children(self.root())
+                .last()
+                .copied()
+                .filter(|&last| self.kind(last) == SyntaxKind::EndOfFileToken);
+        }
+        let mut id = self.root();
+        while !self.is_token(id) {
+            id = self.child_at(id, position, true)?;
+        }
+        return Some(id);
+    }
+
+    // The deepest node other than a token or list that holds position, the trivia before a node is
+    // not part of it
+    pub fn find_innermost_node_at(&self, position: usize) -> Option<NodeId> {
+        if position > self.text.len() {
+            return None;
+        }
+        let mut innermost = self.root();
+        let mut id = innermost;
+        while let Some(child) = self.child_at(id, position, false) {
+            if self.is_token(child) {
+                break;
+            }
+            if self.kind(child) != SyntaxKind::SyntaxList {
+                innermost = child;
+            }
+            id = child;
+        }
+        return Some(innermost);
+    }
+
+    // The id of a node of the tree the arena was built from
+    pub fn find_node(&self, node: &SyntaxNode) -> Option<NodeId> {
+        let mut path = Vec::new();
+        let mut current = node.clone();
+        while let Some(parent) = current.parent() {
+            path.push(current.index());
+            current = parent;
+        }
+        let mut id = self.root();
+        for &index in path.iter().rev() {
+            id = *self.children(id).get(index)?;
+        }
+        if self.kind(id) != node.kind() || self.pos(id) != node.pos() || self.end(id) != node.end() {
+            return None;
+        }
+        return Some(id);
+    }
+
+    // The node of the tree rooted at root for id, root has to be the tree the arena was built from
+    pub fn to_syntax_node(&self, root: &SyntaxNode, id: NodeId) -> Option<SyntaxNode> {
+        let mut path = vec![id];
+        path.extend(self.ancestors(id));
+        let mut node = root.clone();
+        for &ancestor in path.iter().rev().skip(1) {
+            node = node.child(self.node(ancestor).index)?;
+        }
+        return Some(node);
+    }
+}
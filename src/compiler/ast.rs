@@ -0,0 +1,1186 @@
+use super::syntax::{SyntaxNode, SyntaxNodeChildren};
+use super::types::{SourceFile as ParsedSourceFile, SyntaxKind};
+use std::marker::PhantomData;
+
+// Typed views over the nodes produced by the parser. A view wraps a node of a known kind, its
+// accessors locate children by the tokens around them.
+pub trait AstNode: Clone {
+    fn can_cast(kind: SyntaxKind) -> bool;
+    fn cast(syntax: SyntaxNode) -> Option<Self>;
+    fn syntax(&self) -> &SyntaxNode;
+
+    fn kind(&self) -> SyntaxKind {
+        return self.syntax().kind();
+    }
+
+    // Start position of the trivia before the node
+    fn pos(&self) -> usize {
+        return self.syntax().pos();
+    }
+
+    // Start position of the first token of the node
+    fn token_pos(&self) -> usize {
+        return self.syntax().token_pos();
+    }
+
+    fn end(&self) -> usize {
+        return self.syntax().end();
+    }
+}
+
+macro_rules! ast_node {
+    ($name:ident, $($kind:ident)|+) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name {
+            syntax: SyntaxNode,
+        }
+
+        impl AstNode for $name {
+            fn can_cast(kind: SyntaxKind) -> bool {
+                return matches!(kind, $(SyntaxKind::$kind)|+);
+            }
+
+            fn cast(syntax: SyntaxNode) -> Option<Self> {
+                if Self::can_cast(syntax.kind()) {
+                    return Some($name { syntax });
+                }
+                return None;
+            }
+
+            fn syntax(&self) -> &SyntaxNode {
+                return &self.syntax;
+            }
+        }
+    };
+}
+
+macro_rules! ast_enum {
+    ($name:ident { $($variant:ident),+ $(,)? }) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant($variant)),+
+        }
+
+        impl AstNode for $name {
+            fn can_cast(kind: SyntaxKind) -> bool {
+                return $($variant::can_cast(kind))||+;
+            }
+
+            fn cast(syntax: SyntaxNode) -> Option<Self> {
+                $(if $variant::can_cast(syntax.kind()) {
+                    return $variant::cast(syntax).map($name::$variant);
+                })+
+                return None;
+            }
+
+            fn syntax(&self) -> &SyntaxNode {
+                match self {
+                    $($name::$variant(node) => node.syntax()),+
+                }
+            }
+        }
+    };
+}
+
+// Iterates the children of a node or a SyntaxList that can be cast to T, skipping separators.
+#[derive(Debug, Clone)]
+pub struct AstChildren<T> {
+    inner: Option<SyntaxNodeChildren>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: AstNode> AstChildren<T> {
+    fn new(parent: Option<&SyntaxNode>) -> Self {
+        return AstChildren {
+            inner: parent.map(|parent| parent.children()),
+            phantom: PhantomData,
+        };
+    }
+}
+
+impl<T: AstNode> Iterator for AstChildren<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        return self.inner.as_mut()?.find_map(T::cast);
+    }
+}
+
+fn token(parent: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
+    return parent.children().find(|child| child.kind() == kind);
+}
+
+fn child<T: AstNode>(parent: &SyntaxNode) -> Option<T> {
+    return parent.children().find_map(T::cast);
+}
+
+// The child right after the nth token of kind
+fn nth_child_after<T: AstNode>(parent: &SyntaxNode, kind: SyntaxKind, n: usize) -> Option<T> {
+    let found = parent.children().filter(|child| child.kind() == kind).nth(n)?;
+    return found.next_sibling().and_then(T::cast);
+}
+
+fn child_after<T: AstNode>(parent: &SyntaxNode, kind: SyntaxKind) -> Option<T> {
+    return nth_child_after(parent, kind, 0);
+}
+
+// Elements of the SyntaxList right after the first token of kind
+fn list_after<T: AstNode>(parent: &SyntaxNode, kind: SyntaxKind) -> AstChildren<T> {
+    let list = parent
+        .children()
+        .skip_while(|child| child.kind() != kind)
+        .nth(1)
+        .filter(|child| child.kind() == SyntaxKind::SyntaxList);
+    return AstChildren::new(list.as_ref());
+}
+
+// Elements of the first SyntaxList
+fn first_list<T: AstNode>(parent: &SyntaxNode) -> AstChildren<T> {
+    let list = parent.children().find(|child| child.kind() == SyntaxKind::SyntaxList);
+    return AstChildren::new(list.as_ref());
+}
+
+pub fn is_modifier(kind: SyntaxKind) -> bool {
+    return matches!(
+        kind,
+        SyntaxKind::ExportKeyword
+            | SyntaxKind::DeclareKeyword
+            | SyntaxKind::PublicKeyword
+            | SyntaxKind::PrivateKeyword
+            | SyntaxKind::ProtectedKeyword
+            | SyntaxKind::StaticKeyword
+    );
+}
+
+pub trait ModifiersOwner: AstNode {
+    // Modifier tokens in front of the declaration
+    fn modifiers(&self) -> Vec<SyntaxNode> {
+        return self
+            .syntax()
+            .children()
+            .take_while(|child| is_modifier(child.kind()))
+            .collect();
+    }
+
+    fn has_modifier(&self, kind: SyntaxKind) -> bool {
+        return self.modifiers().iter().any(|modifier| modifier.kind() == kind);
+    }
+}
+
+// Declarations with `<T>(parameters): type`
+pub trait SignatureDeclaration: AstNode {
+    fn type_parameters(&self) -> AstChildren<TypeParameter> {
+        return list_after(self.syntax(), SyntaxKind::LessThanToken);
+    }
+
+    fn parameters(&self) -> AstChildren<Parameter> {
+        return list_after(self.syntax(), SyntaxKind::OpenParenToken);
+    }
+
+    fn return_type(&self) -> Option<TypeNode> {
+        return child_after(self.syntax(), SyntaxKind::ColonToken);
+    }
+}
+
+// Names
+ast_node!(Identifier, Identifier);
+ast_node!(QualifiedName, QualifiedName);
+ast_enum!(EntityName {
+    Identifier,
+    QualifiedName
+});
+ast_enum!(PropertyName {
+    Identifier,
+    LiteralExpression
+});
+
+impl Identifier {
+    pub fn text(&self) -> &str {
+        return self.syntax.value();
+    }
+}
+
+impl QualifiedName {
+    pub fn left(&self) -> Option<EntityName> {
+        return child(&self.syntax);
+    }
+
+    pub fn right(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::DotToken);
+    }
+}
+
+// Signature elements
+ast_node!(TypeParameter, TypeParameter);
+ast_node!(Parameter, Parameter);
+
+impl TypeParameter {
+    pub fn name(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+
+    pub fn constraint(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ExtendsKeyword);
+    }
+}
+
+impl ModifiersOwner for Parameter {}
+
+impl Parameter {
+    pub fn dot_dot_dot_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::DotDotDotToken);
+    }
+
+    pub fn name(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+
+    pub fn question_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::QuestionToken);
+    }
+
+    pub fn type_annotation(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+
+    pub fn initializer(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+}
+
+// Type members and class elements
+ast_node!(Property, Property);
+ast_node!(Method, Method);
+ast_node!(Constructor, Constructor);
+ast_node!(GetAccessor, GetAccessor);
+ast_node!(SetAccessor, SetAccessor);
+ast_node!(CallSignature, CallSignature);
+ast_node!(ConstructSignature, ConstructSignature);
+ast_node!(IndexSignature, IndexSignature);
+ast_enum!(ClassElement {
+    Property,
+    Method,
+    Constructor,
+    GetAccessor,
+    SetAccessor,
+    IndexSignature,
+});
+ast_enum!(TypeMember {
+    Property,
+    Method,
+    CallSignature,
+    ConstructSignature,
+    IndexSignature,
+});
+
+impl ModifiersOwner for Property {}
+
+impl Property {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child(&self.syntax);
+    }
+
+    pub fn question_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::QuestionToken);
+    }
+
+    pub fn type_annotation(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+
+    pub fn initializer(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+}
+
+impl ModifiersOwner for Method {}
+impl SignatureDeclaration for Method {}
+
+impl Method {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child(&self.syntax);
+    }
+
+    pub fn question_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::QuestionToken);
+    }
+
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for Constructor {}
+impl SignatureDeclaration for Constructor {}
+
+impl Constructor {
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for GetAccessor {}
+impl SignatureDeclaration for GetAccessor {}
+
+impl GetAccessor {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child_after(&self.syntax, SyntaxKind::GetKeyword);
+    }
+
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for SetAccessor {}
+impl SignatureDeclaration for SetAccessor {}
+
+impl SetAccessor {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child_after(&self.syntax, SyntaxKind::SetKeyword);
+    }
+
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+// The signatures of function and constructor types, `(a: A) => R`, have `=>` in place of `:`
+impl SignatureDeclaration for CallSignature {
+    fn return_type(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken)
+            .or_else(|| child_after(&self.syntax, SyntaxKind::EqualsGreaterThanToken));
+    }
+}
+
+impl SignatureDeclaration for ConstructSignature {
+    fn return_type(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken)
+            .or_else(|| child_after(&self.syntax, SyntaxKind::EqualsGreaterThanToken));
+    }
+}
+
+impl ModifiersOwner for IndexSignature {}
+
+impl IndexSignature {
+    pub fn parameters(&self) -> AstChildren<Parameter> {
+        return list_after(&self.syntax, SyntaxKind::OpenBracketToken);
+    }
+
+    pub fn type_annotation(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+}
+
+// Types
+ast_node!(
+    KeywordType,
+    AnyKeyword | StringKeyword | NumberKeyword | BooleanKeyword | VoidKeyword
+);
+ast_node!(TypeReference, TypeReference);
+ast_node!(TypeQuery, TypeQuery);
+ast_node!(TypeLiteral, TypeLiteral);
+ast_node!(ArrayType, ArrayType);
+ast_enum!(TypeNode {
+    KeywordType,
+    TypeReference,
+    TypeQuery,
+    TypeLiteral,
+    ArrayType,
+});
+
+impl TypeReference {
+    pub fn type_name(&self) -> Option<EntityName> {
+        return child(&self.syntax);
+    }
+
+    pub fn type_arguments(&self) -> AstChildren<TypeNode> {
+        return list_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+}
+
+impl TypeQuery {
+    pub fn expr_name(&self) -> Option<EntityName> {
+        return child_after(&self.syntax, SyntaxKind::TypeOfKeyword);
+    }
+}
+
+impl TypeLiteral {
+    // The signature of a function or constructor type is the only member
+    pub fn members(&self) -> AstChildren<TypeMember> {
+        if self.is_function_type() {
+            return AstChildren::new(Some(&self.syntax));
+        }
+        return list_after(&self.syntax, SyntaxKind::OpenBraceToken);
+    }
+
+    // `(a: A) => R` or `new (a: A) => R`, written without braces
+    pub fn is_function_type(&self) -> bool {
+        return token(&self.syntax, SyntaxKind::OpenBraceToken).is_none();
+    }
+}
+
+impl ArrayType {
+    pub fn element_type(&self) -> Option<TypeNode> {
+        return child(&self.syntax);
+    }
+}
+
+// Expressions
+ast_node!(
+    LiteralExpression,
+    NumericLiteral | StringLiteral | RegularExpressionLiteral | TrueKeyword | FalseKeyword | NullKeyword
+);
+ast_node!(ThisExpression, ThisKeyword);
+ast_node!(SuperExpression, SuperKeyword);
+ast_node!(ArrayLiteral, ArrayLiteral);
+ast_node!(ObjectLiteral, ObjectLiteral);
+ast_node!(PropertyAssignment, PropertyAssignment);
+ast_node!(PropertyAccess, PropertyAccess);
+ast_node!(IndexedAccess, IndexedAccess);
+ast_node!(CallExpression, CallExpression);
+ast_node!(NewExpression, NewExpression);
+ast_node!(TypeAssertion, TypeAssertion);
+ast_node!(ParenExpression, ParenExpression);
+ast_node!(FunctionExpression, FunctionExpression);
+ast_node!(ArrowFunction, ArrowFunction);
+ast_node!(PrefixOperator, PrefixOperator);
+ast_node!(PostfixOperator, PostfixOperator);
+ast_node!(BinaryExpression, BinaryExpression);
+ast_node!(ConditionalExpression, ConditionalExpression);
+ast_node!(OmittedExpression, OmittedExpression);
+ast_enum!(Expression {
+    Identifier,
+    LiteralExpression,
+    ThisExpression,
+    SuperExpression,
+    ArrayLiteral,
+    ObjectLiteral,
+    PropertyAccess,
+    IndexedAccess,
+    CallExpression,
+    NewExpression,
+    TypeAssertion,
+    ParenExpression,
+    FunctionExpression,
+    ArrowFunction,
+    PrefixOperator,
+    PostfixOperator,
+    BinaryExpression,
+    ConditionalExpression,
+    OmittedExpression,
+});
+ast_enum!(ObjectLiteralElement {
+    PropertyAssignment,
+    GetAccessor,
+    SetAccessor,
+});
+ast_enum!(ArrowFunctionBody {
+    FunctionBlock,
+    Expression
+});
+
+impl LiteralExpression {
+    // Value of numeric, string and regular expression literals
+    pub fn value(&self) -> &str {
+        return self.syntax.value();
+    }
+}
+
+impl ArrayLiteral {
+    pub fn elements(&self) -> AstChildren<Expression> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ObjectLiteral {
+    pub fn properties(&self) -> AstChildren<ObjectLiteralElement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl PropertyAssignment {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child(&self.syntax);
+    }
+
+    // Method shorthands `m() { }` have a FunctionExpression initializer
+    pub fn initializer(&self) -> Option<Expression> {
+        return self.syntax.children().skip(1).find_map(Expression::cast);
+    }
+}
+
+impl PropertyAccess {
+    pub fn expression(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::DotToken);
+    }
+}
+
+impl IndexedAccess {
+    pub fn object(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+
+    pub fn index(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenBracketToken);
+    }
+}
+
+impl CallExpression {
+    pub fn expression(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+
+    pub fn type_arguments(&self) -> AstChildren<TypeNode> {
+        return list_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+
+    pub fn arguments(&self) -> AstChildren<Expression> {
+        return list_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl NewExpression {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::NewKeyword);
+    }
+
+    pub fn type_arguments(&self) -> AstChildren<TypeNode> {
+        return list_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+
+    pub fn arguments(&self) -> AstChildren<Expression> {
+        return list_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl TypeAssertion {
+    pub fn type_node(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+
+    pub fn operand(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::GreaterThanToken);
+    }
+}
+
+impl ParenExpression {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl SignatureDeclaration for FunctionExpression {}
+
+impl FunctionExpression {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::FunctionKeyword);
+    }
+
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl SignatureDeclaration for ArrowFunction {
+    // `x => x` has a single parameter without parentheses
+    fn parameters(&self) -> AstChildren<Parameter> {
+        if token(&self.syntax, SyntaxKind::OpenParenToken).is_none() {
+            return AstChildren::new(Some(&self.syntax));
+        }
+        return list_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl ArrowFunction {
+    pub fn body(&self) -> Option<ArrowFunctionBody> {
+        return child_after(&self.syntax, SyntaxKind::EqualsGreaterThanToken);
+    }
+}
+
+impl PrefixOperator {
+    pub fn operator(&self) -> SyntaxKind {
+        return self.syntax.green().children()[0].kind();
+    }
+
+    pub fn operand(&self) -> Option<Expression> {
+        return self.syntax.child(1).and_then(Expression::cast);
+    }
+}
+
+impl PostfixOperator {
+    pub fn operand(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+
+    pub fn operator(&self) -> SyntaxKind {
+        return self.syntax.green().children()[self.syntax.child_count() - 1].kind();
+    }
+}
+
+impl BinaryExpression {
+    pub fn left(&self) -> Option<Expression> {
+        return self.syntax.first_child().and_then(Expression::cast);
+    }
+
+    pub fn operator(&self) -> SyntaxKind {
+        return self.syntax.green().children()[1].kind();
+    }
+
+    pub fn right(&self) -> Option<Expression> {
+        return self.syntax.child(2).and_then(Expression::cast);
+    }
+}
+
+impl ConditionalExpression {
+    pub fn condition(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+
+    pub fn when_true(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::QuestionToken);
+    }
+
+    pub fn when_false(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+}
+
+// Statements
+ast_node!(Block, Block);
+ast_node!(VariableStatement, VariableStatement);
+ast_node!(EmptyStatement, EmptyStatement);
+ast_node!(ExpressionStatement, ExpressionStatement);
+ast_node!(IfStatement, IfStatement);
+ast_node!(DoStatement, DoStatement);
+ast_node!(WhileStatement, WhileStatement);
+ast_node!(ForStatement, ForStatement);
+ast_node!(ForInStatement, ForInStatement);
+ast_node!(ContinueStatement, ContinueStatement);
+ast_node!(BreakStatement, BreakStatement);
+ast_node!(ReturnStatement, ReturnStatement);
+ast_node!(WithStatement, WithStatement);
+ast_node!(SwitchStatement, SwitchStatement);
+ast_node!(CaseClause, CaseClause);
+ast_node!(DefaultClause, DefaultClause);
+ast_node!(LabelledStatement, LabelledStatement);
+ast_node!(ThrowStatement, ThrowStatement);
+ast_node!(TryStatement, TryStatement);
+ast_node!(TryBlock, TryBlock);
+ast_node!(CatchBlock, CatchBlock);
+ast_node!(FinallyBlock, FinallyBlock);
+ast_node!(DebuggerStatement, DebuggerStatement);
+ast_node!(VariableDeclaration, VariableDeclaration);
+ast_node!(FunctionDeclaration, FunctionDeclaration);
+ast_node!(FunctionBlock, FunctionBlock);
+ast_node!(ClassDeclaration, ClassDeclaration);
+ast_node!(InterfaceDeclaration, InterfaceDeclaration);
+ast_node!(EnumDeclaration, EnumDeclaration);
+ast_node!(ModuleDeclaration, ModuleDeclaration);
+ast_node!(ModuleBlock, ModuleBlock);
+ast_node!(ImportDeclaration, ImportDeclaration);
+ast_node!(ExportAssignment, ExportAssignment);
+ast_node!(ImportFromDeclaration, ImportFromDeclaration);
+ast_node!(ImportClause, ImportClause);
+ast_node!(NamespaceImport, NamespaceImport);
+ast_node!(NamedImports, NamedImports);
+ast_node!(ImportSpecifier, ImportSpecifier);
+ast_node!(ExportDeclaration, ExportDeclaration);
+ast_node!(NamedExports, NamedExports);
+ast_node!(ExportSpecifier, ExportSpecifier);
+ast_node!(EnumMember, EnumMember);
+ast_node!(SourceFile, SourceFile);
+ast_enum!(Statement {
+    Block,
+    VariableStatement,
+    EmptyStatement,
+    ExpressionStatement,
+    IfStatement,
+    DoStatement,
+    WhileStatement,
+    ForStatement,
+    ForInStatement,
+    ContinueStatement,
+    BreakStatement,
+    ReturnStatement,
+    WithStatement,
+    SwitchStatement,
+    LabelledStatement,
+    ThrowStatement,
+    TryStatement,
+    DebuggerStatement,
+    FunctionDeclaration,
+    ClassDeclaration,
+    InterfaceDeclaration,
+    EnumDeclaration,
+    ModuleDeclaration,
+    ImportDeclaration,
+    ExportAssignment,
+    ImportFromDeclaration,
+    ExportDeclaration,
+});
+ast_enum!(CaseOrDefaultClause {
+    CaseClause,
+    DefaultClause
+});
+ast_enum!(ModuleBody {
+    ModuleBlock,
+    ModuleDeclaration
+});
+ast_enum!(NamedImportBindings {
+    NamespaceImport,
+    NamedImports
+});
+ast_enum!(ModuleName {
+    Identifier,
+    LiteralExpression
+});
+
+impl Block {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for VariableStatement {}
+
+impl VariableStatement {
+    pub fn declarations(&self) -> AstChildren<VariableDeclaration> {
+        return list_after(&self.syntax, SyntaxKind::VarKeyword);
+    }
+}
+
+impl ExpressionStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child(&self.syntax);
+    }
+}
+
+impl IfStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn then_statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::CloseParenToken);
+    }
+
+    pub fn else_statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::ElseKeyword);
+    }
+}
+
+impl DoStatement {
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::DoKeyword);
+    }
+
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl WhileStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::CloseParenToken);
+    }
+}
+
+impl ForStatement {
+    pub fn declarations(&self) -> AstChildren<VariableDeclaration> {
+        return list_after(&self.syntax, SyntaxKind::VarKeyword);
+    }
+
+    pub fn initializer(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn condition(&self) -> Option<Expression> {
+        return nth_child_after(&self.syntax, SyntaxKind::SemicolonToken, 0);
+    }
+
+    pub fn incrementor(&self) -> Option<Expression> {
+        return nth_child_after(&self.syntax, SyntaxKind::SemicolonToken, 1);
+    }
+
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::CloseParenToken);
+    }
+}
+
+impl ForInStatement {
+    pub fn declaration(&self) -> Option<VariableDeclaration> {
+        return list_after(&self.syntax, SyntaxKind::VarKeyword).next();
+    }
+
+    pub fn variable(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::InKeyword);
+    }
+
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::CloseParenToken);
+    }
+}
+
+impl ContinueStatement {
+    pub fn label(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+}
+
+impl BreakStatement {
+    pub fn label(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+}
+
+impl ReturnStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::ReturnKeyword);
+    }
+}
+
+impl WithStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::CloseParenToken);
+    }
+}
+
+impl SwitchStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn clauses(&self) -> AstChildren<CaseOrDefaultClause> {
+        return list_after(&self.syntax, SyntaxKind::OpenBraceToken);
+    }
+}
+
+impl CaseClause {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::CaseKeyword);
+    }
+
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return list_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+}
+
+impl DefaultClause {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return list_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+}
+
+impl LabelledStatement {
+    pub fn label(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+
+    pub fn statement(&self) -> Option<Statement> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+}
+
+impl ThrowStatement {
+    pub fn expression(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::ThrowKeyword);
+    }
+}
+
+impl TryStatement {
+    pub fn try_block(&self) -> Option<TryBlock> {
+        return child(&self.syntax);
+    }
+
+    pub fn catch_block(&self) -> Option<CatchBlock> {
+        return child(&self.syntax);
+    }
+
+    pub fn finally_block(&self) -> Option<FinallyBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl TryBlock {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl CatchBlock {
+    pub fn variable(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl FinallyBlock {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl VariableDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+
+    pub fn type_annotation(&self) -> Option<TypeNode> {
+        return child_after(&self.syntax, SyntaxKind::ColonToken);
+    }
+
+    pub fn initializer(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+}
+
+impl ModifiersOwner for FunctionDeclaration {}
+impl SignatureDeclaration for FunctionDeclaration {}
+
+impl FunctionDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::FunctionKeyword);
+    }
+
+    // None for overloads and ambient declarations
+    pub fn body(&self) -> Option<FunctionBlock> {
+        return child(&self.syntax);
+    }
+}
+
+impl FunctionBlock {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for ClassDeclaration {}
+
+impl ClassDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::ClassKeyword);
+    }
+
+    pub fn type_parameters(&self) -> AstChildren<TypeParameter> {
+        return list_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+
+    pub fn base_type(&self) -> Option<TypeReference> {
+        return child_after(&self.syntax, SyntaxKind::ExtendsKeyword);
+    }
+
+    pub fn implemented_types(&self) -> AstChildren<TypeReference> {
+        return list_after(&self.syntax, SyntaxKind::ImplementsKeyword);
+    }
+
+    pub fn members(&self) -> AstChildren<ClassElement> {
+        return list_after(&self.syntax, SyntaxKind::OpenBraceToken);
+    }
+}
+
+impl ModifiersOwner for InterfaceDeclaration {}
+
+impl InterfaceDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::InterfaceKeyword);
+    }
+
+    pub fn type_parameters(&self) -> AstChildren<TypeParameter> {
+        return list_after(&self.syntax, SyntaxKind::LessThanToken);
+    }
+
+    pub fn base_types(&self) -> AstChildren<TypeReference> {
+        return list_after(&self.syntax, SyntaxKind::ExtendsKeyword);
+    }
+
+    pub fn members(&self) -> AstChildren<TypeMember> {
+        return list_after(&self.syntax, SyntaxKind::OpenBraceToken);
+    }
+}
+
+impl ModifiersOwner for EnumDeclaration {}
+
+impl EnumDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::EnumKeyword);
+    }
+
+    pub fn members(&self) -> AstChildren<EnumMember> {
+        return list_after(&self.syntax, SyntaxKind::OpenBraceToken);
+    }
+}
+
+impl EnumMember {
+    pub fn name(&self) -> Option<PropertyName> {
+        return child(&self.syntax);
+    }
+
+    pub fn initializer(&self) -> Option<Expression> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+}
+
+impl ModifiersOwner for ModuleDeclaration {}
+
+impl ModuleDeclaration {
+    // The name of `module A.B { }` is A, its body is the declaration of B
+    pub fn name(&self) -> Option<ModuleName> {
+        return self
+            .syntax
+            .children()
+            .skip_while(|child| is_modifier(child.kind()) || child.kind() == SyntaxKind::ModuleKeyword)
+            .find_map(ModuleName::cast);
+    }
+
+    pub fn body(&self) -> Option<ModuleBody> {
+        return child(&self.syntax);
+    }
+}
+
+impl ModuleBlock {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ModifiersOwner for ImportDeclaration {}
+
+impl ImportDeclaration {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::ImportKeyword);
+    }
+
+    // `import a = B.C;`
+    pub fn entity_name(&self) -> Option<EntityName> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+
+    // `import a = require("b");`
+    pub fn external_module_name(&self) -> Option<LiteralExpression> {
+        return child_after(&self.syntax, SyntaxKind::OpenParenToken);
+    }
+}
+
+impl ExportAssignment {
+    pub fn export_name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::EqualsToken);
+    }
+}
+
+impl ImportFromDeclaration {
+    pub fn import_clause(&self) -> Option<ImportClause> {
+        return child(&self.syntax);
+    }
+
+    pub fn module_specifier(&self) -> Option<LiteralExpression> {
+        return child(&self.syntax);
+    }
+}
+
+impl ImportClause {
+    // The default import b of `import b, { c } from "d";`
+    pub fn name(&self) -> Option<Identifier> {
+        return child(&self.syntax);
+    }
+
+    pub fn named_bindings(&self) -> Option<NamedImportBindings> {
+        return child(&self.syntax);
+    }
+}
+
+impl NamespaceImport {
+    pub fn name(&self) -> Option<Identifier> {
+        return child_after(&self.syntax, SyntaxKind::AsKeyword);
+    }
+}
+
+impl NamedImports {
+    pub fn elements(&self) -> AstChildren<ImportSpecifier> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ImportSpecifier {
+    // The imported name a of `a as b`, None when the specifier has no `as`
+    pub fn property_name(&self) -> Option<Identifier> {
+        token(&self.syntax, SyntaxKind::AsKeyword)?;
+        return child(&self.syntax);
+    }
+
+    // The local name b of `a as b`
+    pub fn name(&self) -> Option<Identifier> {
+        return self.syntax.children().filter_map(Identifier::cast).last();
+    }
+}
+
+impl ExportDeclaration {
+    // `export * from "a";` exports everything
+    pub fn asterisk_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::AsteriskToken);
+    }
+
+    pub fn export_clause(&self) -> Option<NamedExports> {
+        return child(&self.syntax);
+    }
+
+    pub fn module_specifier(&self) -> Option<LiteralExpression> {
+        return child_after(&self.syntax, SyntaxKind::FromKeyword);
+    }
+}
+
+impl NamedExports {
+    pub fn elements(&self) -> AstChildren<ExportSpecifier> {
+        return first_list(&self.syntax);
+    }
+}
+
+impl ExportSpecifier {
+    // The local name a of `a as b`, None when the specifier has no `as`
+    pub fn property_name(&self) -> Option<Identifier> {
+        token(&self.syntax, SyntaxKind::AsKeyword)?;
+        return child(&self.syntax);
+    }
+
+    // The exported name b of `a as b`
+    pub fn name(&self) -> Option<Identifier> {
+        return self.syntax.children().filter_map(Identifier::cast).last();
+    }
+}
+
+impl SourceFile {
+    pub fn statements(&self) -> AstChildren<Statement> {
+        return first_list(&self.syntax);
+    }
+
+    pub fn end_of_file_token(&self) -> Option<SyntaxNode> {
+        return token(&self.syntax, SyntaxKind::EndOfFileToken);
+    }
+}
+
+impl ParsedSourceFile {
+    pub fn ast(&self) -> SourceFile {
+        return SourceFile {
+            syntax: self.root.clone(),
+        };
+    }
+}
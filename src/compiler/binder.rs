@@ -0,0 +1,677 @@
+use super::ast::*;
+use super::diagnostics;
+use super::syntax::SyntaxNode;
+use super::types::{
+    symbol_flags, Diagnostic, SourceFile as ParsedSourceFile, Symbol, SymbolId, SymbolTable, SyntaxKind,
+};
+use super::visit::{walk, Visitor};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+// Records the names a file declares. Each declaration adds to the symbol of its name in the table of
+// the node around it: the locals of the file, modules, functions, signatures and catch blocks, the
+// members and exports of classes, interfaces, enums, modules and literals. Two declarations of a name
+// whose meanings cannot go together are both reported as duplicates.
+
+#[derive(Debug, Clone, Default)]
+pub struct BoundFile {
+    pub symbols: Vec<Symbol>,
+    pub locals: HashMap<SyntaxNode, SymbolTable>, // Tables of the nodes that hold local declarations
+    pub node_symbols: HashMap<SyntaxNode, SymbolId>, // Symbol of each declaration
+    pub file_symbol: Option<SymbolId>,            // The file of an external module, it holds the exports
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl BoundFile {
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        return &self.symbols[id.index()];
+    }
+
+    pub fn get_symbol_of_node(&self, node: &SyntaxNode) -> Option<SymbolId> {
+        return self.node_symbols.get(node).copied();
+    }
+
+    pub fn get_locals(&self, node: &SyntaxNode) -> Option<&SymbolTable> {
+        return self.locals.get(node);
+    }
+}
+
+pub fn bind_source_file(file: &ParsedSourceFile) -> BoundFile {
+    let mut binder = Binder {
+        file_name: &file.file_name,
+        is_declaration_file: file.is_declaration_file,
+        bound: BoundFile::default(),
+        container: file.root.clone(),
+        parent_symbol: None,
+        declaration_names: HashMap::new(),
+        reported: HashSet::new(),
+    };
+    binder.visit_node(&file.root);
+    return binder.bound;
+}
+
+// The node a declaration belongs to, lists are skipped
+pub fn owner(node: &SyntaxNode) -> Option<SyntaxNode> {
+    return node
+        .ancestors()
+        .find(|ancestor| ancestor.kind() != SyntaxKind::SyntaxList);
+}
+
+pub fn has_modifier(node: &SyntaxNode, kind: SyntaxKind) -> bool {
+    return node
+        .children()
+        .take_while(|child| is_modifier(child.kind()))
+        .any(|child| child.kind() == kind);
+}
+
+fn is_static(node: &SyntaxNode) -> bool {
+    return has_modifier(node, SyntaxKind::StaticKeyword);
+}
+
+// `export` at the top level makes a file an external module, and so do imports of one
+fn is_external_module(file: &SourceFile) -> bool {
+    return file.statements().any(|statement| match statement {
+        Statement::ExportAssignment(_) | Statement::ImportFromDeclaration(_) | Statement::ExportDeclaration(_) => true,
+        Statement::ImportDeclaration(import) => {
+            import.external_module_name().is_some() || import.has_modifier(SyntaxKind::ExportKeyword)
+        }
+        statement => has_modifier(statement.syntax(), SyntaxKind::ExportKeyword),
+    });
+}
+
+// Whether a module declares values, and so exists at run time, rather than only types
+pub fn is_instantiated(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        SyntaxKind::InterfaceDeclaration => return false,
+        SyntaxKind::ImportDeclaration => return has_modifier(node, SyntaxKind::ExportKeyword),
+        SyntaxKind::ModuleDeclaration => {
+            let body = ModuleDeclaration::cast(node.clone()).and_then(|module| module.body());
+            return body.is_some_and(|body| is_instantiated(body.syntax()));
+        }
+        SyntaxKind::ModuleBlock => {
+            let block = ModuleBlock::cast(node.clone()).unwrap();
+            return block.statements().any(|statement| is_instantiated(statement.syntax()));
+        }
+        _ => return true,
+    }
+}
+
+// The name of a declaration, where errors about it are reported
+pub fn get_declaration_name(node: &SyntaxNode) -> Option<SyntaxNode> {
+    let node = node.clone();
+    let name = match node.kind() {
+        SyntaxKind::VariableDeclaration => VariableDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::Parameter => Parameter::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::TypeParameter => TypeParameter::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::FunctionDeclaration => FunctionDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::FunctionExpression => FunctionExpression::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::ClassDeclaration => ClassDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::InterfaceDeclaration => InterfaceDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::EnumDeclaration => EnumDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::ModuleDeclaration => ModuleDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::Property => Property::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::PropertyAssignment => PropertyAssignment::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::EnumMember => EnumMember::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::Method => Method::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::GetAccessor => GetAccessor::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::SetAccessor => SetAccessor::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::CatchBlock => CatchBlock::cast(node)?.variable()?.syntax().clone(),
+        SyntaxKind::ImportDeclaration => ImportDeclaration::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::ImportClause => ImportClause::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::NamespaceImport => NamespaceImport::cast(node)?.name()?.syntax().clone(),
+        SyntaxKind::ImportSpecifier => ImportSpecifier::cast(node)?.name()?.syntax().clone(),
+        _ => return None,
+    };
+    return Some(name);
+}
+
+fn property_name_text(name: &PropertyName) -> String {
+    match name {
+        PropertyName::Identifier(identifier) => return identifier.text().to_string(),
+        PropertyName::LiteralExpression(literal) => return literal.value().to_string(),
+    }
+}
+
+// The name of the symbol of an external module file, `"lib"` for lib.d.ts
+fn external_module_name(file_name: &str) -> String {
+    let stem = file_name
+        .strip_suffix(".d.ts")
+        .or_else(|| file_name.strip_suffix(".ts"))
+        .unwrap_or(file_name);
+    return format!("\"{}\"", stem);
+}
+
+// The table a declaration goes into
+enum Table {
+    Locals(SyntaxNode),
+    Members(SymbolId),
+    Exports(SymbolId),
+}
+
+struct Binder<'a> {
+    file_name: &'a str,
+    is_declaration_file: bool,
+    bound: BoundFile,
+    container: SyntaxNode, // The file, module or function that var and function declarations belong to
+    parent_symbol: Option<SymbolId>, // The class, interface, enum or literal whose members are being bound
+    declaration_names: HashMap<SyntaxNode, SyntaxNode>, // Where duplicates of each declaration are reported
+    reported: HashSet<SyntaxNode>,
+}
+
+impl<'a> Binder<'a> {
+    fn table_mut(&mut self, table: &Table) -> &mut SymbolTable {
+        match table {
+            Table::Locals(node) => return self.bound.locals.entry(node.clone()).or_default(),
+            Table::Members(id) => return &mut self.bound.symbols[id.index()].members,
+            Table::Exports(id) => return &mut self.bound.symbols[id.index()].exports,
+        }
+    }
+
+    fn create_symbol(&mut self, name: String, parent: Option<SymbolId>) -> SymbolId {
+        let id = SymbolId::new(self.bound.symbols.len());
+        self.bound.symbols.push(Symbol {
+            name,
+            flags: symbol_flags::NONE,
+            declarations: Vec::new(),
+            parent,
+            members: SymbolTable::new(),
+            exports: SymbolTable::new(),
+        });
+        return id;
+    }
+
+    fn add_declaration(&mut self, id: SymbolId, node: &SyntaxNode, includes: u32) {
+        let symbol = &mut self.bound.symbols[id.index()];
+        symbol.flags |= includes;
+        symbol.declarations.push(node.clone());
+        // A parameter property stays the parameter, the property is found through the class
+        self.bound.node_symbols.entry(node.clone()).or_insert(id);
+    }
+
+    fn report_duplicate(&mut self, declaration: &SyntaxNode, name: &str) {
+        if !self.reported.insert(declaration.clone()) {
+            return;
+        }
+        let location = self.declaration_names.get(declaration).unwrap_or(declaration);
+        let start = location.token_pos();
+        let length = location.end() - start;
+        self.bound.diagnostics.push(Diagnostic::create(
+            self.file_name,
+            start,
+            length,
+            &diagnostics::DUPLICATE_IDENTIFIER_0,
+            &[name],
+        ));
+    }
+
+    // Adds node to the symbol of name in table. A declaration that cannot share the name with the
+    // existing ones gets a symbol of its own outside of the table.
+    fn declare_symbol(
+        &mut self,
+        table: Table,
+        node: &SyntaxNode,
+        name: String,
+        name_node: &SyntaxNode,
+        includes: u32,
+        excludes: u32,
+    ) -> SymbolId {
+        self.declaration_names.insert(node.clone(), name_node.clone());
+        let parent = match table {
+            Table::Locals(_) => None,
+            Table::Members(id) | Table::Exports(id) => Some(id),
+        };
+        let id = match self.table_mut(&table).get(&name).copied() {
+            Some(existing) if self.bound.symbols[existing.index()].flags & excludes != 0 => {
+                for declaration in self.bound.symbols[existing.index()].declarations.clone() {
+                    self.report_duplicate(&declaration, &name);
+                }
+                self.report_duplicate(node, &name);
+                self.create_symbol(name, parent)
+            }
+            Some(existing) => existing,
+            None => {
+                let id = self.create_symbol(name.clone(), parent);
+                self.table_mut(&table).insert(name, id);
+                id
+            }
+        };
+        self.add_declaration(id, node, includes);
+        return id;
+    }
+
+    // Symbols of function expressions and literals, they are not in any table
+    fn declare_anonymous_symbol(&mut self, node: &SyntaxNode, name: &str, includes: u32) -> SymbolId {
+        let id = self.create_symbol(name.to_string(), None);
+        self.add_declaration(id, node, includes);
+        return id;
+    }
+
+    // Declarations of an ambient module are exported whether they say so or not, imports excepted
+    fn is_exported(&self, node: &SyntaxNode) -> bool {
+        let declaration = if node.kind() == SyntaxKind::VariableDeclaration {
+            node.ancestors()
+                .find(|ancestor| ancestor.kind() == SyntaxKind::VariableStatement)
+        } else {
+            Some(node.clone())
+        };
+        if declaration.is_some_and(|declaration| has_modifier(&declaration, SyntaxKind::ExportKeyword)) {
+            return true;
+        }
+        return self.container.kind() == SyntaxKind::ModuleDeclaration
+            && node.kind() != SyntaxKind::ImportDeclaration
+            && (self.is_declaration_file
+                || self
+                    .container
+                    .ancestors()
+                    .chain(Some(self.container.clone()))
+                    .any(|ancestor| has_modifier(&ancestor, SyntaxKind::DeclareKeyword)));
+    }
+
+    // Declares node in the locals or the exports of the current container
+    fn declare_in_container(
+        &mut self,
+        node: &SyntaxNode,
+        name: String,
+        name_node: &SyntaxNode,
+        includes: u32,
+        excludes: u32,
+    ) -> SymbolId {
+        let exports = match self.container.kind() {
+            SyntaxKind::ModuleDeclaration => self.bound.get_symbol_of_node(&self.container),
+            SyntaxKind::SourceFile => self.bound.file_symbol,
+            _ => None,
+        };
+        let table = match exports {
+            Some(exports) if self.is_exported(node) => Table::Exports(exports),
+            _ => Table::Locals(self.container.clone()),
+        };
+        return self.declare_symbol(table, node, name, name_node, includes, excludes);
+    }
+
+    // Declares a member of the class, interface, enum or literal being bound, static members of
+    // classes and members of enums are exports
+    fn declare_member(
+        &mut self,
+        node: &SyntaxNode,
+        name: String,
+        name_node: &SyntaxNode,
+        includes: u32,
+        excludes: u32,
+    ) -> Option<SymbolId> {
+        let parent = self.parent_symbol?;
+        let table = if is_static(node) || includes == symbol_flags::ENUM_MEMBER {
+            Table::Exports(parent)
+        } else {
+            Table::Members(parent)
+        };
+        return Some(self.declare_symbol(table, node, name, name_node, includes, excludes));
+    }
+
+    // Binds the children of node, which holds its own locals. Functions and modules also take the
+    // var and function declarations inside them.
+    fn bind_container(&mut self, node: &SyntaxNode, is_declaration_container: bool) {
+        self.bound.locals.entry(node.clone()).or_default();
+        let parent_symbol = self.parent_symbol.take();
+        let container = if is_declaration_container {
+            Some(mem::replace(&mut self.container, node.clone()))
+        } else {
+            None
+        };
+        walk(self, node);
+        if let Some(container) = container {
+            self.container = container;
+        }
+        self.parent_symbol = parent_symbol;
+    }
+
+    // Binds the members of a class, interface, enum or literal into the tables of symbol
+    fn bind_members(&mut self, node: &SyntaxNode, symbol: SymbolId) {
+        let parent_symbol = self.parent_symbol.replace(symbol);
+        walk(self, node);
+        self.parent_symbol = parent_symbol;
+    }
+
+    fn bind_member_with_property_name(
+        &mut self,
+        node: &SyntaxNode,
+        name: Option<PropertyName>,
+        includes: u32,
+        excludes: u32,
+        is_function: bool,
+    ) {
+        if let Some(name) = name {
+            self.declare_member(node, property_name_text(&name), name.syntax(), includes, excludes);
+        }
+        if is_function {
+            self.bind_container(node, true);
+        } else {
+            let parent_symbol = self.parent_symbol.take();
+            walk(self, node);
+            self.parent_symbol = parent_symbol;
+        }
+    }
+
+    fn bind_signature(&mut self, node: &SyntaxNode, name: &str, includes: u32) {
+        self.declare_member(node, name.to_string(), node, includes, symbol_flags::NONE);
+        self.bind_container(node, false);
+    }
+
+    fn bind_function_like_expression(&mut self, node: &SyntaxNode, name: Option<Identifier>) {
+        let name = name.map_or("__function".to_string(), |name| name.text().to_string());
+        self.declare_anonymous_symbol(node, &name, symbol_flags::FUNCTION);
+        self.bind_container(node, true);
+    }
+
+    fn bind_import(&mut self, node: &SyntaxNode, name: Option<Identifier>) {
+        if let Some(name) = name {
+            self.declare_in_container(
+                node,
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::IMPORT,
+                symbol_flags::IMPORT_EXCLUDES,
+            );
+        }
+        walk(self, node);
+    }
+}
+
+impl<'a> Visitor for Binder<'a> {
+    fn visit_source_file(&mut self, node: &SourceFile) {
+        if is_external_module(node) {
+            let name = external_module_name(self.file_name);
+            let id = self.declare_anonymous_symbol(node.syntax(), &name, symbol_flags::VALUE_MODULE);
+            self.bound.file_symbol = Some(id);
+        }
+        self.bind_container(node.syntax(), true);
+    }
+
+    fn visit_module_declaration(&mut self, node: &ModuleDeclaration) {
+        let name = match node.name() {
+            Some(ModuleName::Identifier(identifier)) => {
+                Some((identifier.text().to_string(), identifier.syntax().clone()))
+            }
+            Some(ModuleName::LiteralExpression(literal)) => {
+                Some((format!("\"{}\"", literal.value()), literal.syntax().clone()))
+            }
+            None => None,
+        };
+        if let Some((name, name_node)) = name {
+            let (includes, excludes) = if is_instantiated(node.syntax()) {
+                (symbol_flags::VALUE_MODULE, symbol_flags::VALUE_MODULE_EXCLUDES)
+            } else {
+                (symbol_flags::NAMESPACE_MODULE, symbol_flags::NAMESPACE_MODULE_EXCLUDES)
+            };
+            // The B of `module A.B { }` is an export of A
+            let is_dotted_name = self.container.kind() == SyntaxKind::ModuleDeclaration
+                && node.syntax().parent().as_ref() == Some(&self.container);
+            match self.bound.get_symbol_of_node(&self.container) {
+                Some(outer) if is_dotted_name => {
+                    self.declare_symbol(
+                        Table::Exports(outer),
+                        node.syntax(),
+                        name,
+                        &name_node,
+                        includes,
+                        excludes,
+                    );
+                }
+                _ => {
+                    self.declare_in_container(node.syntax(), name, &name_node, includes, excludes);
+                }
+            }
+        }
+        self.bind_container(node.syntax(), true);
+    }
+
+    fn visit_class_declaration(&mut self, node: &ClassDeclaration) {
+        let symbol = match node.name() {
+            Some(name) => self.declare_in_container(
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::CLASS,
+                symbol_flags::CLASS_EXCLUDES,
+            ),
+            None => self.declare_anonymous_symbol(node.syntax(), "__class", symbol_flags::CLASS),
+        };
+        self.bound.locals.entry(node.syntax().clone()).or_default();
+        self.bind_members(node.syntax(), symbol);
+    }
+
+    fn visit_interface_declaration(&mut self, node: &InterfaceDeclaration) {
+        let symbol = match node.name() {
+            Some(name) => self.declare_in_container(
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::INTERFACE,
+                symbol_flags::INTERFACE_EXCLUDES,
+            ),
+            None => self.declare_anonymous_symbol(node.syntax(), "__interface", symbol_flags::INTERFACE),
+        };
+        self.bound.locals.entry(node.syntax().clone()).or_default();
+        self.bind_members(node.syntax(), symbol);
+    }
+
+    fn visit_enum_declaration(&mut self, node: &EnumDeclaration) {
+        let symbol = match node.name() {
+            Some(name) => self.declare_in_container(
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::ENUM,
+                symbol_flags::ENUM_EXCLUDES,
+            ),
+            None => self.declare_anonymous_symbol(node.syntax(), "__enum", symbol_flags::ENUM),
+        };
+        self.bind_members(node.syntax(), symbol);
+    }
+
+    fn visit_type_literal(&mut self, node: &TypeLiteral) {
+        let symbol = self.declare_anonymous_symbol(node.syntax(), "__type", symbol_flags::TYPE_LITERAL);
+        self.bind_members(node.syntax(), symbol);
+    }
+
+    fn visit_object_literal(&mut self, node: &ObjectLiteral) {
+        let symbol = self.declare_anonymous_symbol(node.syntax(), "__object", symbol_flags::OBJECT_LITERAL);
+        self.bind_members(node.syntax(), symbol);
+    }
+
+    fn visit_function_declaration(&mut self, node: &FunctionDeclaration) {
+        if let Some(name) = node.name() {
+            self.declare_in_container(
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::FUNCTION,
+                symbol_flags::FUNCTION_EXCLUDES,
+            );
+        }
+        self.bind_container(node.syntax(), true);
+    }
+
+    fn visit_function_expression(&mut self, node: &FunctionExpression) {
+        self.bind_function_like_expression(node.syntax(), node.name());
+    }
+
+    fn visit_arrow_function(&mut self, node: &ArrowFunction) {
+        self.bind_function_like_expression(node.syntax(), None);
+    }
+
+    fn visit_property(&mut self, node: &Property) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::PROPERTY,
+            symbol_flags::PROPERTY_EXCLUDES,
+            false,
+        );
+    }
+
+    fn visit_property_assignment(&mut self, node: &PropertyAssignment) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::PROPERTY,
+            symbol_flags::PROPERTY_EXCLUDES,
+            false,
+        );
+    }
+
+    fn visit_enum_member(&mut self, node: &EnumMember) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::ENUM_MEMBER,
+            symbol_flags::ENUM_MEMBER_EXCLUDES,
+            false,
+        );
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::METHOD,
+            symbol_flags::METHOD_EXCLUDES,
+            true,
+        );
+    }
+
+    fn visit_get_accessor(&mut self, node: &GetAccessor) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::GET_ACCESSOR,
+            symbol_flags::GET_ACCESSOR_EXCLUDES,
+            true,
+        );
+    }
+
+    fn visit_set_accessor(&mut self, node: &SetAccessor) {
+        self.bind_member_with_property_name(
+            node.syntax(),
+            node.name(),
+            symbol_flags::SET_ACCESSOR,
+            symbol_flags::SET_ACCESSOR_EXCLUDES,
+            true,
+        );
+    }
+
+    fn visit_constructor(&mut self, node: &Constructor) {
+        self.declare_member(
+            node.syntax(),
+            "__constructor".to_string(),
+            node.syntax(),
+            symbol_flags::CONSTRUCTOR,
+            symbol_flags::NONE,
+        );
+        self.bind_container(node.syntax(), true);
+    }
+
+    fn visit_call_signature(&mut self, node: &CallSignature) {
+        self.bind_signature(node.syntax(), "__call", symbol_flags::CALL_SIGNATURE);
+    }
+
+    fn visit_construct_signature(&mut self, node: &ConstructSignature) {
+        self.bind_signature(node.syntax(), "__new", symbol_flags::CONSTRUCT_SIGNATURE);
+    }
+
+    fn visit_index_signature(&mut self, node: &IndexSignature) {
+        self.bind_signature(node.syntax(), "__index", symbol_flags::INDEX_SIGNATURE);
+    }
+
+    fn visit_parameter(&mut self, node: &Parameter) {
+        let (name, owner) = match (node.name(), owner(node.syntax())) {
+            (Some(name), Some(owner)) => (name, owner),
+            _ => return walk(self, node.syntax()),
+        };
+        self.declare_symbol(
+            Table::Locals(owner.clone()),
+            node.syntax(),
+            name.text().to_string(),
+            name.syntax(),
+            symbol_flags::PARAMETER,
+            symbol_flags::PARAMETER_EXCLUDES,
+        );
+        // `constructor(public x)` also declares the property x
+        let is_property = node.has_modifier(SyntaxKind::PublicKeyword)
+            || node.has_modifier(SyntaxKind::PrivateKeyword)
+            || node.has_modifier(SyntaxKind::ProtectedKeyword);
+        let class = owner
+            .ancestors()
+            .find(|ancestor| ancestor.kind() == SyntaxKind::ClassDeclaration)
+            .and_then(|class| self.bound.get_symbol_of_node(&class));
+        if let Some(class) = class.filter(|_| is_property && owner.kind() == SyntaxKind::Constructor) {
+            self.declare_symbol(
+                Table::Members(class),
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::PROPERTY,
+                symbol_flags::PROPERTY_EXCLUDES,
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_type_parameter(&mut self, node: &TypeParameter) {
+        if let (Some(name), Some(owner)) = (node.name(), owner(node.syntax())) {
+            self.declare_symbol(
+                Table::Locals(owner),
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::TYPE_PARAMETER,
+                symbol_flags::TYPE_PARAMETER_EXCLUDES,
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_variable_declaration(&mut self, node: &VariableDeclaration) {
+        if let Some(name) = node.name() {
+            self.declare_in_container(
+                node.syntax(),
+                name.text().to_string(),
+                name.syntax(),
+                symbol_flags::VARIABLE,
+                symbol_flags::VARIABLE_EXCLUDES,
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    // The variable of a catch block is local to the block, unlike var declarations inside it
+    fn visit_catch_block(&mut self, node: &CatchBlock) {
+        self.bound.locals.entry(node.syntax().clone()).or_default();
+        if let Some(variable) = node.variable() {
+            self.declare_symbol(
+                Table::Locals(node.syntax().clone()),
+                node.syntax(),
+                variable.text().to_string(),
+                variable.syntax(),
+                symbol_flags::VARIABLE,
+                symbol_flags::VARIABLE_EXCLUDES,
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_import_declaration(&mut self, node: &ImportDeclaration) {
+        self.bind_import(node.syntax(), node.name());
+    }
+
+    fn visit_import_clause(&mut self, node: &ImportClause) {
+        self.bind_import(node.syntax(), node.name());
+    }
+
+    fn visit_namespace_import(&mut self, node: &NamespaceImport) {
+        self.bind_import(node.syntax(), node.name());
+    }
+
+    fn visit_import_specifier(&mut self, node: &ImportSpecifier) {
+        self.bind_import(node.syntax(), node.name());
+    }
+}
@@ -0,0 +1,1297 @@
+use super::ast::*;
+use super::binder::{bind_source_file, get_declaration_name, has_modifier, is_instantiated, owner};
+use super::diagnostics;
+use super::syntax::SyntaxNode;
+use super::types::{
+    sort_and_deduplicate_diagnostics, symbol_flags, Diagnostic, DiagnosticMessage, SourceFile as ParsedSourceFile,
+    Symbol, SymbolId, SymbolTable, SyntaxKind,
+};
+use std::collections::{HashMap, HashSet};
+use std::mem;
+
+// Checks the declarations of a program. Each file is bound on its own, then the global declarations of
+// the files that are not external modules are merged into one table: a name declared in several files
+// gets a merged symbol holding the declarations of all of them, so a module reopened in another file
+// adds to the exports of the first. Merges the language does not allow, overload lists that are out of
+// order or disagree on their modifiers, and declarations that are partly exported are reported.
+//
+// Every name is then resolved to the symbol it refers to. A name is looked up in the tables of the
+// nodes around it, innermost first, and then in the globals. Var and function declarations are in the
+// table of their function or module wherever they appear in it, so they are found before they are
+// declared. Names inside `with` statements cannot be resolved, they may be properties of the object.
+//
+// With the names resolved, declarations nothing refers to can be reported as warnings. Each kind is
+// off unless the options ask for it.
+
+// Which unused declarations are reported
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CheckerOptions {
+    pub no_unused_locals: bool,
+    pub no_unused_parameters: bool, // Parameters whose name starts with `_` are left alone
+    pub no_unused_imports: bool,
+    pub no_unused_private_members: bool,
+    pub no_write_only_variables: bool, // Variables that are assigned but never read
+}
+
+pub struct Checker {
+    symbols: Vec<Symbol>,
+    locals: HashMap<SyntaxNode, SymbolTable>,
+    node_symbols: HashMap<SyntaxNode, SymbolId>,
+    globals: SymbolTable,
+    merged_symbols: HashMap<SymbolId, SymbolId>, // Symbols of the files to the merged symbols holding them
+    files: Vec<CheckedFile>,
+    resolutions: HashMap<SyntaxNode, Option<SymbolId>>, // Symbol each identifier refers to
+    module_resolutions: HashMap<SyntaxNode, Option<SymbolId>>, // Module each external module name refers to
+    import_targets: HashMap<SymbolId, Option<SymbolId>>, // What each import refers to
+    arguments_symbol: SymbolId,
+    undefined_symbol: SymbolId,
+    options: CheckerOptions,
+    diagnostics: Vec<Diagnostic>,
+}
+
+struct CheckedFile {
+    root: SyntaxNode,
+    file_name: String,
+    is_declaration_file: bool,
+    file_symbol: Option<SymbolId>,
+}
+
+// Modifiers the overloads of a function have to agree on
+const EXPORTED: u32 = 1 << 0;
+const AMBIENT: u32 = 1 << 1;
+const PRIVATE: u32 = 1 << 2;
+const PROTECTED: u32 = 1 << 3;
+
+// The meanings a declaration gives its name, exported and local declarations cannot share one
+const VALUE_SPACE: u32 = 1 << 0;
+const TYPE_SPACE: u32 = 1 << 1;
+const NAMESPACE_SPACE: u32 = 1 << 2;
+
+pub fn create_checker(files: &[ParsedSourceFile], options: &CheckerOptions) -> Checker {
+    let mut checker = Checker {
+        symbols: Vec::new(),
+        locals: HashMap::new(),
+        node_symbols: HashMap::new(),
+        globals: SymbolTable::new(),
+        merged_symbols: HashMap::new(),
+        files: Vec::new(),
+        resolutions: HashMap::new(),
+        module_resolutions: HashMap::new(),
+        import_targets: HashMap::new(),
+        arguments_symbol: SymbolId::new(0),
+        undefined_symbol: SymbolId::new(0),
+        options: options.clone(),
+        diagnostics: Vec::new(),
+    };
+    for file in files {
+        checker.add_file(file);
+    }
+    checker.arguments_symbol = checker.create_intrinsic_symbol("arguments");
+    checker.undefined_symbol = checker.create_intrinsic_symbol("undefined");
+    for index in 0..checker.files.len() {
+        if checker.files[index].file_symbol.is_none() {
+            let locals = checker.locals[&checker.files[index].root].clone();
+            let mut globals = mem::take(&mut checker.globals);
+            checker.merge_symbol_table(&mut globals, &locals);
+            checker.globals = globals;
+        }
+    }
+    checker.check_declarations();
+    checker.resolve_names();
+    checker.check_unused_declarations();
+    sort_and_deduplicate_diagnostics(&mut checker.diagnostics);
+    return checker;
+}
+
+fn is_function_like(kind: SyntaxKind) -> bool {
+    return matches!(
+        kind,
+        SyntaxKind::FunctionDeclaration | SyntaxKind::Method | SyntaxKind::Constructor
+    );
+}
+
+fn has_body(node: &SyntaxNode) -> bool {
+    return node.children().any(|child| child.kind() == SyntaxKind::FunctionBlock);
+}
+
+// The file, module or function whose var and function declarations node is one of
+fn get_declaration_container(node: &SyntaxNode) -> Option<SyntaxNode> {
+    return node.ancestors().find(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            SyntaxKind::SourceFile
+                | SyntaxKind::ModuleDeclaration
+                | SyntaxKind::FunctionDeclaration
+                | SyntaxKind::FunctionExpression
+                | SyntaxKind::ArrowFunction
+                | SyntaxKind::Method
+                | SyntaxKind::Constructor
+                | SyntaxKind::GetAccessor
+                | SyntaxKind::SetAccessor
+        )
+    });
+}
+
+fn get_declaration_spaces(node: &SyntaxNode) -> u32 {
+    match node.kind() {
+        SyntaxKind::InterfaceDeclaration | SyntaxKind::TypeParameter => return TYPE_SPACE,
+        SyntaxKind::ModuleDeclaration if is_instantiated(node) => return NAMESPACE_SPACE | VALUE_SPACE,
+        SyntaxKind::ModuleDeclaration => return NAMESPACE_SPACE,
+        SyntaxKind::ClassDeclaration | SyntaxKind::EnumDeclaration => return TYPE_SPACE | VALUE_SPACE,
+        SyntaxKind::ImportDeclaration => return TYPE_SPACE | VALUE_SPACE | NAMESPACE_SPACE,
+        _ => return VALUE_SPACE,
+    }
+}
+
+// Whether identifier is assigned to without being read, compound assignments read it first
+fn is_write_access(identifier: &SyntaxNode) -> bool {
+    let parent = match owner(identifier) {
+        Some(parent) => parent,
+        None => return false,
+    };
+    if let Some(binary) = BinaryExpression::cast(parent.clone()) {
+        return binary.operator() == SyntaxKind::EqualsToken
+            && binary.left().is_some_and(|left| left.syntax() == identifier);
+    }
+    if let Some(for_in) = ForInStatement::cast(parent) {
+        return for_in
+            .variable()
+            .is_some_and(|variable| variable.syntax() == identifier);
+    }
+    return false;
+}
+
+// Whether a parameter of node is expected to be used, signatures and overloads have nothing to use it in
+fn has_parameters_to_use(node: &SyntaxNode) -> bool {
+    match node.kind() {
+        SyntaxKind::FunctionExpression | SyntaxKind::ArrowFunction => return true,
+        SyntaxKind::FunctionDeclaration
+        | SyntaxKind::Method
+        | SyntaxKind::Constructor
+        | SyntaxKind::GetAccessor
+        | SyntaxKind::SetAccessor => return has_body(node),
+        _ => return false,
+    }
+}
+
+fn is_parameter_property(node: &SyntaxNode) -> bool {
+    return node.kind() == SyntaxKind::Parameter
+        && (has_modifier(node, SyntaxKind::PublicKeyword)
+            || has_modifier(node, SyntaxKind::PrivateKeyword)
+            || has_modifier(node, SyntaxKind::ProtectedKeyword));
+}
+
+// Whether a member named name is accessed anywhere in class, as `x.name` or `x['name']`
+fn is_member_accessed(class: &SyntaxNode, name: &str) -> bool {
+    return class.descendants().any(|node| {
+        if let Some(access) = PropertyAccess::cast(node.clone()) {
+            return access.name().is_some_and(|member| member.syntax().value() == name);
+        }
+        if let Some(access) = IndexedAccess::cast(node) {
+            return access.index().is_some_and(|index| {
+                index.syntax().kind() == SyntaxKind::StringLiteral && index.syntax().value() == name
+            });
+        }
+        return false;
+    });
+}
+
+// The identifier an entity name ends with
+fn get_entity_name_identifier(name: &EntityName) -> Option<SyntaxNode> {
+    match name {
+        EntityName::Identifier(identifier) => return Some(identifier.syntax().clone()),
+        EntityName::QualifiedName(name) => return name.right().map(|right| right.syntax().clone()),
+    }
+}
+
+// The meaning the entity name node, an identifier or qualified name, refers to. The left side of a
+// qualified name is a module.
+fn get_entity_meaning(node: &SyntaxNode) -> u32 {
+    let owner = match owner(node) {
+        Some(owner) => owner,
+        None => return symbol_flags::VALUE,
+    };
+    match owner.kind() {
+        SyntaxKind::QualifiedName => {
+            let qualified = QualifiedName::cast(owner.clone()).unwrap();
+            if qualified.left().is_some_and(|left| left.syntax() == node) {
+                return symbol_flags::NAMESPACE;
+            }
+            return get_entity_meaning(&owner);
+        }
+        SyntaxKind::TypeReference => return symbol_flags::TYPE,
+        SyntaxKind::ImportDeclaration | SyntaxKind::ExportAssignment | SyntaxKind::ExportSpecifier => {
+            return symbol_flags::VALUE | symbol_flags::TYPE | symbol_flags::NAMESPACE;
+        }
+        _ => return symbol_flags::VALUE,
+    }
+}
+
+// `typeof a.b` names a value, its qualified name is a property access
+fn is_in_type_query(node: &SyntaxNode) -> bool {
+    return node
+        .ancestors()
+        .take_while(|ancestor| matches!(ancestor.kind(), SyntaxKind::QualifiedName | SyntaxKind::TypeQuery))
+        .any(|ancestor| ancestor.kind() == SyntaxKind::TypeQuery);
+}
+
+fn is_in_with_statement_body(node: &SyntaxNode) -> bool {
+    let mut child = node.clone();
+    for ancestor in node.ancestors() {
+        let body = WithStatement::cast(ancestor.clone()).and_then(|statement| statement.statement());
+        if body.is_some_and(|body| body.syntax() == &child) {
+            return true;
+        }
+        child = ancestor;
+    }
+    return false;
+}
+
+// Resolves `.` and `..` in a path relative to the directory of the file importing it
+fn normalize_path(path: &str) -> String {
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split('/') {
+        match component {
+            "." | "" => {}
+            ".." if components.last().is_some_and(|last| *last != "..") => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    return components.join("/");
+}
+
+// Names and constraints of the type parameters of an interface declaration
+fn get_type_parameter_texts(node: &SyntaxNode) -> Vec<(String, Option<String>)> {
+    let interface = match InterfaceDeclaration::cast(node.clone()) {
+        Some(interface) => interface,
+        None => return Vec::new(),
+    };
+    return interface
+        .type_parameters()
+        .map(|parameter| {
+            let name = parameter.name().map_or(String::new(), |name| name.text().to_string());
+            (
+                name,
+                parameter.constraint().map(|constraint| constraint.syntax().text()),
+            )
+        })
+        .collect();
+}
+
+impl Checker {
+    pub fn symbol(&self, id: SymbolId) -> &Symbol {
+        return &self.symbols[id.index()];
+    }
+
+    // Global declarations of all files that are not external modules
+    pub fn globals(&self) -> &SymbolTable {
+        return &self.globals;
+    }
+
+    pub fn get_locals(&self, node: &SyntaxNode) -> Option<&SymbolTable> {
+        return self.locals.get(node);
+    }
+
+    // The symbol holding every declaration merged with those of symbol
+    pub fn get_merged_symbol(&self, id: SymbolId) -> SymbolId {
+        return self.merged_symbols.get(&id).copied().unwrap_or(id);
+    }
+
+    pub fn get_symbol_of_node(&self, node: &SyntaxNode) -> Option<SymbolId> {
+        return self.node_symbols.get(node).map(|&id| self.get_merged_symbol(id));
+    }
+
+    // The symbol an identifier, a qualified name or the property access of a module member refers
+    // to. Names that cannot be resolved and names of declarations have none.
+    pub fn resolve_name(&self, node: &SyntaxNode) -> Option<SymbolId> {
+        let identifier = match node.kind() {
+            SyntaxKind::QualifiedName => QualifiedName::cast(node.clone())?.right()?.syntax().clone(),
+            SyntaxKind::PropertyAccess => PropertyAccess::cast(node.clone())?.name()?.syntax().clone(),
+            _ => node.clone(),
+        };
+        return self.resolutions.get(&identifier).copied().flatten();
+    }
+
+    // The symbol an import refers to, the module itself for `import x = require("m")`
+    pub fn get_import_target(&self, id: SymbolId) -> Option<SymbolId> {
+        return self.import_targets.get(&id).copied().flatten();
+    }
+
+    // Errors of the binder and the checker in all files, sorted by file and position
+    pub fn get_diagnostics(&self) -> &[Diagnostic] {
+        return &self.diagnostics;
+    }
+
+    // Binds file and moves its symbols after those of the files before it
+    fn add_file(&mut self, file: &ParsedSourceFile) {
+        let bound = bind_source_file(file);
+        let offset = self.symbols.len();
+        let shift = |id: SymbolId| SymbolId::new(id.index() + offset);
+        let shift_table = |table: SymbolTable| -> SymbolTable {
+            return table.into_iter().map(|(name, id)| (name, shift(id))).collect();
+        };
+        for symbol in bound.symbols {
+            self.symbols.push(Symbol {
+                parent: symbol.parent.map(shift),
+                members: shift_table(symbol.members),
+                exports: shift_table(symbol.exports),
+                ..symbol
+            });
+        }
+        for (node, table) in bound.locals {
+            self.locals.insert(node, shift_table(table));
+        }
+        for (node, id) in bound.node_symbols {
+            self.node_symbols.insert(node, shift(id));
+        }
+        self.diagnostics.extend(bound.diagnostics);
+        self.files.push(CheckedFile {
+            root: file.root.clone(),
+            file_name: file.file_name.clone(),
+            is_declaration_file: file.is_declaration_file,
+            file_symbol: bound.file_symbol.map(shift),
+        });
+    }
+
+    // `arguments` and `undefined`, which every program has without declaring them
+    fn create_intrinsic_symbol(&mut self, name: &str) -> SymbolId {
+        let id = SymbolId::new(self.symbols.len());
+        self.symbols.push(Symbol {
+            name: name.to_string(),
+            flags: symbol_flags::VARIABLE,
+            declarations: Vec::new(),
+            parent: None,
+            members: SymbolTable::new(),
+            exports: SymbolTable::new(),
+        });
+        return id;
+    }
+
+    fn get_file(&self, node: &SyntaxNode) -> &CheckedFile {
+        let root = node.root();
+        return self.files.iter().find(|file| file.root == root).unwrap();
+    }
+
+    fn get_file_index(&self, node: &SyntaxNode) -> usize {
+        let root = node.root();
+        return self.files.iter().position(|file| file.root == root).unwrap();
+    }
+
+    fn is_in_ambient_context(&self, node: &SyntaxNode) -> bool {
+        return self.get_file(node).is_declaration_file
+            || node
+                .ancestors()
+                .chain(Some(node.clone()))
+                .any(|ancestor| has_modifier(&ancestor, SyntaxKind::DeclareKeyword));
+    }
+
+    fn error(&mut self, node: &SyntaxNode, message: &DiagnosticMessage, args: &[&str]) {
+        let start = node.token_pos();
+        let length = node.end() - start;
+        let file_name = self.get_file(node).file_name.clone();
+        self.diagnostics
+            .push(Diagnostic::create(&file_name, start, length, message, args));
+    }
+
+    // Errors about a declaration are reported at its name
+    fn error_at_declaration(&mut self, node: &SyntaxNode, message: &DiagnosticMessage, args: &[&str]) {
+        let location = get_declaration_name(node).unwrap_or_else(|| node.clone());
+        self.error(&location, message, args);
+    }
+
+    fn clone_symbol(&mut self, id: SymbolId) -> SymbolId {
+        let mut symbol = self.symbols[id.index()].clone();
+        symbol.flags |= symbol_flags::MERGED;
+        let merged = SymbolId::new(self.symbols.len());
+        self.symbols.push(symbol);
+        self.merged_symbols.insert(id, merged);
+        return merged;
+    }
+
+    // Adds the declarations of source to target, a merged symbol, or reports them as duplicates when
+    // they cannot go together
+    fn merge_symbol(&mut self, target: SymbolId, source: SymbolId) {
+        let source_symbol = self.symbols[source.index()].clone();
+        let excludes = symbol_flags::get_excluded_symbol_flags(source_symbol.flags);
+        if self.symbols[target.index()].flags & excludes != 0 {
+            for declaration in &source_symbol.declarations {
+                self.error_at_declaration(
+                    declaration,
+                    &diagnostics::DUPLICATE_IDENTIFIER_0,
+                    &[&source_symbol.name],
+                );
+            }
+            return;
+        }
+        let mut members = mem::take(&mut self.symbols[target.index()].members);
+        self.merge_symbol_table(&mut members, &source_symbol.members);
+        let mut exports = mem::take(&mut self.symbols[target.index()].exports);
+        self.merge_symbol_table(&mut exports, &source_symbol.exports);
+        let symbol = &mut self.symbols[target.index()];
+        symbol.flags |= source_symbol.flags;
+        symbol.declarations.extend(source_symbol.declarations);
+        symbol.members = members;
+        symbol.exports = exports;
+        self.merged_symbols.insert(source, target);
+    }
+
+    // Adds the symbols of source to target. A name in both gets a merged symbol, the symbols of the
+    // files are left as they were.
+    fn merge_symbol_table(&mut self, target: &mut SymbolTable, source: &SymbolTable) {
+        let mut names: Vec<&String> = source.keys().collect();
+        names.sort();
+        for name in names {
+            let id = source[name];
+            let existing = match target.get(name) {
+                Some(&existing) => existing,
+                None => {
+                    target.insert(name.clone(), id);
+                    continue;
+                }
+            };
+            let merged = if self.symbols[existing.index()].flags & symbol_flags::MERGED != 0 {
+                existing
+            } else {
+                let merged = self.clone_symbol(existing);
+                target.insert(name.clone(), merged);
+                merged
+            };
+            self.merge_symbol(merged, id);
+        }
+    }
+
+    fn check_declarations(&mut self) {
+        for index in 0..self.symbols.len() {
+            let id = SymbolId::new(index);
+            // Symbols that were merged are checked through the merged symbol
+            if self.merged_symbols.contains_key(&id) {
+                continue;
+            }
+            let flags = self.symbols[index].flags;
+            if flags & (symbol_flags::FUNCTION | symbol_flags::METHOD | symbol_flags::CONSTRUCTOR) != 0 {
+                self.check_function_or_constructor_symbol(id);
+            }
+            if flags & symbol_flags::VALUE_MODULE != 0 {
+                self.check_module_merged_with_class_or_function(id);
+            }
+            if flags & symbol_flags::INTERFACE != 0 {
+                self.check_interface_type_parameters(id);
+            }
+            if flags & symbol_flags::ENUM != 0 {
+                self.check_enum_first_initializers(id);
+            }
+        }
+        let containers: Vec<SyntaxNode> = self
+            .locals
+            .keys()
+            .filter(|node| matches!(node.kind(), SyntaxKind::SourceFile | SyntaxKind::ModuleDeclaration))
+            .cloned()
+            .collect();
+        for container in containers {
+            self.check_exports_on_merged_declarations(&container);
+        }
+    }
+
+    // The functions, methods or constructors declared by a symbol. Exported and local functions of a
+    // module are in different tables, when they share a name they still make one overload list.
+    fn get_overload_declarations(&self, id: SymbolId) -> Vec<SyntaxNode> {
+        let symbol = &self.symbols[id.index()];
+        let mut declarations: Vec<SyntaxNode> = symbol
+            .declarations
+            .iter()
+            .filter(|declaration| is_function_like(declaration.kind()))
+            .cloned()
+            .collect();
+        let mut others: Vec<SyntaxNode> = Vec::new();
+        for declaration in &declarations {
+            if declaration.kind() != SyntaxKind::FunctionDeclaration {
+                continue;
+            }
+            let container = match get_declaration_container(declaration) {
+                Some(container)
+                    if matches!(container.kind(), SyntaxKind::SourceFile | SyntaxKind::ModuleDeclaration) =>
+                {
+                    container
+                }
+                _ => continue,
+            };
+            let local = self.locals.get(&container).and_then(|locals| locals.get(&symbol.name));
+            let export = self
+                .node_symbols
+                .get(&container)
+                .and_then(|module| self.symbols[module.index()].exports.get(&symbol.name));
+            for &other in local.into_iter().chain(export) {
+                for other_declaration in &self.symbols[other.index()].declarations {
+                    if other_declaration.kind() == SyntaxKind::FunctionDeclaration
+                        && get_declaration_container(other_declaration).as_ref() == Some(&container)
+                        && !declarations.contains(other_declaration)
+                        && !others.contains(other_declaration)
+                    {
+                        others.push(other_declaration.clone());
+                    }
+                }
+            }
+        }
+        if !others.is_empty() {
+            declarations.extend(others);
+            declarations.sort_by_key(|declaration| (self.get_file_index(declaration), declaration.pos()));
+        }
+        return declarations;
+    }
+
+    // Overloads come right before the implementation, only ambient functions and signatures of
+    // interfaces have none
+    fn check_function_or_constructor_symbol(&mut self, id: SymbolId) {
+        let declarations = self.get_overload_declarations(id);
+        let mut body_declaration: Option<SyntaxNode> = None;
+        let mut has_duplicate_implementation = false;
+        let mut has_overloads = false;
+        let mut previous_declaration: Option<SyntaxNode> = None;
+        let mut last_seen_non_ambient_declaration: Option<SyntaxNode> = None;
+        for node in &declarations {
+            let in_ambient_context_or_interface = self.is_in_ambient_context(node)
+                || owner(node).is_some_and(|owner| {
+                    matches!(owner.kind(), SyntaxKind::InterfaceDeclaration | SyntaxKind::TypeLiteral)
+                });
+            if in_ambient_context_or_interface {
+                previous_declaration = None;
+            }
+            if let Some(previous) = previous_declaration.take() {
+                if previous.parent() == node.parent() && previous.end() != node.pos() {
+                    self.report_implementation_expected(&previous);
+                }
+            }
+            if has_body(node) {
+                if body_declaration.is_some() {
+                    has_duplicate_implementation = true;
+                } else {
+                    body_declaration = Some(node.clone());
+                }
+            } else {
+                has_overloads = true;
+                previous_declaration = Some(node.clone());
+            }
+            if !in_ambient_context_or_interface {
+                last_seen_non_ambient_declaration = Some(node.clone());
+            }
+        }
+        if has_duplicate_implementation {
+            for node in declarations.iter().filter(|node| has_body(node)) {
+                self.error_at_declaration(node, &diagnostics::DUPLICATE_FUNCTION_IMPLEMENTATION, &[]);
+            }
+        }
+        if let Some(last) = last_seen_non_ambient_declaration.filter(|last| !has_body(last)) {
+            self.report_implementation_expected(&last);
+        }
+        if has_overloads {
+            self.check_flag_agreement_between_overloads(&declarations, body_declaration.as_ref());
+        }
+    }
+
+    fn report_implementation_expected(&mut self, node: &SyntaxNode) {
+        let name = get_declaration_name(node);
+        if node.kind() != SyntaxKind::Constructor && name.as_ref().is_none_or(|name| name.is_missing()) {
+            return;
+        }
+        if let Some(subsequent) = node.next_sibling().filter(|next| next.kind() == node.kind()) {
+            let subsequent_name = get_declaration_name(&subsequent);
+            let error_node = subsequent_name.clone().unwrap_or_else(|| subsequent.clone());
+            match (&name, &subsequent_name) {
+                // Only a static and an instance method share a name but not a symbol
+                (Some(name), Some(subsequent_name)) if name.value() == subsequent_name.value() => {
+                    let is_static = has_modifier(node, SyntaxKind::StaticKeyword);
+                    if is_static != has_modifier(&subsequent, SyntaxKind::StaticKeyword) {
+                        let message = if is_static {
+                            &diagnostics::FUNCTION_OVERLOAD_MUST_BE_STATIC
+                        } else {
+                            &diagnostics::FUNCTION_OVERLOAD_MUST_NOT_BE_STATIC
+                        };
+                        self.error(&error_node, message, &[]);
+                        return;
+                    }
+                }
+                (Some(name), _) if has_body(&subsequent) => {
+                    self.error(
+                        &error_node,
+                        &diagnostics::FUNCTION_IMPLEMENTATION_NAME_MUST_BE_0,
+                        &[name.token_text()],
+                    );
+                    return;
+                }
+                _ => {}
+            }
+        }
+        if node.kind() == SyntaxKind::Constructor {
+            self.error_at_declaration(node, &diagnostics::CONSTRUCTOR_IMPLEMENTATION_IS_MISSING, &[]);
+        } else {
+            self.error_at_declaration(
+                node,
+                &diagnostics::FUNCTION_IMPLEMENTATION_IS_MISSING_OR_NOT_IMMEDIATELY_FOLLOWING_THE_DECLARATION,
+                &[],
+            );
+        }
+    }
+
+    // Declarations inside an ambient module are exported without saying so
+    fn get_effective_declaration_flags(&self, node: &SyntaxNode) -> u32 {
+        let mut flags = 0;
+        for (kind, flag) in [
+            (SyntaxKind::ExportKeyword, EXPORTED),
+            (SyntaxKind::DeclareKeyword, AMBIENT),
+            (SyntaxKind::PrivateKeyword, PRIVATE),
+            (SyntaxKind::ProtectedKeyword, PROTECTED),
+        ] {
+            if has_modifier(node, kind) {
+                flags |= flag;
+            }
+        }
+        let in_interface = owner(node).is_some_and(|owner| owner.kind() == SyntaxKind::InterfaceDeclaration);
+        if !in_interface && self.is_in_ambient_context(node) {
+            let in_module = get_declaration_container(node)
+                .is_some_and(|container| container.kind() == SyntaxKind::ModuleDeclaration);
+            if flags & AMBIENT == 0 && in_module {
+                flags |= EXPORTED;
+            }
+            flags |= AMBIENT;
+        }
+        return flags;
+    }
+
+    // Overloads that differ from the implementation, or from the first overload when the
+    // implementation is elsewhere, are reported
+    fn check_flag_agreement_between_overloads(
+        &mut self,
+        declarations: &[SyntaxNode],
+        implementation: Option<&SyntaxNode>,
+    ) {
+        let flags: Vec<u32> = declarations
+            .iter()
+            .map(|node| self.get_effective_declaration_flags(node))
+            .collect();
+        let some_flags = flags.iter().fold(0, |result, flags| result | flags);
+        let all_flags = flags.iter().fold(!0, |result, flags| result & flags);
+        if some_flags == all_flags {
+            return;
+        }
+        let canonical = match implementation {
+            Some(implementation) if implementation.parent() == declarations[0].parent() => implementation,
+            _ => &declarations[0],
+        };
+        let canonical_flags = self.get_effective_declaration_flags(canonical);
+        for (node, flags) in declarations.iter().zip(flags) {
+            let deviation = flags ^ canonical_flags;
+            let message = if deviation & EXPORTED != 0 {
+                &diagnostics::OVERLOAD_SIGNATURES_MUST_ALL_BE_EXPORTED_OR_NOT_EXPORTED
+            } else if deviation & AMBIENT != 0 {
+                &diagnostics::OVERLOAD_SIGNATURES_MUST_ALL_BE_AMBIENT_OR_NON_AMBIENT
+            } else if deviation & (PRIVATE | PROTECTED) != 0 {
+                &diagnostics::OVERLOAD_SIGNATURES_MUST_ALL_BE_PUBLIC_PRIVATE_OR_PROTECTED
+            } else {
+                continue;
+            };
+            self.error_at_declaration(node, message, &[]);
+        }
+    }
+
+    // A module merged with a class or function adds to it, so it has to come after it in the same file
+    fn check_module_merged_with_class_or_function(&mut self, id: SymbolId) {
+        let declarations = self.symbols[id.index()].declarations.clone();
+        let class_or_function = declarations.iter().find(|declaration| {
+            matches!(
+                declaration.kind(),
+                SyntaxKind::ClassDeclaration | SyntaxKind::FunctionDeclaration
+            ) && !self.is_in_ambient_context(declaration)
+        });
+        let class_or_function = match class_or_function {
+            Some(class_or_function) => class_or_function,
+            None => return,
+        };
+        for module in &declarations {
+            if module.kind() != SyntaxKind::ModuleDeclaration || self.is_in_ambient_context(module) {
+                continue;
+            }
+            if module.root() != class_or_function.root() {
+                self.error_at_declaration(
+                    module,
+                    &diagnostics::A_MODULE_DECLARATION_CANNOT_BE_IN_A_DIFFERENT_FILE_FROM_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED,
+                    &[],
+                );
+            } else if module.pos() < class_or_function.pos() {
+                self.error_at_declaration(
+                    module,
+                    &diagnostics::A_MODULE_DECLARATION_CANNOT_BE_LOCATED_PRIOR_TO_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED,
+                    &[],
+                );
+            }
+        }
+    }
+
+    fn check_interface_type_parameters(&mut self, id: SymbolId) {
+        let interfaces: Vec<SyntaxNode> = self.symbols[id.index()]
+            .declarations
+            .iter()
+            .filter(|declaration| declaration.kind() == SyntaxKind::InterfaceDeclaration)
+            .cloned()
+            .collect();
+        let first = match interfaces.first() {
+            Some(first) => get_type_parameter_texts(first),
+            None => return,
+        };
+        for interface in &interfaces[1..] {
+            if get_type_parameter_texts(interface) != first {
+                self.error_at_declaration(
+                    interface,
+                    &diagnostics::ALL_DECLARATIONS_OF_AN_INTERFACE_MUST_HAVE_IDENTICAL_TYPE_PARAMETERS,
+                    &[],
+                );
+            }
+        }
+    }
+
+    // Members without an initializer continue from the one before, only one declaration of an enum
+    // can start from zero
+    fn check_enum_first_initializers(&mut self, id: SymbolId) {
+        let declarations = self.symbols[id.index()].declarations.clone();
+        let mut seen_missing_initializer = false;
+        for declaration in declarations {
+            let first = EnumDeclaration::cast(declaration).and_then(|declaration| declaration.members().next());
+            let first = match first {
+                Some(first) if first.initializer().is_none() => first,
+                _ => continue,
+            };
+            if seen_missing_initializer {
+                self.error_at_declaration(
+                    first.syntax(),
+                    &diagnostics::IN_AN_ENUM_WITH_MULTIPLE_DECLARATIONS_ONLY_ONE_DECLARATION_CAN_OMIT_AN_INITIALIZER_FOR_ITS_FIRST_ENUM_ELEMENT,
+                    &[],
+                );
+            }
+            seen_missing_initializer = true;
+        }
+    }
+
+    // A name declared both exported and local in one module cannot have the same meaning in both. The
+    // declarations of whichever side comes second are reported, they are the ones that disagree.
+    // Functions alone make an overload list, whose overloads are checked to agree on `export` instead.
+    fn check_exports_on_merged_declarations(&mut self, container: &SyntaxNode) {
+        let exports = match self.node_symbols.get(container) {
+            Some(module) => &self.symbols[module.index()].exports,
+            None => return,
+        };
+        let mut errors = Vec::new();
+        for (name, local) in &self.locals[container] {
+            let export = match exports.get(name) {
+                Some(export) => export,
+                None => continue,
+            };
+            let exported: Vec<&SyntaxNode> = self.symbols[export.index()]
+                .declarations
+                .iter()
+                .filter(|declaration| get_declaration_container(declaration).as_ref() == Some(container))
+                .collect();
+            let local_declarations = &self.symbols[local.index()].declarations;
+            let exported_spaces = exported
+                .iter()
+                .fold(0, |spaces, declaration| spaces | get_declaration_spaces(declaration));
+            let local_spaces = local_declarations
+                .iter()
+                .fold(0, |spaces, declaration| spaces | get_declaration_spaces(declaration));
+            let common_spaces = exported_spaces & local_spaces;
+            if common_spaces == 0 {
+                continue;
+            }
+            let in_common_spaces = |declaration: &&SyntaxNode| get_declaration_spaces(declaration) & common_spaces != 0;
+            let local_declarations: Vec<&SyntaxNode> = local_declarations.iter().filter(in_common_spaces).collect();
+            let exported: Vec<&SyntaxNode> = exported.into_iter().filter(in_common_spaces).collect();
+            if local_declarations
+                .iter()
+                .chain(&exported)
+                .all(|declaration| declaration.kind() == SyntaxKind::FunctionDeclaration)
+            {
+                continue;
+            }
+            let first_pos = |declarations: &[&SyntaxNode]| declarations.iter().map(|node| node.pos()).min();
+            let disagreeing = if first_pos(&exported) < first_pos(&local_declarations) {
+                local_declarations
+            } else {
+                exported
+            };
+            for declaration in disagreeing {
+                errors.push((declaration.clone(), name.clone()));
+            }
+        }
+        for (declaration, name) in errors {
+            self.error_at_declaration(
+                &declaration,
+                &diagnostics::INDIVIDUAL_DECLARATIONS_IN_MERGED_DECLARATION_0_MUST_BE_ALL_EXPORTED_OR_ALL_LOCAL,
+                &[&name],
+            );
+        }
+    }
+
+    fn resolve_names(&mut self) {
+        let roots: Vec<SyntaxNode> = self.files.iter().map(|file| file.root.clone()).collect();
+        for root in roots {
+            for node in root.descendants() {
+                match node.kind() {
+                    SyntaxKind::Identifier => {
+                        self.get_resolved_symbol(&node);
+                    }
+                    SyntaxKind::WithStatement => {
+                        if let Some(expression) = WithStatement::cast(node).unwrap().expression() {
+                            self.error(
+                                expression.syntax(),
+                                &diagnostics::ALL_SYMBOLS_WITHIN_A_WITH_BLOCK_WILL_BE_RESOLVED_TO_ANY,
+                                &[],
+                            );
+                        }
+                    }
+                    SyntaxKind::ImportFromDeclaration | SyntaxKind::ExportDeclaration => {
+                        let name = match ImportFromDeclaration::cast(node.clone()) {
+                            Some(import) => import.module_specifier(),
+                            None => ExportDeclaration::cast(node).unwrap().module_specifier(),
+                        };
+                        if let Some(name) = name {
+                            self.resolve_external_module(name.syntax());
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        // Imports nothing refers to still have to refer to something
+        for index in 0..self.symbols.len() {
+            if self.symbols[index].flags & symbol_flags::IMPORT != 0 {
+                self.resolve_import(SymbolId::new(index));
+            }
+        }
+    }
+
+    // Whether symbol has one of the meanings, an import has those of what it imports
+    fn symbol_has_meaning(&mut self, id: SymbolId, meaning: u32) -> bool {
+        let flags = self.symbols[id.index()].flags;
+        if flags & meaning != 0 {
+            return true;
+        }
+        if flags & symbol_flags::IMPORT != 0 {
+            return self
+                .resolve_import(id)
+                .is_none_or(|target| self.symbols[target.index()].flags & meaning != 0);
+        }
+        return false;
+    }
+
+    // The symbol of name in a table, if it has the meaning
+    fn get_symbol(&mut self, id: Option<SymbolId>, meaning: u32) -> Option<SymbolId> {
+        let id = id?;
+        if self.symbol_has_meaning(id, meaning) {
+            return Some(id);
+        }
+        return None;
+    }
+
+    // The symbol of identifier, memoized, an identifier that takes part in its own resolution resolves
+    // to nothing
+    fn get_resolved_symbol(&mut self, identifier: &SyntaxNode) -> Option<SymbolId> {
+        if let Some(&result) = self.resolutions.get(identifier) {
+            return result;
+        }
+        self.resolutions.insert(identifier.clone(), None);
+        let result = self.resolve_identifier(identifier).map(|id| self.get_merged_symbol(id));
+        self.resolutions.insert(identifier.clone(), result);
+        return result;
+    }
+
+    fn resolve_identifier(&mut self, identifier: &SyntaxNode) -> Option<SymbolId> {
+        if identifier.is_missing() || is_in_with_statement_body(identifier) {
+            return None;
+        }
+        let parent = owner(identifier)?;
+        let name = identifier.value().to_string();
+        match parent.kind() {
+            SyntaxKind::QualifiedName => {
+                let qualified = QualifiedName::cast(parent.clone()).unwrap();
+                let left = qualified.left()?;
+                if left.syntax() == identifier {
+                    let meaning = if is_in_type_query(&parent) {
+                        symbol_flags::VALUE
+                    } else {
+                        symbol_flags::NAMESPACE
+                    };
+                    return self.resolve_name_in_scope(identifier, &name, meaning);
+                }
+                let namespace = self.get_resolved_symbol(&get_entity_name_identifier(&left)?)?;
+                if is_in_type_query(&parent) {
+                    return self.get_property_of_module(namespace, &name);
+                }
+                let meaning = get_entity_meaning(&parent);
+                return self.get_exported_member(namespace, &name, meaning, identifier, &left.syntax().text());
+            }
+            SyntaxKind::PropertyAccess => {
+                let access = PropertyAccess::cast(parent.clone()).unwrap();
+                if access.name().is_some_and(|property| property.syntax() == identifier) {
+                    let object = match access.expression()? {
+                        Expression::Identifier(object) => object.syntax().clone(),
+                        Expression::PropertyAccess(object) => object.name()?.syntax().clone(),
+                        _ => return None,
+                    };
+                    let namespace = self.get_resolved_symbol(&object)?;
+                    return self.get_property_of_module(namespace, &name);
+                }
+            }
+            SyntaxKind::LabelledStatement | SyntaxKind::BreakStatement | SyntaxKind::ContinueStatement => return None,
+            // The imported name of `a as b` is resolved with the import
+            SyntaxKind::ImportSpecifier => return None,
+            SyntaxKind::ExportSpecifier => {
+                let specifier = ExportSpecifier::cast(parent.clone()).unwrap();
+                let local = specifier.property_name().or_else(|| specifier.name());
+                if local.is_none_or(|local| local.syntax() != identifier) {
+                    return None;
+                }
+                let declaration = parent.ancestors().find_map(ExportDeclaration::cast)?;
+                if let Some(module_name) = declaration.module_specifier() {
+                    let module = self.resolve_external_module(module_name.syntax())?;
+                    let meaning = get_entity_meaning(identifier);
+                    return self.get_exported_member(
+                        module,
+                        &name,
+                        meaning,
+                        identifier,
+                        module_name.syntax().token_text(),
+                    );
+                }
+            }
+            _ => {}
+        }
+        if get_declaration_name(&parent).as_ref() == Some(identifier) {
+            return None;
+        }
+        return self.resolve_name_in_scope(identifier, &name, get_entity_meaning(identifier));
+    }
+
+    // Looks name up in the nodes around location, innermost first, then in the globals
+    fn resolve_name_in_scope(&mut self, location: &SyntaxNode, name: &str, meaning: u32) -> Option<SymbolId> {
+        for location in location.ancestors() {
+            let is_global_source_file =
+                location.kind() == SyntaxKind::SourceFile && self.get_file(&location).file_symbol.is_none();
+            if !is_global_source_file {
+                let local = self.locals.get(&location).and_then(|locals| locals.get(name)).copied();
+                if let Some(id) = self.get_symbol(local, meaning) {
+                    return Some(id);
+                }
+            }
+            match location.kind() {
+                SyntaxKind::SourceFile | SyntaxKind::ModuleDeclaration | SyntaxKind::EnumDeclaration => {
+                    let export = self
+                        .get_symbol_of_node(&location)
+                        .and_then(|id| self.symbols[id.index()].exports.get(name).copied());
+                    if let Some(id) = self.get_symbol(export, meaning) {
+                        return Some(id);
+                    }
+                }
+                SyntaxKind::FunctionExpression
+                    if FunctionExpression::cast(location.clone())
+                        .and_then(|function| function.name())
+                        .is_some_and(|function_name| function_name.text() == name) =>
+                {
+                    return self.get_symbol_of_node(&location);
+                }
+                _ => {}
+            }
+            let is_function = matches!(
+                location.kind(),
+                SyntaxKind::FunctionDeclaration
+                    | SyntaxKind::FunctionExpression
+                    | SyntaxKind::ArrowFunction
+                    | SyntaxKind::Method
+                    | SyntaxKind::Constructor
+                    | SyntaxKind::GetAccessor
+                    | SyntaxKind::SetAccessor
+            );
+            if is_function && name == "arguments" && meaning & symbol_flags::VALUE != 0 {
+                return Some(self.arguments_symbol);
+            }
+        }
+        let global = self.globals.get(name).copied();
+        if let Some(id) = self.get_symbol(global, meaning) {
+            return Some(id);
+        }
+        if name == "undefined" && meaning & symbol_flags::VALUE != 0 {
+            return Some(self.undefined_symbol);
+        }
+        self.error(location, &diagnostics::CANNOT_FIND_NAME_0, &[name]);
+        return None;
+    }
+
+    // What symbol stands for, following imports
+    fn resolve_alias(&mut self, id: SymbolId) -> Option<SymbolId> {
+        if self.symbols[id.index()].flags & symbol_flags::IMPORT != 0 {
+            return self.resolve_import(id);
+        }
+        return Some(self.get_merged_symbol(id));
+    }
+
+    // The exported member name of the module namespace, reported when it has none
+    fn get_exported_member(
+        &mut self,
+        namespace: SymbolId,
+        name: &str,
+        meaning: u32,
+        location: &SyntaxNode,
+        namespace_text: &str,
+    ) -> Option<SymbolId> {
+        let namespace = self.resolve_alias(namespace)?;
+        let export = self.symbols[namespace.index()].exports.get(name).copied();
+        if let Some(id) = self.get_symbol(export, meaning) {
+            return Some(id);
+        }
+        self.error(
+            location,
+            &diagnostics::MODULE_0_HAS_NO_EXPORTED_MEMBER_1,
+            &[namespace_text, name],
+        );
+        return None;
+    }
+
+    // `a.b` where a is a module, an enum or a class with static member b. The properties of other
+    // values depend on their types and are not resolved.
+    fn get_property_of_module(&mut self, object: SymbolId, name: &str) -> Option<SymbolId> {
+        let object = self.resolve_alias(object)?;
+        let flags = self.symbols[object.index()].flags;
+        if flags & (symbol_flags::VALUE_MODULE | symbol_flags::ENUM | symbol_flags::CLASS) == 0 {
+            return None;
+        }
+        let export = self.symbols[object.index()].exports.get(name).copied();
+        return self.get_symbol(export, symbol_flags::VALUE);
+    }
+
+    // The symbol an import refers to, memoized, an import that takes part in its own resolution
+    // refers to nothing
+    fn resolve_import(&mut self, id: SymbolId) -> Option<SymbolId> {
+        if let Some(&target) = self.import_targets.get(&id) {
+            return target;
+        }
+        self.import_targets.insert(id, None);
+        let declaration = self.symbols[id.index()].declarations.first()?.clone();
+        let target = match declaration.kind() {
+            SyntaxKind::ImportDeclaration => {
+                let import = ImportDeclaration::cast(declaration).unwrap();
+                if let Some(module_name) = import.external_module_name() {
+                    self.resolve_external_module(module_name.syntax())
+                        .map(|module| self.get_export_assignment_target(module).unwrap_or(module))
+                } else {
+                    let identifier = import.entity_name().and_then(|name| get_entity_name_identifier(&name));
+                    identifier
+                        .and_then(|identifier| self.get_resolved_symbol(&identifier))
+                        .and_then(|target| self.resolve_alias(target))
+                }
+            }
+            SyntaxKind::ImportClause | SyntaxKind::NamespaceImport | SyntaxKind::ImportSpecifier => {
+                self.resolve_import_from(&declaration)
+            }
+            _ => None,
+        };
+        let target = target.map(|target| self.get_merged_symbol(target));
+        self.import_targets.insert(id, target);
+        return target;
+    }
+
+    // The default export, the module or the named export an `import ... from "m"` declaration refers to
+    fn resolve_import_from(&mut self, declaration: &SyntaxNode) -> Option<SymbolId> {
+        let import = declaration.ancestors().find_map(ImportFromDeclaration::cast)?;
+        let module_name = import.module_specifier()?;
+        let module = self.resolve_external_module(module_name.syntax())?;
+        match declaration.kind() {
+            SyntaxKind::NamespaceImport => return Some(module),
+            SyntaxKind::ImportClause => {
+                let export = self.symbols[module.index()].exports.get("default").copied();
+                let meaning = symbol_flags::VALUE | symbol_flags::TYPE | symbol_flags::NAMESPACE;
+                let target = self.get_symbol(export, meaning);
+                if target.is_none() {
+                    self.error_at_declaration(
+                        declaration,
+                        &diagnostics::EXTERNAL_MODULE_0_HAS_NO_DEFAULT_EXPORT,
+                        &[module_name.syntax().token_text()],
+                    );
+                }
+                return target.and_then(|target| self.resolve_alias(target));
+            }
+            _ => {
+                let specifier = ImportSpecifier::cast(declaration.clone()).unwrap();
+                let name = specifier.property_name().or_else(|| specifier.name())?;
+                let meaning = symbol_flags::VALUE | symbol_flags::TYPE | symbol_flags::NAMESPACE;
+                let target = self.get_exported_member(
+                    module,
+                    name.text(),
+                    meaning,
+                    name.syntax(),
+                    module_name.syntax().token_text(),
+                )?;
+                return self.resolve_alias(target);
+            }
+        }
+    }
+
+    // The module of an external module name: an ambient `declare module "m"` or a file of the program,
+    // relative names are resolved from the directory of the file they are in
+    fn resolve_external_module(&mut self, name: &SyntaxNode) -> Option<SymbolId> {
+        if let Some(&module) = self.module_resolutions.get(name) {
+            return module;
+        }
+        let text = name.value().to_string();
+        let ambient = self.globals.get(&format!("\"{}\"", text)).copied();
+        let module = ambient.or_else(|| {
+            let path = if text.starts_with("./") || text.starts_with("../") {
+                let file_name = &self.get_file(name).file_name;
+                let directory = file_name.rsplit_once('/').map_or("", |(directory, _)| directory);
+                normalize_path(&format!("{}/{}", directory, text))
+            } else {
+                normalize_path(&text)
+            };
+            let module_name = format!("\"{}\"", path);
+            return self
+                .files
+                .iter()
+                .filter_map(|file| file.file_symbol)
+                .find(|&module| self.symbols[module.index()].name == module_name);
+        });
+        if module.is_none() {
+            self.error(name, &diagnostics::CANNOT_FIND_EXTERNAL_MODULE_0, &[&text]);
+        }
+        let module = module.map(|module| self.get_merged_symbol(module));
+        self.module_resolutions.insert(name.clone(), module);
+        return module;
+    }
+
+    // What `export = x` in the file or ambient declaration of module makes the module stand for
+    fn get_export_assignment_target(&mut self, module: SymbolId) -> Option<SymbolId> {
+        for declaration in self.symbols[module.index()].declarations.clone() {
+            let statements: Vec<Statement> = match declaration.kind() {
+                SyntaxKind::SourceFile => SourceFile::cast(declaration).unwrap().statements().collect(),
+                SyntaxKind::ModuleDeclaration => match ModuleDeclaration::cast(declaration).unwrap().body() {
+                    Some(ModuleBody::ModuleBlock(block)) => block.statements().collect(),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            for statement in statements {
+                if let Statement::ExportAssignment(assignment) = statement {
+                    let name = assignment.export_name()?;
+                    return self
+                        .get_resolved_symbol(name.syntax())
+                        .and_then(|target| self.resolve_alias(target));
+                }
+            }
+        }
+        return None;
+    }
+
+    fn check_unused_declarations(&mut self) {
+        let options = self.options.clone();
+        if options == CheckerOptions::default() {
+            return;
+        }
+        let mut read = HashSet::new();
+        let mut written = HashSet::new();
+        for (identifier, &id) in &self.resolutions {
+            if let Some(id) = id {
+                if is_write_access(identifier) {
+                    written.insert(id);
+                } else {
+                    read.insert(id);
+                }
+            }
+        }
+        let mut unused: Vec<(SyntaxNode, &DiagnosticMessage, String)> = Vec::new();
+        for (container, table) in &self.locals {
+            // The globals of scripts may be used by any other script, catch variables cannot be left out
+            if container.kind() == SyntaxKind::CatchBlock
+                || container.kind() == SyntaxKind::SourceFile && self.get_file(container).file_symbol.is_none()
+                || self.is_in_ambient_context(container)
+            {
+                continue;
+            }
+            let exports = self
+                .node_symbols
+                .get(container)
+                .map(|module| &self.symbols[module.index()].exports);
+            for &id in table.values() {
+                if read.contains(&id) {
+                    continue;
+                }
+                let symbol = &self.symbols[id.index()];
+                // A local merged with an exported declaration of its name is used through the export
+                if exports.is_some_and(|exports| exports.contains_key(&symbol.name)) {
+                    continue;
+                }
+                let message = if symbol.flags & symbol_flags::TYPE_PARAMETER != 0 {
+                    continue;
+                } else if symbol.flags & symbol_flags::PARAMETER != 0 {
+                    if !options.no_unused_parameters
+                        || symbol.name.starts_with('_')
+                        || !has_parameters_to_use(container)
+                        || symbol.declarations.iter().any(is_parameter_property)
+                    {
+                        continue;
+                    }
+                    &diagnostics::PARAMETER_0_IS_DECLARED_BUT_NEVER_USED
+                } else if symbol.flags & symbol_flags::IMPORT != 0 {
+                    if !options.no_unused_imports {
+                        continue;
+                    }
+                    &diagnostics::IMPORT_0_IS_DECLARED_BUT_NEVER_USED
+                } else if written.contains(&id) {
+                    if !options.no_write_only_variables || symbol.flags & symbol_flags::VARIABLE == 0 {
+                        continue;
+                    }
+                    &diagnostics::_0_IS_ASSIGNED_A_VALUE_BUT_NEVER_READ
+                } else {
+                    if !options.no_unused_locals {
+                        continue;
+                    }
+                    &diagnostics::_0_IS_DECLARED_BUT_NEVER_USED
+                };
+                for declaration in &symbol.declarations {
+                    unused.push((declaration.clone(), message, symbol.name.clone()));
+                }
+            }
+        }
+        if options.no_unused_private_members {
+            for symbol in &self.symbols {
+                if symbol.flags & symbol_flags::CLASS == 0 || symbol.flags & symbol_flags::MERGED != 0 {
+                    continue;
+                }
+                let class = match symbol
+                    .declarations
+                    .iter()
+                    .find(|node| node.kind() == SyntaxKind::ClassDeclaration)
+                {
+                    Some(class) if !self.is_in_ambient_context(class) => class,
+                    _ => continue,
+                };
+                for &id in symbol.members.values().chain(symbol.exports.values()) {
+                    let member = &self.symbols[id.index()];
+                    let is_private = member
+                        .declarations
+                        .iter()
+                        .all(|declaration| has_modifier(declaration, SyntaxKind::PrivateKeyword));
+                    if !is_private || is_member_accessed(class, &member.name) {
+                        continue;
+                    }
+                    for declaration in &member.declarations {
+                        unused.push((
+                            declaration.clone(),
+                            &diagnostics::PRIVATE_MEMBER_0_IS_DECLARED_BUT_NEVER_USED,
+                            member.name.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        for (declaration, message, name) in unused {
+            self.error_at_declaration(&declaration, message, &[&name]);
+        }
+    }
+}
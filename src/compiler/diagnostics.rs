@@ -0,0 +1,381 @@
+use super::types::{DiagnosticCategory, DiagnosticMessage};
+
+macro_rules! diagnostic {
+    ($name:ident, $code:expr, $category:ident, $message:expr) => {
+        pub const $name: DiagnosticMessage = DiagnosticMessage {
+            code: $code,
+            category: DiagnosticCategory::$category,
+            message: $message,
+        };
+    };
+}
+
+diagnostic!(UNTERMINATED_STRING_LITERAL, 1002, Error, "Unterminated string literal.");
+diagnostic!(IDENTIFIER_EXPECTED, 1003, Error, "Identifier expected.");
+diagnostic!(_0_EXPECTED, 1005, Error, "'{0}' expected.");
+diagnostic!(TRAILING_COMMA_NOT_ALLOWED, 1009, Error, "Trailing comma not allowed.");
+diagnostic!(ASTERISK_SLASH_EXPECTED, 1010, Error, "'*/' expected.");
+diagnostic!(
+    ACCESSIBILITY_MODIFIER_ALREADY_SEEN,
+    1028,
+    Error,
+    "Accessibility modifier already seen."
+);
+diagnostic!(
+    _0_MODIFIER_MUST_PRECEDE_1_MODIFIER,
+    1029,
+    Error,
+    "'{0}' modifier must precede '{1}' modifier."
+);
+diagnostic!(_0_MODIFIER_ALREADY_SEEN, 1030, Error, "'{0}' modifier already seen.");
+diagnostic!(
+    ONLY_AMBIENT_MODULES_CAN_USE_QUOTED_NAMES,
+    1035,
+    Error,
+    "Only ambient modules can use quoted names."
+);
+diagnostic!(
+    STATEMENTS_ARE_NOT_ALLOWED_IN_AMBIENT_CONTEXTS,
+    1036,
+    Error,
+    "Statements are not allowed in ambient contexts."
+);
+diagnostic!(
+    A_DECLARE_MODIFIER_CANNOT_BE_USED_IN_AN_ALREADY_AMBIENT_CONTEXT,
+    1038,
+    Error,
+    "A 'declare' modifier cannot be used in an already ambient context."
+);
+diagnostic!(
+    INITIALIZERS_ARE_NOT_ALLOWED_IN_AMBIENT_CONTEXTS,
+    1039,
+    Error,
+    "Initializers are not allowed in ambient contexts."
+);
+diagnostic!(
+    A_DECLARE_MODIFIER_IS_REQUIRED_FOR_A_TOP_LEVEL_DECLARATION_IN_A_D_TS_FILE,
+    1046,
+    Error,
+    "A 'declare' modifier is required for a top level declaration in a .d.ts file."
+);
+diagnostic!(
+    AMBIENT_ENUM_ELEMENTS_CAN_ONLY_HAVE_INTEGER_LITERAL_INITIALIZERS,
+    1066,
+    Error,
+    "Ambient enum elements can only have integer literal initializers."
+);
+diagnostic!(
+    UNEXPECTED_TOKEN_A_CONSTRUCTOR_METHOD_ACCESSOR_OR_PROPERTY_WAS_EXPECTED,
+    1068,
+    Error,
+    "Unexpected token. A constructor, method, accessor or property was expected."
+);
+diagnostic!(
+    AN_ACCESSOR_CANNOT_BE_DECLARED_IN_AN_AMBIENT_CONTEXT,
+    1086,
+    Error,
+    "An accessor cannot be declared in an ambient context."
+);
+diagnostic!(
+    _0_MODIFIER_CANNOT_APPEAR_ON_A_CONSTRUCTOR_DECLARATION,
+    1089,
+    Error,
+    "'{0}' modifier cannot appear on a constructor declaration."
+);
+diagnostic!(
+    _0_MODIFIER_CANNOT_APPEAR_ON_A_PARAMETER,
+    1090,
+    Error,
+    "'{0}' modifier cannot appear on a parameter."
+);
+diagnostic!(
+    INVALID_USE_OF_0_IN_STRICT_MODE,
+    1100,
+    Error,
+    "Invalid use of '{0}' in strict mode."
+);
+diagnostic!(
+    WITH_STATEMENTS_ARE_NOT_ALLOWED_IN_STRICT_MODE,
+    1101,
+    Error,
+    "'with' statements are not allowed in strict mode."
+);
+diagnostic!(
+    DELETE_CANNOT_BE_CALLED_ON_AN_IDENTIFIER_IN_STRICT_MODE,
+    1102,
+    Error,
+    "'delete' cannot be called on an identifier in strict mode."
+);
+diagnostic!(
+    A_CONTINUE_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_STATEMENT,
+    1104,
+    Error,
+    "A 'continue' statement can only be used within an enclosing iteration statement."
+);
+diagnostic!(
+    A_BREAK_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_OR_SWITCH_STATEMENT,
+    1105,
+    Error,
+    "A 'break' statement can only be used within an enclosing iteration or switch statement."
+);
+diagnostic!(
+    JUMP_TARGET_CANNOT_CROSS_FUNCTION_BOUNDARY,
+    1107,
+    Error,
+    "Jump target cannot cross function boundary."
+);
+diagnostic!(
+    A_RETURN_STATEMENT_CAN_ONLY_BE_USED_WITHIN_A_FUNCTION_BODY,
+    1108,
+    Error,
+    "A 'return' statement can only be used within a function body."
+);
+diagnostic!(EXPRESSION_EXPECTED, 1109, Error, "Expression expected.");
+diagnostic!(TYPE_EXPECTED, 1110, Error, "Type expected.");
+diagnostic!(DUPLICATE_LABEL_0, 1114, Error, "Duplicate label '{0}'");
+diagnostic!(
+    A_CONTINUE_STATEMENT_CAN_ONLY_JUMP_TO_A_LABEL_OF_AN_ENCLOSING_ITERATION_STATEMENT,
+    1115,
+    Error,
+    "A 'continue' statement can only jump to a label of an enclosing iteration statement."
+);
+diagnostic!(
+    A_BREAK_STATEMENT_CAN_ONLY_JUMP_TO_A_LABEL_OF_AN_ENCLOSING_STATEMENT,
+    1116,
+    Error,
+    "A 'break' statement can only jump to a label of an enclosing statement."
+);
+diagnostic!(
+    OCTAL_LITERALS_ARE_NOT_ALLOWED_IN_STRICT_MODE,
+    1121,
+    Error,
+    "Octal literals are not allowed in strict mode."
+);
+diagnostic!(UNEXPECTED_END_OF_TEXT, 1126, Error, "Unexpected end of text.");
+diagnostic!(INVALID_CHARACTER, 1127, Error, "Invalid character.");
+diagnostic!(
+    DECLARATION_OR_STATEMENT_EXPECTED,
+    1128,
+    Error,
+    "Declaration or statement expected."
+);
+diagnostic!(STATEMENT_EXPECTED, 1129, Error, "Statement expected.");
+diagnostic!(CASE_OR_DEFAULT_EXPECTED, 1130, Error, "'case' or 'default' expected.");
+diagnostic!(
+    PROPERTY_OR_SIGNATURE_EXPECTED,
+    1131,
+    Error,
+    "Property or signature expected."
+);
+diagnostic!(ENUM_MEMBER_EXPECTED, 1132, Error, "Enum member expected.");
+diagnostic!(TYPE_REFERENCE_EXPECTED, 1133, Error, "Type reference expected.");
+diagnostic!(
+    VARIABLE_DECLARATION_EXPECTED,
+    1134,
+    Error,
+    "Variable declaration expected."
+);
+diagnostic!(
+    ARGUMENT_EXPRESSION_EXPECTED,
+    1135,
+    Error,
+    "Argument expression expected."
+);
+diagnostic!(
+    PROPERTY_ASSIGNMENT_EXPECTED,
+    1136,
+    Error,
+    "Property assignment expected."
+);
+diagnostic!(
+    EXPRESSION_OR_COMMA_EXPECTED,
+    1137,
+    Error,
+    "Expression or comma expected."
+);
+diagnostic!(
+    PARAMETER_DECLARATION_EXPECTED,
+    1138,
+    Error,
+    "Parameter declaration expected."
+);
+diagnostic!(
+    TYPE_PARAMETER_DECLARATION_EXPECTED,
+    1139,
+    Error,
+    "Type parameter declaration expected."
+);
+diagnostic!(TYPE_ARGUMENT_EXPECTED, 1140, Error, "Type argument expected.");
+diagnostic!(STRING_LITERAL_EXPECTED, 1141, Error, "String literal expected.");
+diagnostic!(
+    LINE_BREAK_NOT_PERMITTED_HERE,
+    1142,
+    Error,
+    "Line break not permitted here."
+);
+diagnostic!(
+    MODIFIERS_CANNOT_APPEAR_HERE,
+    1145,
+    Error,
+    "Modifiers cannot appear here."
+);
+diagnostic!(DECLARATION_EXPECTED, 1146, Error, "Declaration expected.");
+diagnostic!(
+    UNTERMINATED_REGULAR_EXPRESSION_LITERAL,
+    1161,
+    Error,
+    "Unterminated regular expression literal."
+);
+diagnostic!(
+    AN_IMPLEMENTATION_CANNOT_BE_DECLARED_IN_AMBIENT_CONTEXTS,
+    1184,
+    Error,
+    "An implementation cannot be declared in ambient contexts."
+);
+diagnostic!(
+    EXTERNAL_MODULE_0_HAS_NO_DEFAULT_EXPORT,
+    1192,
+    Error,
+    "External module '{0}' has no default export."
+);
+diagnostic!(DUPLICATE_IDENTIFIER_0, 2300, Error, "Duplicate identifier '{0}'.");
+diagnostic!(CANNOT_FIND_NAME_0, 2304, Error, "Cannot find name '{0}'.");
+diagnostic!(
+    MODULE_0_HAS_NO_EXPORTED_MEMBER_1,
+    2305,
+    Error,
+    "Module '{0}' has no exported member '{1}'."
+);
+diagnostic!(
+    CANNOT_FIND_EXTERNAL_MODULE_0,
+    2307,
+    Error,
+    "Cannot find external module '{0}'."
+);
+diagnostic!(
+    A_PARAMETER_PROPERTY_IS_ONLY_ALLOWED_IN_A_CONSTRUCTOR_IMPLEMENTATION,
+    2369,
+    Error,
+    "A parameter property is only allowed in a constructor implementation."
+);
+diagnostic!(
+    OVERLOAD_SIGNATURES_MUST_ALL_BE_EXPORTED_OR_NOT_EXPORTED,
+    2383,
+    Error,
+    "Overload signatures must all be exported or not exported."
+);
+diagnostic!(
+    OVERLOAD_SIGNATURES_MUST_ALL_BE_AMBIENT_OR_NON_AMBIENT,
+    2384,
+    Error,
+    "Overload signatures must all be ambient or non-ambient."
+);
+diagnostic!(
+    OVERLOAD_SIGNATURES_MUST_ALL_BE_PUBLIC_PRIVATE_OR_PROTECTED,
+    2385,
+    Error,
+    "Overload signatures must all be public, private or protected."
+);
+diagnostic!(
+    FUNCTION_OVERLOAD_MUST_BE_STATIC,
+    2387,
+    Error,
+    "Function overload must be static."
+);
+diagnostic!(
+    FUNCTION_OVERLOAD_MUST_NOT_BE_STATIC,
+    2388,
+    Error,
+    "Function overload must not be static."
+);
+diagnostic!(
+    FUNCTION_IMPLEMENTATION_NAME_MUST_BE_0,
+    2389,
+    Error,
+    "Function implementation name must be '{0}'."
+);
+diagnostic!(
+    CONSTRUCTOR_IMPLEMENTATION_IS_MISSING,
+    2390,
+    Error,
+    "Constructor implementation is missing."
+);
+diagnostic!(
+    FUNCTION_IMPLEMENTATION_IS_MISSING_OR_NOT_IMMEDIATELY_FOLLOWING_THE_DECLARATION,
+    2391,
+    Error,
+    "Function implementation is missing or not immediately following the declaration."
+);
+diagnostic!(
+    DUPLICATE_FUNCTION_IMPLEMENTATION,
+    2393,
+    Error,
+    "Duplicate function implementation."
+);
+diagnostic!(
+    INDIVIDUAL_DECLARATIONS_IN_MERGED_DECLARATION_0_MUST_BE_ALL_EXPORTED_OR_ALL_LOCAL,
+    2395,
+    Error,
+    "Individual declarations in merged declaration {0} must be all exported or all local."
+);
+diagnostic!(
+    ALL_SYMBOLS_WITHIN_A_WITH_BLOCK_WILL_BE_RESOLVED_TO_ANY,
+    2410,
+    Error,
+    "All symbols within a 'with' block will be resolved to 'any'."
+);
+diagnostic!(
+    ALL_DECLARATIONS_OF_AN_INTERFACE_MUST_HAVE_IDENTICAL_TYPE_PARAMETERS,
+    2428,
+    Error,
+    "All declarations of an interface must have identical type parameters."
+);
+diagnostic!(
+    IN_AN_ENUM_WITH_MULTIPLE_DECLARATIONS_ONLY_ONE_DECLARATION_CAN_OMIT_AN_INITIALIZER_FOR_ITS_FIRST_ENUM_ELEMENT,
+    2432,
+    Error,
+    "In an enum with multiple declarations, only one declaration can omit an initializer for its first enum element."
+);
+diagnostic!(
+    A_MODULE_DECLARATION_CANNOT_BE_IN_A_DIFFERENT_FILE_FROM_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED,
+    2433,
+    Error,
+    "A module declaration cannot be in a different file from a class or function with which it is merged"
+);
+diagnostic!(
+    A_MODULE_DECLARATION_CANNOT_BE_LOCATED_PRIOR_TO_A_CLASS_OR_FUNCTION_WITH_WHICH_IT_IS_MERGED,
+    2434,
+    Error,
+    "A module declaration cannot be located prior to a class or function with which it is merged"
+);
+diagnostic!(
+    _0_IS_DECLARED_BUT_NEVER_USED,
+    6133,
+    Warning,
+    "'{0}' is declared but never used."
+);
+diagnostic!(
+    PARAMETER_0_IS_DECLARED_BUT_NEVER_USED,
+    6134,
+    Warning,
+    "Parameter '{0}' is declared but never used."
+);
+diagnostic!(
+    IMPORT_0_IS_DECLARED_BUT_NEVER_USED,
+    6135,
+    Warning,
+    "Import '{0}' is declared but never used."
+);
+diagnostic!(
+    PRIVATE_MEMBER_0_IS_DECLARED_BUT_NEVER_USED,
+    6136,
+    Warning,
+    "Private member '{0}' is declared but never used."
+);
+diagnostic!(
+    _0_IS_ASSIGNED_A_VALUE_BUT_NEVER_READ,
+    6137,
+    Warning,
+    "'{0}' is assigned a value but never read."
+);
+diagnostic!(CATCH_OR_FINALLY_EXPECTED, 1005, Error, "'catch' or 'finally' expected.");
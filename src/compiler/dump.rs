@@ -0,0 +1,273 @@
+use super::scanner::{compute_line_starts, get_line_and_character_of_position, Scanner};
+use super::syntax::SyntaxNode;
+use super::types::{Diagnostic, DiagnosticCategory, SourceFile, SyntaxKind, Trivia};
+
+// JSON dumps of the token stream and of syntax trees for tools that are not written in Rust.
+// The schema is documented in docs/json-output.md, bump SCHEMA_VERSION when it changes.
+
+pub const SCHEMA_VERSION: usize = 1;
+
+enum Json {
+    Bool(bool),
+    Number(usize),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn is_scalar(&self) -> bool {
+        match self {
+            Json::Array(elements) => elements.is_empty(),
+            Json::Object(members) => members.is_empty(),
+            _ => true,
+        }
+    }
+
+    // Objects and arrays holding only scalars are written on a single line, everything else has
+    // one member per line so dumps diff well
+    fn write(&self, out: &mut String, indent: usize) {
+        match self {
+            Json::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            Json::Number(value) => out.push_str(&value.to_string()),
+            Json::String(value) => write_string(out, value),
+            Json::Array(elements) => {
+                if elements.is_empty() {
+                    out.push_str("[]");
+                    return;
+                }
+                out.push('[');
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                    }
+                    new_line(out, indent + 1);
+                    element.write(out, indent + 1);
+                }
+                new_line(out, indent);
+                out.push(']');
+            }
+            Json::Object(members) => {
+                let single_line = members.iter().all(|(_, value)| value.is_scalar());
+                out.push('{');
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        out.push(',');
+                        if single_line {
+                            out.push(' ');
+                        }
+                    }
+                    if !single_line {
+                        new_line(out, indent + 1);
+                    }
+                    write_string(out, name);
+                    out.push_str(": ");
+                    value.write(out, indent + 1);
+                }
+                if !single_line {
+                    new_line(out, indent);
+                }
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn new_line(out: &mut String, indent: usize) {
+    out.push('\n');
+    for _ in 0..indent {
+        out.push_str("  ");
+    }
+}
+
+fn write_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => out.push(ch),
+        }
+    }
+    out.push('"');
+}
+
+fn to_string(json: &Json) -> String {
+    let mut out = String::new();
+    json.write(&mut out, 0);
+    out.push('\n');
+    return out;
+}
+
+// Members describing the text from pos to end, lines and columns are 1-based and count bytes
+fn span(line_starts: &[usize], pos: usize, end: usize) -> Vec<(&'static str, Json)> {
+    let (line, column) = get_line_and_character_of_position(line_starts, pos);
+    let (end_line, end_column) = get_line_and_character_of_position(line_starts, end);
+    return vec![
+        ("pos", Json::Number(pos)),
+        ("end", Json::Number(end)),
+        ("line", Json::Number(line)),
+        ("column", Json::Number(column)),
+        ("endLine", Json::Number(end_line)),
+        ("endColumn", Json::Number(end_column)),
+    ];
+}
+
+fn trivia_to_json(trivia: &[Trivia]) -> Json {
+    return Json::Array(
+        trivia
+            .iter()
+            .map(|trivia| {
+                Json::Object(vec![
+                    ("kind", Json::String(format!("{:?}", trivia.kind))),
+                    ("text", Json::String(trivia.text.clone())),
+                ])
+            })
+            .collect(),
+    );
+}
+
+// Members of a token following its span
+fn token_members(
+    members: &mut Vec<(&'static str, Json)>,
+    text: &str,
+    value: &str,
+    leading_trivia: &[Trivia],
+    kind: SyntaxKind,
+) {
+    members.push(("text", Json::String(text.to_string())));
+    if matches!(
+        kind,
+        SyntaxKind::Identifier
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::RegularExpressionLiteral
+    ) {
+        members.push(("value", Json::String(value.to_string())));
+    }
+    if !leading_trivia.is_empty() {
+        members.push(("leadingTrivia", trivia_to_json(leading_trivia)));
+    }
+}
+
+fn diagnostics_to_json(line_starts: &[usize], diagnostics: &[Diagnostic]) -> Json {
+    return Json::Array(
+        diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let category = match diagnostic.category {
+                    DiagnosticCategory::Warning => "warning",
+                    DiagnosticCategory::Error => "error",
+                    DiagnosticCategory::Message => "message",
+                };
+                let mut members = vec![
+                    ("code", Json::Number(diagnostic.code as usize)),
+                    ("category", Json::String(category.to_string())),
+                    ("message", Json::String(diagnostic.message_text.clone())),
+                ];
+                members.extend(span(
+                    line_starts,
+                    diagnostic.start,
+                    diagnostic.start + diagnostic.length,
+                ));
+                return Json::Object(members);
+            })
+            .collect(),
+    );
+}
+
+// The tokens the scanner produces for text, up to and including EndOfFileToken
+pub fn tokens_to_json(file_name: &str, text: &str) -> String {
+    let line_starts = compute_line_starts(text);
+    let mut scanner = Scanner::create_scanner(text);
+    let mut tokens = Vec::new();
+    let mut diagnostics = Vec::new();
+    loop {
+        let kind = scanner.scan();
+        let mut members = vec![("kind", Json::String(format!("{:?}", kind)))];
+        members.extend(span(&line_starts, scanner.get_token_pos(), scanner.get_text_pos()));
+        token_members(
+            &mut members,
+            &scanner.get_token_text(),
+            scanner.get_token_value(),
+            scanner.get_leading_trivia(),
+            kind,
+        );
+        tokens.push(Json::Object(members));
+        for (start, length, message) in scanner.take_errors() {
+            diagnostics.push(Diagnostic::create(file_name, start, length, &message, &[]));
+        }
+        if kind == SyntaxKind::EndOfFileToken {
+            break;
+        }
+    }
+    return to_string(&Json::Object(vec![
+        ("schemaVersion", Json::Number(SCHEMA_VERSION)),
+        ("fileName", Json::String(file_name.to_string())),
+        ("tokens", Json::Array(tokens)),
+        ("diagnostics", diagnostics_to_json(&line_starts, &diagnostics)),
+    ]));
+}
+
+fn node_to_json(line_starts: &[usize], node: &SyntaxNode) -> Json {
+    let mut members = vec![("kind", Json::String(format!("{:?}", node.kind())))];
+    members.extend(span(line_starts, node.token_pos(), node.end()));
+    members.push(("fullPos", Json::Number(node.pos())));
+    if node.is_missing() {
+        members.push(("missing", Json::Bool(true)));
+    }
+    if node.is_token() {
+        token_members(
+            &mut members,
+            node.token_text(),
+            node.value(),
+            node.leading_trivia(),
+            node.kind(),
+        );
+    } else {
+        let children = node.children().map(|child| node_to_json(line_starts, &child)).collect();
+        members.push(("children", Json::Array(children)));
+    }
+    return Json::Object(members);
+}
+
+// The lossless syntax tree of source_file and its parse diagnostics
+pub fn source_file_to_json(source_file: &SourceFile) -> String {
+    let line_starts = compute_line_starts(&source_file.text);
+    return to_string(&Json::Object(vec![
+        ("schemaVersion", Json::Number(SCHEMA_VERSION)),
+        ("fileName", Json::String(source_file.file_name.clone())),
+        ("root", node_to_json(&line_starts, &source_file.root)),
+        (
+            "diagnostics",
+            diagnostics_to_json(&line_starts, &source_file.parse_diagnostics),
+        ),
+    ]));
+}
+
+// The diagnostics of checking source_files together, grouped by the file they are in
+pub fn program_diagnostics_to_json(source_files: &[SourceFile], diagnostics: &[Diagnostic]) -> String {
+    let files = source_files
+        .iter()
+        .map(|source_file| {
+            let line_starts = compute_line_starts(&source_file.text);
+            let file_diagnostics: Vec<Diagnostic> = diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.file_name == source_file.file_name)
+                .cloned()
+                .collect();
+            return Json::Object(vec![
+                ("fileName", Json::String(source_file.file_name.clone())),
+                ("diagnostics", diagnostics_to_json(&line_starts, &file_diagnostics)),
+            ]);
+        })
+        .collect();
+    return to_string(&Json::Object(vec![
+        ("schemaVersion", Json::Number(SCHEMA_VERSION)),
+        ("files", Json::Array(files)),
+    ]));
+}
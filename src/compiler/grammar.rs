@@ -0,0 +1,340 @@
+use super::ast::*;
+use super::diagnostics;
+use super::precedence::is_assignment_operator;
+use super::syntax::SyntaxNode;
+use super::types::{Diagnostic, DiagnosticMessage, SourceFile as ParsedSourceFile, SyntaxKind};
+use super::visit::{walk, Visitor};
+use std::collections::HashSet;
+use std::mem;
+
+// Early errors that depend on where a construct appears and so cannot be reported while parsing:
+// strict mode restrictions, labels and jumps, and `return` outside of functions. Strict mode starts
+// with a "use strict" directive in the prologue of a file or function and covers everything nested.
+pub fn check_grammar(file: &ParsedSourceFile) -> Vec<Diagnostic> {
+    let mut checker = GrammarChecker {
+        file_name: &file.file_name,
+        diagnostics: Vec::new(),
+        in_strict_mode: false,
+        in_function: false,
+        targets: Vec::new(),
+        outer_targets: Vec::new(),
+    };
+    checker.visit_node(&file.root);
+    return checker.diagnostics;
+}
+
+// Whether statements start with a directive prologue holding "use strict"
+fn has_use_strict_directive(statements: AstChildren<Statement>) -> bool {
+    for statement in statements {
+        let expression = match statement {
+            Statement::ExpressionStatement(statement) => statement.expression(),
+            _ => return false,
+        };
+        let literal = match expression {
+            Some(Expression::LiteralExpression(literal)) if literal.syntax().kind() == SyntaxKind::StringLiteral => {
+                literal
+            }
+            _ => return false,
+        };
+        // The directive is recognized by its exact text, escapes make it an ordinary string
+        let text = literal.syntax().token_text();
+        if text == "\"use strict\"" || text == "'use strict'" {
+            return true;
+        }
+    }
+    return false;
+}
+
+// `017`, a leading zero followed by an octal digit
+fn is_octal_literal(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    return bytes.len() > 1 && bytes[0] == b'0' && (b'0'..=b'7').contains(&bytes[1]);
+}
+
+fn is_iteration_statement(statement: &Statement) -> bool {
+    return matches!(
+        statement,
+        Statement::DoStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+    );
+}
+
+fn is_eval_or_arguments(identifier: &Identifier) -> bool {
+    return identifier.text() == "eval" || identifier.text() == "arguments";
+}
+
+// What a break or continue can jump to
+#[derive(Debug, Clone)]
+enum JumpTarget {
+    Iteration,
+    Switch,
+    Label { name: String, is_iteration: bool },
+}
+
+struct GrammarChecker<'a> {
+    file_name: &'a str,
+    diagnostics: Vec<Diagnostic>,
+    in_strict_mode: bool,
+    in_function: bool,
+    targets: Vec<JumpTarget>,            // Enclosing statements in the current function
+    outer_targets: Vec<Vec<JumpTarget>>, // Enclosing statements in the functions around it
+}
+
+impl<'a> GrammarChecker<'a> {
+    fn error(&mut self, node: &SyntaxNode, message: &DiagnosticMessage, args: &[&str]) {
+        let start = node.token_pos();
+        let length = node.end() - start;
+        self.diagnostics
+            .push(Diagnostic::create(self.file_name, start, length, message, args));
+    }
+
+    fn check_strict_mode_name(&mut self, name: Option<Identifier>) {
+        if let Some(name) = name {
+            if self.in_strict_mode && is_eval_or_arguments(&name) {
+                self.error(
+                    name.syntax(),
+                    &diagnostics::INVALID_USE_OF_0_IN_STRICT_MODE,
+                    &[name.text()],
+                );
+            }
+        }
+    }
+
+    // Assignments and increments cannot target eval or arguments in strict mode
+    fn check_assignment_target(&mut self, target: Option<Expression>) {
+        if let Some(Expression::Identifier(identifier)) = target {
+            self.check_strict_mode_name(Some(identifier));
+        }
+    }
+
+    // Checks the name and parameters and walks a function with its own jump targets, the function
+    // is strict when its body is, body is None for arrow functions with an expression body
+    fn check_function(
+        &mut self,
+        node: &impl SignatureDeclaration,
+        name: Option<Identifier>,
+        body: Option<FunctionBlock>,
+    ) {
+        let in_strict_mode = self.in_strict_mode;
+        let in_function = mem::replace(&mut self.in_function, true);
+        self.in_strict_mode |= body.is_some_and(|body| has_use_strict_directive(body.statements()));
+        self.check_strict_mode_name(name);
+        if self.in_strict_mode {
+            let mut names = HashSet::new();
+            for parameter in node.parameters() {
+                if let Some(name) = parameter.name() {
+                    if !names.insert(name.text().to_string()) {
+                        self.error(name.syntax(), &diagnostics::DUPLICATE_IDENTIFIER_0, &[name.text()]);
+                    }
+                }
+            }
+        }
+        let targets = mem::take(&mut self.targets);
+        self.outer_targets.push(targets);
+        walk(self, node.syntax());
+        self.targets = self.outer_targets.pop().unwrap();
+        self.in_function = in_function;
+        self.in_strict_mode = in_strict_mode;
+    }
+
+    fn walk_with_target(&mut self, node: &SyntaxNode, target: JumpTarget) {
+        self.targets.push(target);
+        walk(self, node);
+        self.targets.pop();
+    }
+
+    fn check_jump(&mut self, node: &SyntaxNode, label: Option<Identifier>, is_continue: bool) {
+        let is_target = |target: &JumpTarget| match (target, &label) {
+            (JumpTarget::Iteration, None) => true,
+            (JumpTarget::Switch, None) => !is_continue,
+            (JumpTarget::Label { name, is_iteration }, Some(label)) => {
+                name == label.text() && (*is_iteration || !is_continue)
+            }
+            _ => false,
+        };
+        if self.targets.iter().any(is_target) {
+            return;
+        }
+        let message = if self.outer_targets.iter().flatten().any(is_target) {
+            &diagnostics::JUMP_TARGET_CANNOT_CROSS_FUNCTION_BOUNDARY
+        } else {
+            match (is_continue, label.is_some()) {
+                (true, true) => {
+                    &diagnostics::A_CONTINUE_STATEMENT_CAN_ONLY_JUMP_TO_A_LABEL_OF_AN_ENCLOSING_ITERATION_STATEMENT
+                }
+                (true, false) => {
+                    &diagnostics::A_CONTINUE_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_STATEMENT
+                }
+                (false, true) => &diagnostics::A_BREAK_STATEMENT_CAN_ONLY_JUMP_TO_A_LABEL_OF_AN_ENCLOSING_STATEMENT,
+                (false, false) => {
+                    &diagnostics::A_BREAK_STATEMENT_CAN_ONLY_BE_USED_WITHIN_AN_ENCLOSING_ITERATION_OR_SWITCH_STATEMENT
+                }
+            }
+        };
+        self.error(node, message, &[]);
+    }
+}
+
+impl<'a> Visitor for GrammarChecker<'a> {
+    fn visit_source_file(&mut self, node: &SourceFile) {
+        self.in_strict_mode = has_use_strict_directive(node.statements());
+        walk(self, node.syntax());
+    }
+
+    fn visit_function_declaration(&mut self, node: &FunctionDeclaration) {
+        self.check_function(node, node.name(), node.body());
+    }
+
+    fn visit_function_expression(&mut self, node: &FunctionExpression) {
+        self.check_function(node, node.name(), node.body());
+    }
+
+    fn visit_arrow_function(&mut self, node: &ArrowFunction) {
+        let body = match node.body() {
+            Some(ArrowFunctionBody::FunctionBlock(block)) => Some(block),
+            _ => None,
+        };
+        self.check_function(node, None, body);
+    }
+
+    fn visit_method(&mut self, node: &Method) {
+        self.check_function(node, None, node.body());
+    }
+
+    fn visit_constructor(&mut self, node: &Constructor) {
+        self.check_function(node, None, node.body());
+    }
+
+    fn visit_get_accessor(&mut self, node: &GetAccessor) {
+        self.check_function(node, None, node.body());
+    }
+
+    fn visit_set_accessor(&mut self, node: &SetAccessor) {
+        self.check_function(node, None, node.body());
+    }
+
+    fn visit_parameter(&mut self, node: &Parameter) {
+        self.check_strict_mode_name(node.name());
+        walk(self, node.syntax());
+    }
+
+    fn visit_variable_declaration(&mut self, node: &VariableDeclaration) {
+        self.check_strict_mode_name(node.name());
+        walk(self, node.syntax());
+    }
+
+    fn visit_catch_block(&mut self, node: &CatchBlock) {
+        self.check_strict_mode_name(node.variable());
+        walk(self, node.syntax());
+    }
+
+    fn visit_binary_expression(&mut self, node: &BinaryExpression) {
+        if is_assignment_operator(node.operator()) {
+            self.check_assignment_target(node.left());
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_prefix_operator(&mut self, node: &PrefixOperator) {
+        match node.operator() {
+            SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken => self.check_assignment_target(node.operand()),
+            SyntaxKind::DeleteKeyword if self.in_strict_mode => {
+                if let Some(Expression::Identifier(identifier)) = node.operand() {
+                    self.error(
+                        identifier.syntax(),
+                        &diagnostics::DELETE_CANNOT_BE_CALLED_ON_AN_IDENTIFIER_IN_STRICT_MODE,
+                        &[],
+                    );
+                }
+            }
+            _ => {}
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_postfix_operator(&mut self, node: &PostfixOperator) {
+        self.check_assignment_target(node.operand());
+        walk(self, node.syntax());
+    }
+
+    fn visit_literal_expression(&mut self, node: &LiteralExpression) {
+        let token = node.syntax();
+        if self.in_strict_mode && token.kind() == SyntaxKind::NumericLiteral && is_octal_literal(token.token_text()) {
+            self.error(token, &diagnostics::OCTAL_LITERALS_ARE_NOT_ALLOWED_IN_STRICT_MODE, &[]);
+        }
+    }
+
+    fn visit_with_statement(&mut self, node: &WithStatement) {
+        if self.in_strict_mode {
+            self.error(
+                node.syntax(),
+                &diagnostics::WITH_STATEMENTS_ARE_NOT_ALLOWED_IN_STRICT_MODE,
+                &[],
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_return_statement(&mut self, node: &ReturnStatement) {
+        if !self.in_function {
+            self.error(
+                node.syntax(),
+                &diagnostics::A_RETURN_STATEMENT_CAN_ONLY_BE_USED_WITHIN_A_FUNCTION_BODY,
+                &[],
+            );
+        }
+        walk(self, node.syntax());
+    }
+
+    fn visit_do_statement(&mut self, node: &DoStatement) {
+        self.walk_with_target(node.syntax(), JumpTarget::Iteration);
+    }
+
+    fn visit_while_statement(&mut self, node: &WhileStatement) {
+        self.walk_with_target(node.syntax(), JumpTarget::Iteration);
+    }
+
+    fn visit_for_statement(&mut self, node: &ForStatement) {
+        self.walk_with_target(node.syntax(), JumpTarget::Iteration);
+    }
+
+    fn visit_for_in_statement(&mut self, node: &ForInStatement) {
+        self.walk_with_target(node.syntax(), JumpTarget::Iteration);
+    }
+
+    fn visit_switch_statement(&mut self, node: &SwitchStatement) {
+        self.walk_with_target(node.syntax(), JumpTarget::Switch);
+    }
+
+    // `a: b: while (x) continue a;` continues the loop both labels stand for
+    fn visit_labelled_statement(&mut self, node: &LabelledStatement) {
+        let label = match node.label() {
+            Some(label) => label,
+            None => return walk(self, node.syntax()),
+        };
+        let name = label.text().to_string();
+        let is_duplicate = self
+            .targets
+            .iter()
+            .any(|target| matches!(target, JumpTarget::Label { name: other, .. } if *other == name));
+        if is_duplicate {
+            self.error(label.syntax(), &diagnostics::DUPLICATE_LABEL_0, &[&name]);
+        }
+        let mut statement = node.statement();
+        while let Some(Statement::LabelledStatement(labelled)) = statement {
+            statement = labelled.statement();
+        }
+        let is_iteration = statement.is_some_and(|statement| is_iteration_statement(&statement));
+        self.walk_with_target(node.syntax(), JumpTarget::Label { name, is_iteration });
+    }
+
+    fn visit_break_statement(&mut self, node: &BreakStatement) {
+        self.check_jump(node.syntax(), node.label(), false);
+    }
+
+    fn visit_continue_statement(&mut self, node: &ContinueStatement) {
+        self.check_jump(node.syntax(), node.label(), true);
+    }
+}
@@ -1,3 +1,14 @@
+pub mod arena;
+pub mod ast;
+pub mod binder;
+pub mod checker;
+pub mod diagnostics;
+pub mod dump;
+pub mod grammar;
 pub mod parse;
+pub mod precedence;
+pub mod printer;
 pub mod scanner;
+pub mod syntax;
 pub mod types;
+pub mod visit;
@@ -1 +1,2098 @@
+use super::diagnostics;
+use super::precedence::{
+    get_associativity, get_binary_operator_precedence, get_expression_precedence, get_postfix_operator_precedence,
+    get_prefix_operator_precedence, Associativity, Precedence,
+};
+use super::scanner::{token_to_string, Scanner};
+use super::syntax::{GreenNode, SyntaxNode};
+use super::types::{syntax_kind, Diagnostic, DiagnosticMessage, Directive, SourceFile, SyntaxKind, TextChange};
 
+fn is_declaration_file_name(file_name: &str) -> bool {
+    return file_name.ends_with(".d.ts");
+}
+
+pub fn create_source_file(file_name: &str, text: &str) -> SourceFile {
+    return Parser::new(file_name, text).finish_source_file(text);
+}
+
+// Parses new_text, the result of applying change to the text of source_file, reusing the subtrees of
+// source_file the change cannot affect
+pub fn update_source_file(source_file: &SourceFile, new_text: &str, change: TextChange) -> SourceFile {
+    debug_assert_eq!(
+        new_text.len(),
+        source_file.text.len() - change.old_length + change.new_length
+    );
+    let mut parser = Parser::new(&source_file.file_name, new_text);
+    parser.syntax_cursor = Some(SyntaxCursor::new(source_file, change));
+    return parser.finish_source_file(new_text);
+}
+
+// Finds the nodes of an old tree that can be reused at a position of the new text
+struct SyntaxCursor {
+    old_text: String,
+    root: SyntaxNode,
+    diagnostics: Vec<Diagnostic>,
+    directives: Vec<Directive>,
+    change: TextChange,
+    is_declaration_file: bool,
+    // Nodes before the change must end before this position, the parser may have looked at the two
+    // tokens preceding the change when it decided where they end
+    affected_start: usize,
+}
+
+impl SyntaxCursor {
+    fn new(source_file: &SourceFile, change: TextChange) -> Self {
+        let preceding_tokens: Vec<usize> = source_file
+            .root
+            .tokens()
+            .filter(|token| !token.token_text().is_empty())
+            .map(|token| token.token_pos())
+            .take_while(|&token_pos| token_pos < change.start)
+            .collect();
+        let affected_start = match preceding_tokens.len() {
+            0 => 0,
+            1 => preceding_tokens[0],
+            count => preceding_tokens[count - 2],
+        };
+        return SyntaxCursor {
+            old_text: source_file.text.clone(),
+            root: source_file.root.clone(),
+            diagnostics: source_file.parse_diagnostics.clone(),
+            directives: source_file.directives.clone(),
+            change,
+            is_declaration_file: source_file.is_declaration_file,
+            affected_start: affected_start.min(change.start),
+        };
+    }
+
+    // An element of a list of the given context that starts at pos in the new text
+    fn find_reusable_node(&self, pos: usize, context: ParsingContext) -> Option<GreenNode> {
+        let old_pos = if pos < self.affected_start {
+            pos
+        } else if pos >= self.change.new_end() {
+            pos - self.change.new_length + self.change.old_length
+        } else {
+            return None;
+        };
+        let mut node = self.root.clone();
+        'descend: loop {
+            for child in node.children() {
+                if child.pos() > old_pos {
+                    return None;
+                }
+                if child.end() <= old_pos {
+                    continue;
+                }
+                if child.pos() == old_pos
+                    && list_context(&node).is_some_and(|list| is_same_list_context(list, context))
+                    && self.is_reusable(&child)
+                {
+                    return Some(child.green().clone());
+                }
+                if child.is_token() {
+                    return None;
+                }
+                node = child;
+                continue 'descend;
+            }
+            return None;
+        }
+    }
+
+    // Nodes are reused when they are error free and neither they nor the token following them is
+    // affected by the change. Nodes in ambient contexts are never reused, the errors reported for them
+    // depend on the context.
+    fn is_reusable(&self, node: &SyntaxNode) -> bool {
+        if node.is_token() || (node.end() > self.affected_start && node.pos() < self.change.old_end()) {
+            return false;
+        }
+        if self.is_declaration_file || node.ancestors().any(|ancestor| has_declare_modifier(&ancestor)) {
+            return false;
+        }
+        if node.descendants().any(|descendant| descendant.is_missing()) {
+            return false;
+        }
+        let mut scanner = Scanner::create_scanner(&self.old_text);
+        scanner.set_text_pos(node.end());
+        scanner.scan();
+        let next_token_pos = scanner.get_token_pos();
+        if self
+            .diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.start >= node.pos() && diagnostic.start <= next_token_pos)
+        {
+            return false;
+        }
+        // Directives are collected by the scanner, which does not see the text of reused nodes
+        return !self
+            .directives
+            .iter()
+            .any(|directive| directive.end > node.pos() && directive.pos < node.end());
+    }
+}
+
+fn has_declare_modifier(node: &SyntaxNode) -> bool {
+    return node.children().any(|child| child.kind() == SyntaxKind::DeclareKeyword);
+}
+
+// Context of a list in a parsed tree
+fn list_context(list: &SyntaxNode) -> Option<ParsingContext> {
+    if list.kind() != SyntaxKind::SyntaxList {
+        return None;
+    }
+    let follows_open_brace = || list.prev_sibling().map(|sibling| sibling.kind()) == Some(SyntaxKind::OpenBraceToken);
+    match list.parent()?.kind() {
+        SyntaxKind::SourceFile => Some(ParsingContext::SourceElements),
+        SyntaxKind::ModuleBlock => Some(ParsingContext::ModuleElements),
+        SyntaxKind::Block
+        | SyntaxKind::FunctionBlock
+        | SyntaxKind::TryBlock
+        | SyntaxKind::CatchBlock
+        | SyntaxKind::FinallyBlock => Some(ParsingContext::BlockStatements),
+        SyntaxKind::CaseClause | SyntaxKind::DefaultClause => Some(ParsingContext::SwitchClauseStatements),
+        SyntaxKind::SwitchStatement => Some(ParsingContext::SwitchClauses),
+        SyntaxKind::ClassDeclaration if follows_open_brace() => Some(ParsingContext::ClassMembers),
+        SyntaxKind::InterfaceDeclaration if follows_open_brace() => Some(ParsingContext::TypeMembers),
+        SyntaxKind::TypeLiteral => Some(ParsingContext::TypeMembers),
+        _ => None,
+    }
+}
+
+// Lists of statements all parse their elements with parse_statement
+fn is_same_list_context(a: ParsingContext, b: ParsingContext) -> bool {
+    let is_statements = |context| {
+        matches!(
+            context,
+            ParsingContext::SourceElements
+                | ParsingContext::ModuleElements
+                | ParsingContext::BlockStatements
+                | ParsingContext::SwitchClauseStatements
+        )
+    };
+    return a == b || (is_statements(a) && is_statements(b));
+}
+
+pub struct Parser<'a> {
+    scanner: Scanner<'a>,
+    file_name: String,
+    token: SyntaxKind,
+    parsing_context: u32, // Bit set of the ParsingContext of the lists being parsed
+    diagnostics: Vec<Diagnostic>,
+    syntax_cursor: Option<SyntaxCursor>, // Old tree when parsing incrementally
+    is_declaration_file: bool,
+    in_ambient_context: bool, // In a .d.ts file or a declaration with the `declare` modifier
+    parameter_properties: Vec<(usize, usize)>, // Accessibility modifiers of the parameter list being parsed
+}
+
+// Lists the parser can resynchronize at after an error
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ParsingContext {
+    SourceElements,
+    ModuleElements,
+    BlockStatements,
+    SwitchClauses,
+    SwitchClauseStatements,
+    TypeMembers,
+    ClassMembers,
+    EnumMembers,
+    BaseTypeReferences,
+    VariableDeclarations,
+    ArgumentExpressions,
+    ObjectLiteralMembers,
+    ArrayLiteralMembers,
+    Parameters,
+    TypeParameters,
+    TypeArguments,
+    ImportOrExportSpecifiers,
+    Count,
+}
+
+// Result of looking ahead at a construct that may need speculative parsing
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Tristate {
+    False,
+    True,
+    Unknown,
+}
+
+const PARSING_CONTEXTS: [ParsingContext; ParsingContext::Count as usize] = [
+    ParsingContext::SourceElements,
+    ParsingContext::ModuleElements,
+    ParsingContext::BlockStatements,
+    ParsingContext::SwitchClauses,
+    ParsingContext::SwitchClauseStatements,
+    ParsingContext::TypeMembers,
+    ParsingContext::ClassMembers,
+    ParsingContext::EnumMembers,
+    ParsingContext::BaseTypeReferences,
+    ParsingContext::VariableDeclarations,
+    ParsingContext::ArgumentExpressions,
+    ParsingContext::ObjectLiteralMembers,
+    ParsingContext::ArrayLiteralMembers,
+    ParsingContext::Parameters,
+    ParsingContext::TypeParameters,
+    ParsingContext::TypeArguments,
+    ParsingContext::ImportOrExportSpecifiers,
+];
+
+fn parsing_context_error(context: ParsingContext) -> &'static DiagnosticMessage {
+    match context {
+        ParsingContext::SourceElements | ParsingContext::ModuleElements => {
+            &diagnostics::DECLARATION_OR_STATEMENT_EXPECTED
+        }
+        ParsingContext::BlockStatements | ParsingContext::SwitchClauseStatements => &diagnostics::STATEMENT_EXPECTED,
+        ParsingContext::SwitchClauses => &diagnostics::CASE_OR_DEFAULT_EXPECTED,
+        ParsingContext::TypeMembers => &diagnostics::PROPERTY_OR_SIGNATURE_EXPECTED,
+        ParsingContext::ClassMembers => {
+            &diagnostics::UNEXPECTED_TOKEN_A_CONSTRUCTOR_METHOD_ACCESSOR_OR_PROPERTY_WAS_EXPECTED
+        }
+        ParsingContext::EnumMembers => &diagnostics::ENUM_MEMBER_EXPECTED,
+        ParsingContext::BaseTypeReferences => &diagnostics::TYPE_REFERENCE_EXPECTED,
+        ParsingContext::VariableDeclarations => &diagnostics::VARIABLE_DECLARATION_EXPECTED,
+        ParsingContext::ArgumentExpressions => &diagnostics::ARGUMENT_EXPRESSION_EXPECTED,
+        ParsingContext::ObjectLiteralMembers => &diagnostics::PROPERTY_ASSIGNMENT_EXPECTED,
+        ParsingContext::ArrayLiteralMembers => &diagnostics::EXPRESSION_OR_COMMA_EXPECTED,
+        ParsingContext::Parameters => &diagnostics::PARAMETER_DECLARATION_EXPECTED,
+        ParsingContext::TypeParameters => &diagnostics::TYPE_PARAMETER_DECLARATION_EXPECTED,
+        ParsingContext::ImportOrExportSpecifiers => &diagnostics::IDENTIFIER_EXPECTED,
+        ParsingContext::TypeArguments | ParsingContext::Count => &diagnostics::TYPE_ARGUMENT_EXPECTED,
+    }
+}
+
+// `1` or `-1`
+fn is_integer_literal(node: &GreenNode) -> bool {
+    let is_integer = |node: &GreenNode| {
+        node.kind() == SyntaxKind::NumericLiteral && node.text().bytes().all(|byte| byte.is_ascii_digit())
+    };
+    if node.kind() == SyntaxKind::PrefixOperator {
+        let children = node.children();
+        return children.len() == 2 && children[0].kind() == SyntaxKind::MinusToken && is_integer(&children[1]);
+    }
+    return is_integer(node);
+}
+
+fn is_left_hand_side_expression(node: &GreenNode) -> bool {
+    return matches!(
+        node.kind(),
+        SyntaxKind::PropertyAccess
+            | SyntaxKind::IndexedAccess
+            | SyntaxKind::NewExpression
+            | SyntaxKind::CallExpression
+            | SyntaxKind::ArrayLiteral
+            | SyntaxKind::ParenExpression
+            | SyntaxKind::ObjectLiteral
+            | SyntaxKind::FunctionExpression
+            | SyntaxKind::Identifier
+            | SyntaxKind::Missing
+            | SyntaxKind::RegularExpressionLiteral
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::ThisKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::SuperKeyword
+    );
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(file_name: &str, text: &'a str) -> Self {
+        let mut parser = Parser {
+            scanner: Scanner::create_scanner(text),
+            file_name: file_name.to_string(),
+            token: SyntaxKind::Unknown,
+            parsing_context: 0,
+            diagnostics: Vec::new(),
+            syntax_cursor: None,
+            is_declaration_file: is_declaration_file_name(file_name),
+            in_ambient_context: false,
+            parameter_properties: Vec::new(),
+        };
+        parser.next_token();
+        return parser;
+    }
+
+    fn finish_source_file(mut self, text: &str) -> SourceFile {
+        let root = SyntaxNode::new_root(self.parse_source_file());
+        return SourceFile {
+            file_name: self.file_name,
+            text: text.to_string(),
+            root,
+            is_declaration_file: self.is_declaration_file,
+            directives: self.scanner.get_directives().to_vec(),
+            parse_diagnostics: self.diagnostics,
+        };
+    }
+
+    pub fn parse_source_file(&mut self) -> GreenNode {
+        self.in_ambient_context = self.is_declaration_file;
+        let statements = self.parse_list(ParsingContext::SourceElements, Parser::parse_source_element);
+        let end_of_file = self.create_token_node();
+        return self.finish_node(SyntaxKind::SourceFile, vec![statements, end_of_file]);
+    }
+
+    fn next_token(&mut self) -> SyntaxKind {
+        self.token = self.scanner.scan();
+        for (start, length, message) in self.scanner.take_errors() {
+            self.parse_error_at(start, length, &message, &[]);
+        }
+        return self.token;
+    }
+
+    // Runs callback and restores the scanner to the current token afterwards
+    fn look_ahead<T>(&mut self, callback: impl FnOnce(&mut Parser<'a>) -> T) -> T {
+        let saved_scanner = self.scanner.clone();
+        let saved_token = self.token;
+        let result = callback(self);
+        self.scanner = saved_scanner;
+        self.token = saved_token;
+        return result;
+    }
+
+    // Runs callback speculatively, the parser is restored if it returns None or reports an error
+    fn try_parse<T>(&mut self, callback: impl FnOnce(&mut Parser<'a>) -> Option<T>) -> Option<T> {
+        let saved_scanner = self.scanner.clone();
+        let saved_token = self.token;
+        let saved_diagnostics = std::mem::take(&mut self.diagnostics);
+        let result = callback(self).filter(|_| self.diagnostics.is_empty());
+        self.diagnostics = saved_diagnostics;
+        if result.is_none() {
+            self.scanner = saved_scanner;
+            self.token = saved_token;
+        }
+        return result;
+    }
+
+    fn parse_error_at_current(&mut self, message: &DiagnosticMessage, args: &[&str]) {
+        let start = self.scanner.get_token_pos();
+        let length = self.scanner.get_text_pos() - start;
+        self.parse_error_at(start, length, message, args);
+    }
+
+    // Only the first error at a position is reported, the others are usually caused by it
+    fn parse_error_at(&mut self, start: usize, length: usize, message: &DiagnosticMessage, args: &[&str]) {
+        if self.diagnostics.last().is_none_or(|last| last.start != start) {
+            self.diagnostics
+                .push(Diagnostic::create(&self.file_name, start, length, message, args));
+        }
+    }
+
+    // Creates a leaf for the current token and advances
+    fn create_token_node(&mut self) -> GreenNode {
+        let kind = self.token;
+        return self.create_token_node_of_kind(kind);
+    }
+
+    // Creates an identifier leaf for the current token, which may be a keyword
+    fn create_identifier_node(&mut self) -> GreenNode {
+        return self.create_token_node_of_kind(SyntaxKind::Identifier);
+    }
+
+    fn create_token_node_of_kind(&mut self, kind: SyntaxKind) -> GreenNode {
+        let value = match kind {
+            SyntaxKind::Identifier
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::RegularExpressionLiteral => self.scanner.get_token_value().to_string(),
+            _ => String::new(),
+        };
+        let node = GreenNode::new_token(
+            kind,
+            self.scanner.get_leading_trivia().to_vec(),
+            self.scanner.get_token_text(),
+            value,
+        );
+        self.next_token();
+        return node;
+    }
+
+    // A token the parser expected but did not find, it has no text
+    fn create_missing_token(&self, kind: SyntaxKind) -> GreenNode {
+        return GreenNode::new_token(kind, Vec::new(), String::new(), String::new());
+    }
+
+    // A node the parser expected but did not find, children are the tokens skipped in its place
+    fn create_missing_node(&self, children: Vec<GreenNode>) -> GreenNode {
+        return GreenNode::new_node(SyntaxKind::Missing, children);
+    }
+
+    fn finish_node(&self, kind: SyntaxKind, children: Vec<GreenNode>) -> GreenNode {
+        return GreenNode::new_node(kind, children);
+    }
+
+    fn parse_expected(&mut self, kind: SyntaxKind, children: &mut Vec<GreenNode>) -> bool {
+        if self.token == kind {
+            children.push(self.create_token_node());
+            return true;
+        }
+        self.parse_error_at_current(&diagnostics::_0_EXPECTED, &[token_to_string(kind).unwrap_or("")]);
+        children.push(self.create_missing_token(kind));
+        return false;
+    }
+
+    fn parse_optional(&mut self, kind: SyntaxKind, children: &mut Vec<GreenNode>) -> bool {
+        if self.token == kind {
+            children.push(self.create_token_node());
+            return true;
+        }
+        return false;
+    }
+
+    // A semicolon is automatically inserted before `}`, at the end of the file and after a line
+    // break, see ES5 7.9.1
+    fn can_parse_semicolon(&self) -> bool {
+        return self.token == SyntaxKind::SemicolonToken
+            || self.token == SyntaxKind::CloseBraceToken
+            || self.token == SyntaxKind::EndOfFileToken
+            || self.scanner.has_preceding_line_break();
+    }
+
+    fn parse_semicolon(&mut self, children: &mut Vec<GreenNode>) -> bool {
+        if self.can_parse_semicolon() {
+            self.parse_optional(SyntaxKind::SemicolonToken, children);
+            return true;
+        }
+        return self.parse_expected(SyntaxKind::SemicolonToken, children);
+    }
+
+    fn is_identifier(&self) -> bool {
+        return self.token == SyntaxKind::Identifier
+            || self.token as usize > syntax_kind::LAST_RESERVED_WORD as usize
+                && self.token as usize <= syntax_kind::LAST_KEYWORD as usize;
+    }
+
+    fn is_identifier_or_keyword(&self) -> bool {
+        return self.token as usize >= SyntaxKind::Identifier as usize
+            && self.token as usize <= syntax_kind::LAST_KEYWORD as usize;
+    }
+
+    fn is_property_name(&self) -> bool {
+        return self.is_identifier_or_keyword()
+            || self.token == SyntaxKind::StringLiteral
+            || self.token == SyntaxKind::NumericLiteral;
+    }
+
+    fn parse_identifier(&mut self) -> GreenNode {
+        if self.is_identifier() {
+            return self.create_identifier_node();
+        }
+        self.parse_error_at_current(&diagnostics::IDENTIFIER_EXPECTED, &[]);
+        return self.create_missing_token(SyntaxKind::Identifier);
+    }
+
+    fn parse_identifier_name(&mut self) -> GreenNode {
+        if self.is_identifier_or_keyword() {
+            return self.create_identifier_node();
+        }
+        self.parse_error_at_current(&diagnostics::IDENTIFIER_EXPECTED, &[]);
+        return self.create_missing_token(SyntaxKind::Identifier);
+    }
+
+    fn parse_property_name(&mut self) -> GreenNode {
+        if self.token == SyntaxKind::StringLiteral || self.token == SyntaxKind::NumericLiteral {
+            return self.create_token_node();
+        }
+        return self.parse_identifier_name();
+    }
+
+    fn parse_string_literal(&mut self) -> GreenNode {
+        if self.token == SyntaxKind::StringLiteral {
+            return self.create_token_node();
+        }
+        self.parse_error_at_current(&diagnostics::STRING_LITERAL_EXPECTED, &[]);
+        return self.create_missing_token(SyntaxKind::StringLiteral);
+    }
+
+    // Lists
+
+    fn is_list_element(&mut self, context: ParsingContext) -> bool {
+        match context {
+            ParsingContext::SourceElements
+            | ParsingContext::ModuleElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauseStatements => self.is_start_of_statement(),
+            ParsingContext::SwitchClauses => {
+                self.token == SyntaxKind::CaseKeyword || self.token == SyntaxKind::DefaultKeyword
+            }
+            ParsingContext::TypeMembers => self.is_type_member_start(),
+            ParsingContext::EnumMembers | ParsingContext::ObjectLiteralMembers => self.is_property_name(),
+            ParsingContext::ClassMembers => self.is_class_member_start(),
+            ParsingContext::BaseTypeReferences
+            | ParsingContext::VariableDeclarations
+            | ParsingContext::TypeParameters => self.is_identifier(),
+            ParsingContext::ArgumentExpressions => self.is_start_of_expression(),
+            ParsingContext::ArrayLiteralMembers => {
+                self.token == SyntaxKind::CommaToken || self.is_start_of_expression()
+            }
+            ParsingContext::Parameters => self.token == SyntaxKind::DotDotDotToken || self.is_identifier(),
+            ParsingContext::TypeArguments => self.is_start_of_type(),
+            ParsingContext::ImportOrExportSpecifiers => self.is_identifier_or_keyword(),
+            ParsingContext::Count => false,
+        }
+    }
+
+    fn is_list_terminator(&self, context: ParsingContext) -> bool {
+        if self.token == SyntaxKind::EndOfFileToken {
+            return true;
+        }
+        match context {
+            ParsingContext::SourceElements => false,
+            ParsingContext::ModuleElements
+            | ParsingContext::BlockStatements
+            | ParsingContext::SwitchClauses
+            | ParsingContext::TypeMembers
+            | ParsingContext::ClassMembers
+            | ParsingContext::EnumMembers
+            | ParsingContext::ObjectLiteralMembers
+            | ParsingContext::ImportOrExportSpecifiers => self.token == SyntaxKind::CloseBraceToken,
+            ParsingContext::SwitchClauseStatements => matches!(
+                self.token,
+                SyntaxKind::CloseBraceToken | SyntaxKind::CaseKeyword | SyntaxKind::DefaultKeyword
+            ),
+            ParsingContext::BaseTypeReferences => {
+                self.token == SyntaxKind::OpenBraceToken || self.token == SyntaxKind::ImplementsKeyword
+            }
+            ParsingContext::VariableDeclarations => self.can_parse_semicolon() || self.token == SyntaxKind::InKeyword,
+            ParsingContext::ArgumentExpressions => {
+                self.token == SyntaxKind::CloseParenToken || self.token == SyntaxKind::SemicolonToken
+            }
+            ParsingContext::ArrayLiteralMembers => self.token == SyntaxKind::CloseBracketToken,
+            ParsingContext::Parameters => {
+                self.token == SyntaxKind::CloseParenToken || self.token == SyntaxKind::CloseBracketToken
+            }
+            ParsingContext::TypeParameters => matches!(
+                self.token,
+                SyntaxKind::GreaterThanToken | SyntaxKind::OpenParenToken | SyntaxKind::OpenBraceToken
+            ),
+            ParsingContext::TypeArguments => self.token == SyntaxKind::GreaterThanToken,
+            ParsingContext::Count => true,
+        }
+    }
+
+    // Whether a list being parsed, including the current one, can continue at the current token
+    fn is_in_some_parsing_context(&mut self) -> bool {
+        for &context in PARSING_CONTEXTS.iter() {
+            if self.parsing_context & (1 << context as u32) != 0
+                && (self.is_list_element(context) || self.is_list_terminator(context))
+            {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    // Reports an unexpected token in a list. Returns true if the token belongs to an enclosing list,
+    // otherwise skips tokens until the parsing can continue and adds them as a Missing element.
+    fn abort_parsing_list_or_skip_tokens(&mut self, context: ParsingContext, elements: &mut Vec<GreenNode>) -> bool {
+        self.parse_error_at_current(parsing_context_error(context), &[]);
+        if self.is_in_some_parsing_context() {
+            return true;
+        }
+        let mut skipped = Vec::new();
+        while !self.is_in_some_parsing_context() {
+            skipped.push(self.create_token_node());
+        }
+        elements.push(self.create_missing_node(skipped));
+        return false;
+    }
+
+    fn parse_list(&mut self, context: ParsingContext, parse_element: fn(&mut Parser<'a>) -> GreenNode) -> GreenNode {
+        let saved_parsing_context = self.parsing_context;
+        self.parsing_context |= 1 << context as u32;
+        let mut elements = Vec::new();
+        while !self.is_list_terminator(context) {
+            if self.is_list_element(context) {
+                let start = self.scanner.get_start_pos();
+                if let Some(node) = self.reuse_list_element(context) {
+                    elements.push(node);
+                    continue;
+                }
+                elements.push(parse_element(self));
+                // An element that consumed nothing would be parsed again forever, skip its first token
+                if self.scanner.get_start_pos() == start && !self.is_list_terminator(context) {
+                    self.parse_error_at_current(parsing_context_error(context), &[]);
+                    let token = self.create_token_node();
+                    elements.push(self.create_missing_node(vec![token]));
+                }
+                continue;
+            }
+            if self.abort_parsing_list_or_skip_tokens(context, &mut elements) {
+                break;
+            }
+        }
+        self.parsing_context = saved_parsing_context;
+        return self.finish_node(SyntaxKind::SyntaxList, elements);
+    }
+
+    // Takes the node of the old tree at the current position when parsing incrementally
+    fn reuse_list_element(&mut self, context: ParsingContext) -> Option<GreenNode> {
+        if self.in_ambient_context {
+            return None;
+        }
+        let start = self.scanner.get_start_pos();
+        let node = self.syntax_cursor.as_ref()?.find_reusable_node(start, context)?;
+        self.scanner.set_text_pos(start + node.width());
+        self.next_token();
+        return Some(node);
+    }
+
+    fn parse_delimited_list(
+        &mut self,
+        context: ParsingContext,
+        parse_element: fn(&mut Parser<'a>) -> GreenNode,
+        allow_trailing_comma: bool,
+    ) -> GreenNode {
+        let saved_parsing_context = self.parsing_context;
+        self.parsing_context |= 1 << context as u32;
+        let mut elements = Vec::new();
+        let mut comma_pos = None;
+        loop {
+            if self.is_list_element(context) {
+                let start = self.scanner.get_start_pos();
+                elements.push(parse_element(self));
+                comma_pos = Some(self.scanner.get_token_pos());
+                if self.parse_optional(SyntaxKind::CommaToken, &mut elements) {
+                    continue;
+                }
+                comma_pos = None;
+                if self.is_list_terminator(context) {
+                    break;
+                }
+                self.parse_error_at_current(&diagnostics::_0_EXPECTED, &[","]);
+                if self.scanner.get_start_pos() != start && self.is_list_element(context) {
+                    elements.push(self.create_missing_token(SyntaxKind::CommaToken));
+                    continue;
+                }
+            } else if self.is_list_terminator(context) {
+                if let (Some(pos), false) = (comma_pos, allow_trailing_comma) {
+                    self.parse_error_at(pos, 1, &diagnostics::TRAILING_COMMA_NOT_ALLOWED, &[]);
+                }
+                break;
+            }
+            if self.abort_parsing_list_or_skip_tokens(context, &mut elements) {
+                break;
+            }
+        }
+        self.parsing_context = saved_parsing_context;
+        return self.finish_node(SyntaxKind::SyntaxList, elements);
+    }
+
+    // Parses `< list >` around a delimited list
+    fn parse_bracketed_list(
+        &mut self,
+        context: ParsingContext,
+        open: SyntaxKind,
+        parse_element: fn(&mut Parser<'a>) -> GreenNode,
+        close: SyntaxKind,
+        children: &mut Vec<GreenNode>,
+    ) {
+        self.parse_expected(open, children);
+        children.push(self.parse_delimited_list(context, parse_element, false));
+        self.parse_expected(close, children);
+    }
+
+    // Names and types
+
+    fn parse_entity_name(&mut self) -> GreenNode {
+        let mut entity = self.parse_identifier();
+        while self.token == SyntaxKind::DotToken {
+            let dot = self.create_token_node();
+            let right = self.parse_identifier_name();
+            entity = self.finish_node(SyntaxKind::QualifiedName, vec![entity, dot, right]);
+        }
+        return entity;
+    }
+
+    fn parse_type_reference(&mut self) -> GreenNode {
+        let mut children = vec![self.parse_entity_name()];
+        if !self.scanner.has_preceding_line_break() && self.token == SyntaxKind::LessThanToken {
+            self.parse_bracketed_list(
+                ParsingContext::TypeArguments,
+                SyntaxKind::LessThanToken,
+                Parser::parse_type,
+                SyntaxKind::GreaterThanToken,
+                &mut children,
+            );
+        }
+        return self.finish_node(SyntaxKind::TypeReference, children);
+    }
+
+    fn parse_type_parameter(&mut self) -> GreenNode {
+        let mut children = vec![self.parse_identifier()];
+        if self.parse_optional(SyntaxKind::ExtendsKeyword, &mut children) {
+            children.push(self.parse_type());
+        }
+        return self.finish_node(SyntaxKind::TypeParameter, children);
+    }
+
+    fn parse_type_parameters(&mut self, children: &mut Vec<GreenNode>) {
+        if self.token == SyntaxKind::LessThanToken {
+            self.parse_bracketed_list(
+                ParsingContext::TypeParameters,
+                SyntaxKind::LessThanToken,
+                Parser::parse_type_parameter,
+                SyntaxKind::GreaterThanToken,
+                children,
+            );
+        }
+    }
+
+    fn is_start_of_type(&self) -> bool {
+        match self.token {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::OpenParenToken
+            | SyntaxKind::LessThanToken
+            | SyntaxKind::NewKeyword => true,
+            _ => self.is_identifier(),
+        }
+    }
+
+    fn parse_type_query(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        children.push(self.parse_entity_name());
+        return self.finish_node(SyntaxKind::TypeQuery, children);
+    }
+
+    fn parse_type_literal(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        self.parse_type_members(&mut children);
+        return self.finish_node(SyntaxKind::TypeLiteral, children);
+    }
+
+    // `(a: A) => R` is a type literal with a single call signature and `new (a: A) => R` one with a
+    // single construct signature, the signatures have `=>` in place of `:`
+    fn parse_function_type(&mut self, kind: SyntaxKind) -> GreenNode {
+        let mut children = Vec::new();
+        if kind == SyntaxKind::ConstructSignature {
+            children.push(self.create_token_node());
+        }
+        self.parse_type_parameters(&mut children);
+        let parameter_properties = self.parse_parameter_list(&mut children);
+        self.report_parameter_properties(parameter_properties);
+        self.parse_expected(SyntaxKind::EqualsGreaterThanToken, &mut children);
+        children.push(self.parse_type());
+        let signature = self.finish_node(kind, children);
+        return self.finish_node(SyntaxKind::TypeLiteral, vec![signature]);
+    }
+
+    fn parse_non_array_type(&mut self) -> GreenNode {
+        match self.token {
+            SyntaxKind::AnyKeyword
+            | SyntaxKind::StringKeyword
+            | SyntaxKind::NumberKeyword
+            | SyntaxKind::BooleanKeyword
+            | SyntaxKind::VoidKeyword => {
+                return self.create_token_node();
+            }
+            SyntaxKind::TypeOfKeyword => self.parse_type_query(),
+            SyntaxKind::OpenBraceToken => self.parse_type_literal(),
+            SyntaxKind::OpenParenToken | SyntaxKind::LessThanToken => {
+                self.parse_function_type(SyntaxKind::CallSignature)
+            }
+            SyntaxKind::NewKeyword => self.parse_function_type(SyntaxKind::ConstructSignature),
+            _ => {
+                if self.is_identifier() {
+                    return self.parse_type_reference();
+                }
+                self.parse_error_at_current(&diagnostics::TYPE_EXPECTED, &[]);
+                return self.create_missing_node(Vec::new());
+            }
+        }
+    }
+
+    fn parse_type(&mut self) -> GreenNode {
+        let mut node = self.parse_non_array_type();
+        while !self.scanner.has_preceding_line_break() && self.token == SyntaxKind::OpenBracketToken {
+            let mut children = vec![node, self.create_token_node()];
+            self.parse_expected(SyntaxKind::CloseBracketToken, &mut children);
+            node = self.finish_node(SyntaxKind::ArrayType, children);
+        }
+        return node;
+    }
+
+    fn parse_type_annotation(&mut self, children: &mut Vec<GreenNode>) {
+        if self.parse_optional(SyntaxKind::ColonToken, children) {
+            children.push(self.parse_type());
+        }
+    }
+
+    // Signatures
+
+    // Parameters of constructors declare properties when they have an accessibility modifier, whether
+    // the parameter is in a constructor implementation is only known once its list is parsed
+    fn parse_parameter(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        let pos = self.scanner.get_token_pos();
+        if matches!(
+            self.token,
+            SyntaxKind::PublicKeyword | SyntaxKind::PrivateKeyword | SyntaxKind::ProtectedKeyword
+        ) && self.can_follow_modifier()
+        {
+            self.parameter_properties.push((pos, self.scanner.get_text_pos() - pos));
+        }
+        if let Some(pos) = self.parse_member_modifiers(&mut children) {
+            self.parse_error_at(
+                pos,
+                "static".len(),
+                &diagnostics::_0_MODIFIER_CANNOT_APPEAR_ON_A_PARAMETER,
+                &["static"],
+            );
+        }
+        self.parse_optional(SyntaxKind::DotDotDotToken, &mut children);
+        children.push(self.parse_identifier());
+        self.parse_optional(SyntaxKind::QuestionToken, &mut children);
+        self.parse_type_annotation(&mut children);
+        self.parse_initializer(false, &mut children);
+        return self.finish_node(SyntaxKind::Parameter, children);
+    }
+
+    // Parses parameters between open and close into children and returns the spans of the
+    // accessibility modifiers of the parameters
+    fn parse_parameters(
+        &mut self,
+        open: SyntaxKind,
+        close: SyntaxKind,
+        children: &mut Vec<GreenNode>,
+    ) -> Vec<(usize, usize)> {
+        let outer_parameter_properties = std::mem::take(&mut self.parameter_properties);
+        self.parse_bracketed_list(
+            ParsingContext::Parameters,
+            open,
+            Parser::parse_parameter,
+            close,
+            children,
+        );
+        return std::mem::replace(&mut self.parameter_properties, outer_parameter_properties);
+    }
+
+    fn parse_parameter_list(&mut self, children: &mut Vec<GreenNode>) -> Vec<(usize, usize)> {
+        return self.parse_parameters(SyntaxKind::OpenParenToken, SyntaxKind::CloseParenToken, children);
+    }
+
+    fn report_parameter_properties(&mut self, parameter_properties: Vec<(usize, usize)>) {
+        for (start, length) in parameter_properties {
+            self.parse_error_at(
+                start,
+                length,
+                &diagnostics::A_PARAMETER_PROPERTY_IS_ONLY_ALLOWED_IN_A_CONSTRUCTOR_IMPLEMENTATION,
+                &[],
+            );
+        }
+    }
+
+    // Parses `<T>(a: A, b: B): R` into children, parameter properties are left to the constructor and
+    // to arrow functions, whose parameters may turn out not to be a signature
+    fn parse_signature_with_parameter_properties(&mut self, children: &mut Vec<GreenNode>) -> Vec<(usize, usize)> {
+        self.parse_type_parameters(children);
+        let parameter_properties = self.parse_parameter_list(children);
+        self.parse_type_annotation(children);
+        return parameter_properties;
+    }
+
+    fn parse_signature(&mut self, children: &mut Vec<GreenNode>) {
+        let parameter_properties = self.parse_signature_with_parameter_properties(children);
+        self.report_parameter_properties(parameter_properties);
+    }
+
+    // The statements of a body are not ambient, a body in an ambient context is reported where the
+    // body is optional
+    fn parse_function_block(&mut self) -> GreenNode {
+        let in_ambient_context = std::mem::replace(&mut self.in_ambient_context, false);
+        let block = self.parse_block(SyntaxKind::FunctionBlock);
+        self.in_ambient_context = in_ambient_context;
+        return block;
+    }
+
+    fn parse_function_block_or_semicolon(&mut self, children: &mut Vec<GreenNode>) {
+        if self.token == SyntaxKind::OpenBraceToken {
+            if self.in_ambient_context {
+                self.parse_error_at_current(
+                    &diagnostics::AN_IMPLEMENTATION_CANNOT_BE_DECLARED_IN_AMBIENT_CONTEXTS,
+                    &[],
+                );
+            }
+            children.push(self.parse_function_block());
+        } else {
+            self.parse_semicolon(children);
+        }
+    }
+
+    // Expressions
+
+    fn is_start_of_expression(&self) -> bool {
+        match self.token {
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::StringLiteral
+            | SyntaxKind::SlashToken
+            | SyntaxKind::SlashEqualsToken
+            | SyntaxKind::OpenParenToken
+            | SyntaxKind::OpenBracketToken
+            | SyntaxKind::OpenBraceToken
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::NewKeyword
+            | SyntaxKind::PlusToken
+            | SyntaxKind::MinusToken
+            | SyntaxKind::TildeToken
+            | SyntaxKind::ExclamationToken
+            | SyntaxKind::DeleteKeyword
+            | SyntaxKind::TypeOfKeyword
+            | SyntaxKind::VoidKeyword
+            | SyntaxKind::PlusPlusToken
+            | SyntaxKind::MinusMinusToken
+            | SyntaxKind::LessThanToken => true,
+            _ => self.is_identifier(),
+        }
+    }
+
+    pub fn parse_expression(&mut self, no_in: bool) -> GreenNode {
+        return self.parse_binary_expression(Precedence::Comma, no_in);
+    }
+
+    fn parse_assignment_expression(&mut self, no_in: bool) -> GreenNode {
+        return self.parse_binary_expression(Precedence::Assignment, no_in);
+    }
+
+    // Parses an expression whose operators bind at least as tightly as min_precedence, arrow
+    // functions start where an assignment expression can
+    fn parse_binary_expression(&mut self, min_precedence: Precedence, no_in: bool) -> GreenNode {
+        if min_precedence <= Precedence::Assignment {
+            if let Some(arrow) = self.try_parse_parenthesized_arrow_function(no_in) {
+                return self.parse_binary_operators(arrow, min_precedence, no_in);
+            }
+        }
+        let mut operand = self.parse_unary_expression();
+        if min_precedence <= Precedence::Assignment
+            && operand.kind() == SyntaxKind::Identifier
+            && self.token == SyntaxKind::EqualsGreaterThanToken
+        {
+            operand = self.parse_simple_arrow_function(operand, no_in);
+        }
+        return self.parse_binary_operators(operand, min_precedence, no_in);
+    }
+
+    // Parses `x => body`, the identifier becomes the single parameter
+    fn parse_simple_arrow_function(&mut self, identifier: GreenNode, no_in: bool) -> GreenNode {
+        let parameter = self.finish_node(SyntaxKind::Parameter, vec![identifier]);
+        let mut children = vec![parameter, self.create_token_node()];
+        children.push(self.parse_arrow_function_body(no_in));
+        return self.finish_node(SyntaxKind::ArrowFunction, children);
+    }
+
+    fn try_parse_parenthesized_arrow_function(&mut self, no_in: bool) -> Option<GreenNode> {
+        let (mut children, parameter_properties) = match self.is_parenthesized_arrow_function() {
+            Tristate::False => return None,
+            Tristate::True => {
+                let mut children = Vec::new();
+                let parameter_properties = self.parse_signature_with_parameter_properties(&mut children);
+                self.parse_expected(SyntaxKind::EqualsGreaterThanToken, &mut children);
+                (children, parameter_properties)
+            }
+            Tristate::Unknown => self.try_parse(|parser| {
+                let mut children = Vec::new();
+                let parameter_properties = parser.parse_signature_with_parameter_properties(&mut children);
+                if parser.parse_expected(SyntaxKind::EqualsGreaterThanToken, &mut children) {
+                    return Some((children, parameter_properties));
+                }
+                return None;
+            })?,
+        };
+        self.report_parameter_properties(parameter_properties);
+        children.push(self.parse_arrow_function_body(no_in));
+        return Some(self.finish_node(SyntaxKind::ArrowFunction, children));
+    }
+
+    // Decides from the first tokens whether `(` or `<` starts an arrow function: `()`, `(...` and `(a:`
+    // always do, `(a` and `<T` need a speculative parse, anything else is a parenthesized expression
+    // or a type assertion
+    fn is_parenthesized_arrow_function(&mut self) -> Tristate {
+        if self.token != SyntaxKind::OpenParenToken && self.token != SyntaxKind::LessThanToken {
+            return Tristate::False;
+        }
+        return self.look_ahead(|parser| {
+            let first = parser.token;
+            let second = parser.next_token();
+            if first == SyntaxKind::OpenParenToken {
+                if second == SyntaxKind::CloseParenToken {
+                    // `()` is only valid before `=>` or a return type annotation
+                    return match parser.next_token() {
+                        SyntaxKind::EqualsGreaterThanToken | SyntaxKind::ColonToken | SyntaxKind::OpenBraceToken => {
+                            Tristate::True
+                        }
+                        _ => Tristate::False,
+                    };
+                }
+                if second == SyntaxKind::DotDotDotToken {
+                    return Tristate::True;
+                }
+                if !parser.is_identifier() {
+                    return Tristate::False;
+                }
+                if parser.next_token() == SyntaxKind::ColonToken {
+                    return Tristate::True;
+                }
+                return Tristate::Unknown;
+            }
+            if !parser.is_identifier() {
+                return Tristate::False;
+            }
+            return Tristate::Unknown;
+        });
+    }
+
+    fn parse_arrow_function_body(&mut self, no_in: bool) -> GreenNode {
+        if self.token == SyntaxKind::OpenBraceToken {
+            return self.parse_function_block();
+        }
+        return self.parse_assignment_expression(no_in);
+    }
+
+    // Precedence climbing: an operator takes the expression before it as its left operand when the
+    // operator binds at least as tightly as min_precedence and no more tightly than the operand
+    fn parse_binary_operators(&mut self, mut left: GreenNode, min_precedence: Precedence, no_in: bool) -> GreenNode {
+        loop {
+            // The scanner always stops after a single `>`, combine it with what follows in expressions
+            self.token = self.scanner.rescan_greater_token();
+            let precedence = match get_binary_operator_precedence(self.token) {
+                Some(precedence) if precedence >= min_precedence => precedence,
+                _ => return left,
+            };
+            if no_in && self.token == SyntaxKind::InKeyword {
+                return left;
+            }
+            let operator_of_left = left.children().get(1).map_or(SyntaxKind::Unknown, |child| child.kind());
+            let left_precedence = get_expression_precedence(left.kind(), operator_of_left);
+            let takes_left = match precedence {
+                Precedence::Assignment => is_left_hand_side_expression(&left),
+                Precedence::Conditional => left_precedence > Precedence::Conditional,
+                _ => left_precedence >= precedence,
+            };
+            if !takes_left {
+                return left;
+            }
+            let mut children = vec![left, self.create_token_node()];
+            if precedence == Precedence::Conditional {
+                children.push(self.parse_assignment_expression(false));
+                self.parse_expected(SyntaxKind::ColonToken, &mut children);
+                children.push(self.parse_assignment_expression(no_in));
+                left = self.finish_node(SyntaxKind::ConditionalExpression, children);
+                continue;
+            }
+            let right_precedence = match get_associativity(precedence) {
+                Associativity::Left => precedence.higher(),
+                Associativity::Right => precedence,
+            };
+            children.push(self.parse_binary_expression(right_precedence, no_in));
+            left = self.finish_node(SyntaxKind::BinaryExpression, children);
+        }
+    }
+
+    fn parse_unary_expression(&mut self) -> GreenNode {
+        if get_prefix_operator_precedence(self.token).is_some() {
+            let operator = self.create_token_node();
+            let operand = self.parse_unary_expression();
+            return self.finish_node(SyntaxKind::PrefixOperator, vec![operator, operand]);
+        }
+        if self.token == SyntaxKind::LessThanToken {
+            return self.parse_type_assertion();
+        }
+        return self.parse_postfix_expression();
+    }
+
+    fn parse_type_assertion(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        children.push(self.parse_type());
+        self.parse_expected(SyntaxKind::GreaterThanToken, &mut children);
+        children.push(self.parse_unary_expression());
+        return self.finish_node(SyntaxKind::TypeAssertion, children);
+    }
+
+    fn parse_postfix_expression(&mut self) -> GreenNode {
+        let expr = self.parse_left_hand_side_expression();
+        // No line break is allowed before a postfix operator, `a \n ++b` is `a; ++b;`
+        if get_postfix_operator_precedence(self.token).is_some() && !self.scanner.has_preceding_line_break() {
+            let operator = self.create_token_node();
+            return self.finish_node(SyntaxKind::PostfixOperator, vec![expr, operator]);
+        }
+        return expr;
+    }
+
+    fn parse_left_hand_side_expression(&mut self) -> GreenNode {
+        let expr = if self.token == SyntaxKind::NewKeyword {
+            self.parse_new_expression()
+        } else {
+            self.parse_primary_expression()
+        };
+        return self.parse_call_and_access(expr, false);
+    }
+
+    fn parse_new_expression(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        let callee = if self.token == SyntaxKind::NewKeyword {
+            self.parse_new_expression()
+        } else {
+            self.parse_primary_expression()
+        };
+        children.push(self.parse_call_and_access(callee, true));
+        if self.token == SyntaxKind::LessThanToken {
+            if let Some(type_arguments) = self.try_parse(Parser::parse_type_arguments_of_call) {
+                children.extend(type_arguments);
+            }
+        }
+        if self.token == SyntaxKind::OpenParenToken {
+            self.parse_arguments(&mut children);
+        }
+        return self.finish_node(SyntaxKind::NewExpression, children);
+    }
+
+    fn parse_arguments(&mut self, children: &mut Vec<GreenNode>) {
+        self.parse_bracketed_list(
+            ParsingContext::ArgumentExpressions,
+            SyntaxKind::OpenParenToken,
+            |p| p.parse_assignment_expression(false),
+            SyntaxKind::CloseParenToken,
+            children,
+        );
+    }
+
+    // Parses `<T, U>` followed by `(`, fails for `a < b > c` and other comparisons
+    fn parse_type_arguments_of_call(&mut self) -> Option<Vec<GreenNode>> {
+        let mut children = Vec::new();
+        self.parse_bracketed_list(
+            ParsingContext::TypeArguments,
+            SyntaxKind::LessThanToken,
+            Parser::parse_type,
+            SyntaxKind::GreaterThanToken,
+            &mut children,
+        );
+        if self.token != SyntaxKind::OpenParenToken {
+            return None;
+        }
+        return Some(children);
+    }
+
+    fn parse_call_and_access(&mut self, mut expr: GreenNode, in_new_expression: bool) -> GreenNode {
+        loop {
+            match self.token {
+                SyntaxKind::DotToken => {
+                    let mut children = vec![expr, self.create_token_node()];
+                    children.push(self.parse_identifier_name());
+                    expr = self.finish_node(SyntaxKind::PropertyAccess, children);
+                }
+                SyntaxKind::OpenBracketToken => {
+                    let mut children = vec![expr, self.create_token_node()];
+                    children.push(self.parse_expression(false));
+                    self.parse_expected(SyntaxKind::CloseBracketToken, &mut children);
+                    expr = self.finish_node(SyntaxKind::IndexedAccess, children);
+                }
+                SyntaxKind::LessThanToken if !in_new_expression => {
+                    let type_arguments = match self.try_parse(Parser::parse_type_arguments_of_call) {
+                        Some(type_arguments) => type_arguments,
+                        None => return expr,
+                    };
+                    let mut children = vec![expr];
+                    children.extend(type_arguments);
+                    self.parse_arguments(&mut children);
+                    expr = self.finish_node(SyntaxKind::CallExpression, children);
+                }
+                SyntaxKind::OpenParenToken if !in_new_expression => {
+                    let mut children = vec![expr];
+                    self.parse_arguments(&mut children);
+                    expr = self.finish_node(SyntaxKind::CallExpression, children);
+                }
+                _ => {
+                    return expr;
+                }
+            }
+        }
+    }
+
+    fn parse_primary_expression(&mut self) -> GreenNode {
+        match self.token {
+            SyntaxKind::ThisKeyword
+            | SyntaxKind::SuperKeyword
+            | SyntaxKind::NullKeyword
+            | SyntaxKind::TrueKeyword
+            | SyntaxKind::FalseKeyword
+            | SyntaxKind::NumericLiteral
+            | SyntaxKind::StringLiteral => {
+                return self.create_token_node();
+            }
+            SyntaxKind::SlashToken | SyntaxKind::SlashEqualsToken => {
+                // A slash where an expression starts begins a regular expression, not a division
+                self.token = self.scanner.rescan_slash_token();
+                return self.create_token_node();
+            }
+            SyntaxKind::OpenParenToken => {
+                let mut children = vec![self.create_token_node()];
+                children.push(self.parse_expression(false));
+                self.parse_expected(SyntaxKind::CloseParenToken, &mut children);
+                return self.finish_node(SyntaxKind::ParenExpression, children);
+            }
+            SyntaxKind::OpenBracketToken => {
+                return self.parse_array_literal();
+            }
+            SyntaxKind::OpenBraceToken => {
+                return self.parse_object_literal();
+            }
+            SyntaxKind::FunctionKeyword => {
+                return self.parse_function_expression();
+            }
+            _ => {
+                if self.is_identifier() {
+                    return self.parse_identifier();
+                }
+                self.parse_error_at_current(&diagnostics::EXPRESSION_EXPECTED, &[]);
+                return self.create_missing_node(Vec::new());
+            }
+        }
+    }
+
+    fn parse_array_literal(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        children.push(self.parse_delimited_list(
+            ParsingContext::ArrayLiteralMembers,
+            Parser::parse_array_literal_element,
+            true,
+        ));
+        self.parse_expected(SyntaxKind::CloseBracketToken, &mut children);
+        return self.finish_node(SyntaxKind::ArrayLiteral, children);
+    }
+
+    fn parse_array_literal_element(&mut self) -> GreenNode {
+        if self.token == SyntaxKind::CommaToken {
+            return self.finish_node(SyntaxKind::OmittedExpression, Vec::new());
+        }
+        return self.parse_assignment_expression(false);
+    }
+
+    fn parse_object_literal(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        children.push(self.parse_delimited_list(
+            ParsingContext::ObjectLiteralMembers,
+            Parser::parse_property_assignment,
+            true,
+        ));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(SyntaxKind::ObjectLiteral, children);
+    }
+
+    fn parse_property_assignment(&mut self) -> GreenNode {
+        if self.is_accessor_start() {
+            return self.parse_accessor(Vec::new());
+        }
+        let mut children = vec![self.parse_property_name()];
+        if self.token == SyntaxKind::OpenParenToken || self.token == SyntaxKind::LessThanToken {
+            // Method shorthand, `m() { }` is `m: function () { }`
+            let mut signature = Vec::new();
+            self.parse_signature(&mut signature);
+            signature.push(self.parse_function_block());
+            children.push(self.finish_node(SyntaxKind::FunctionExpression, signature));
+        } else {
+            self.parse_expected(SyntaxKind::ColonToken, &mut children);
+            children.push(self.parse_assignment_expression(false));
+        }
+        return self.finish_node(SyntaxKind::PropertyAssignment, children);
+    }
+
+    fn parse_function_expression(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        if self.is_identifier() {
+            children.push(self.create_identifier_node());
+        }
+        self.parse_signature(&mut children);
+        children.push(self.parse_function_block());
+        return self.finish_node(SyntaxKind::FunctionExpression, children);
+    }
+
+    // Statements
+
+    fn parse_block(&mut self, kind: SyntaxKind) -> GreenNode {
+        let context = if kind == SyntaxKind::ModuleBlock {
+            ParsingContext::ModuleElements
+        } else {
+            ParsingContext::BlockStatements
+        };
+        let mut children = Vec::new();
+        self.parse_expected(SyntaxKind::OpenBraceToken, &mut children);
+        children.push(self.parse_list(context, Parser::parse_statement));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(kind, children);
+    }
+
+    // Parses `keyword { statements }`, the keyword belongs to the block
+    fn parse_token_and_block(&mut self, kind: SyntaxKind) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        let block = self.parse_block(kind);
+        children.extend(block.children().iter().cloned());
+        return self.finish_node(kind, children);
+    }
+
+    fn is_declaration_start(&mut self) -> bool {
+        match self.token {
+            SyntaxKind::VarKeyword
+            | SyntaxKind::FunctionKeyword
+            | SyntaxKind::ClassKeyword
+            | SyntaxKind::EnumKeyword
+            | SyntaxKind::ImportKeyword => true,
+            // `interface` and `module` are also valid identifiers
+            SyntaxKind::InterfaceKeyword => self.look_ahead(|p| {
+                p.next_token();
+                return p.is_identifier();
+            }),
+            SyntaxKind::ModuleKeyword => self.look_ahead(|p| {
+                p.next_token();
+                return p.is_identifier() || p.token == SyntaxKind::StringLiteral;
+            }),
+            _ => false,
+        }
+    }
+
+    // `declare` is also a valid identifier, it is a modifier when a declaration follows on the same line
+    fn is_declare_modifier(&mut self) -> bool {
+        return self.token == SyntaxKind::DeclareKeyword
+            && self.look_ahead(|p| {
+                p.next_token();
+                return !p.scanner.has_preceding_line_break() && p.is_declaration_start();
+            });
+    }
+
+    fn is_start_of_declaration_statement(&mut self) -> bool {
+        return self.token == SyntaxKind::ExportKeyword || self.is_declaration_start() || self.is_declare_modifier();
+    }
+
+    fn is_start_of_statement(&mut self) -> bool {
+        match self.token {
+            SyntaxKind::OpenBraceToken
+            | SyntaxKind::SemicolonToken
+            | SyntaxKind::IfKeyword
+            | SyntaxKind::DoKeyword
+            | SyntaxKind::WhileKeyword
+            | SyntaxKind::ForKeyword
+            | SyntaxKind::ContinueKeyword
+            | SyntaxKind::BreakKeyword
+            | SyntaxKind::ReturnKeyword
+            | SyntaxKind::WithKeyword
+            | SyntaxKind::SwitchKeyword
+            | SyntaxKind::ThrowKeyword
+            | SyntaxKind::TryKeyword
+            | SyntaxKind::DebuggerKeyword
+            | SyntaxKind::ExportKeyword => true,
+            _ => self.is_declaration_start() || self.is_start_of_expression(),
+        }
+    }
+
+    // Top level declarations of a .d.ts file are ambient, and have to say so unless they only declare types
+    fn parse_source_element(&mut self) -> GreenNode {
+        if self.is_declaration_file
+            && self.is_declaration_start()
+            && !matches!(self.token, SyntaxKind::InterfaceKeyword | SyntaxKind::ImportKeyword)
+        {
+            self.parse_error_at_current(
+                &diagnostics::A_DECLARE_MODIFIER_IS_REQUIRED_FOR_A_TOP_LEVEL_DECLARATION_IN_A_D_TS_FILE,
+                &[],
+            );
+        }
+        return self.parse_statement();
+    }
+
+    pub fn parse_statement(&mut self) -> GreenNode {
+        // Reported once, the statements nested in the statement are not checked again
+        if self.in_ambient_context && !self.is_start_of_declaration_statement() {
+            self.parse_error_at_current(&diagnostics::STATEMENTS_ARE_NOT_ALLOWED_IN_AMBIENT_CONTEXTS, &[]);
+            self.in_ambient_context = false;
+            let statement = self.parse_statement();
+            self.in_ambient_context = true;
+            return statement;
+        }
+        match self.token {
+            SyntaxKind::OpenBraceToken => self.parse_block(SyntaxKind::Block),
+            SyntaxKind::SemicolonToken => {
+                let semicolon = self.create_token_node();
+                return self.finish_node(SyntaxKind::EmptyStatement, vec![semicolon]);
+            }
+            SyntaxKind::IfKeyword => self.parse_if_statement(),
+            SyntaxKind::DoKeyword => self.parse_do_statement(),
+            SyntaxKind::WhileKeyword => self.parse_while_statement(),
+            SyntaxKind::ForKeyword => self.parse_for_or_for_in_statement(),
+            SyntaxKind::ContinueKeyword => self.parse_break_or_continue_statement(SyntaxKind::ContinueStatement),
+            SyntaxKind::BreakKeyword => self.parse_break_or_continue_statement(SyntaxKind::BreakStatement),
+            SyntaxKind::ReturnKeyword => self.parse_return_statement(),
+            SyntaxKind::WithKeyword => self.parse_with_statement(),
+            SyntaxKind::SwitchKeyword => self.parse_switch_statement(),
+            SyntaxKind::ThrowKeyword => self.parse_throw_statement(),
+            SyntaxKind::TryKeyword => self.parse_try_statement(),
+            SyntaxKind::DebuggerKeyword => {
+                let mut children = vec![self.create_token_node()];
+                self.parse_semicolon(&mut children);
+                return self.finish_node(SyntaxKind::DebuggerStatement, children);
+            }
+            SyntaxKind::ExportKeyword => self.parse_export_assignment_or_declaration(),
+            _ => {
+                if self.is_declare_modifier() {
+                    let mut modifiers = Vec::new();
+                    self.parse_declaration_modifiers(&mut modifiers);
+                    return self.parse_declaration(modifiers);
+                }
+                if self.is_declaration_start() {
+                    return self.parse_declaration(Vec::new());
+                }
+                return self.parse_expression_or_labelled_statement();
+            }
+        }
+    }
+
+    fn parse_expression_or_labelled_statement(&mut self) -> GreenNode {
+        let expr = self.parse_expression(false);
+        if expr.kind() == SyntaxKind::Identifier && self.token == SyntaxKind::ColonToken {
+            let mut children = vec![expr, self.create_token_node()];
+            children.push(self.parse_statement());
+            return self.finish_node(SyntaxKind::LabelledStatement, children);
+        }
+        let mut children = vec![expr];
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ExpressionStatement, children);
+    }
+
+    // Parses `( expression )` into children
+    fn parse_parenthesized_expression(&mut self, children: &mut Vec<GreenNode>) {
+        self.parse_expected(SyntaxKind::OpenParenToken, children);
+        children.push(self.parse_expression(false));
+        self.parse_expected(SyntaxKind::CloseParenToken, children);
+    }
+
+    fn parse_if_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        self.parse_parenthesized_expression(&mut children);
+        children.push(self.parse_statement());
+        if self.parse_optional(SyntaxKind::ElseKeyword, &mut children) {
+            children.push(self.parse_statement());
+        }
+        return self.finish_node(SyntaxKind::IfStatement, children);
+    }
+
+    fn parse_do_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        children.push(self.parse_statement());
+        self.parse_expected(SyntaxKind::WhileKeyword, &mut children);
+        self.parse_parenthesized_expression(&mut children);
+        // A semicolon is always inserted after do-while, see ES5 7.9.1
+        self.parse_optional(SyntaxKind::SemicolonToken, &mut children);
+        return self.finish_node(SyntaxKind::DoStatement, children);
+    }
+
+    fn parse_while_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        self.parse_parenthesized_expression(&mut children);
+        children.push(self.parse_statement());
+        return self.finish_node(SyntaxKind::WhileStatement, children);
+    }
+
+    fn parse_for_or_for_in_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        self.parse_expected(SyntaxKind::OpenParenToken, &mut children);
+        let mut declaration_count = 0;
+        let mut has_initializer = false;
+        if self.parse_optional(SyntaxKind::VarKeyword, &mut children) {
+            let declarations = self.parse_variable_declaration_list(true);
+            declaration_count = declarations.children().len().div_ceil(2);
+            children.push(declarations);
+        } else if self.token != SyntaxKind::SemicolonToken {
+            children.push(self.parse_expression(true));
+            has_initializer = true;
+        }
+        if (declaration_count == 1 || has_initializer) && self.token == SyntaxKind::InKeyword {
+            children.push(self.create_token_node());
+            children.push(self.parse_expression(false));
+            self.parse_expected(SyntaxKind::CloseParenToken, &mut children);
+            children.push(self.parse_statement());
+            return self.finish_node(SyntaxKind::ForInStatement, children);
+        }
+        self.parse_expected(SyntaxKind::SemicolonToken, &mut children);
+        if self.token != SyntaxKind::SemicolonToken {
+            children.push(self.parse_expression(false));
+        }
+        self.parse_expected(SyntaxKind::SemicolonToken, &mut children);
+        if self.token != SyntaxKind::CloseParenToken {
+            children.push(self.parse_expression(false));
+        }
+        self.parse_expected(SyntaxKind::CloseParenToken, &mut children);
+        children.push(self.parse_statement());
+        return self.finish_node(SyntaxKind::ForStatement, children);
+    }
+
+    fn parse_break_or_continue_statement(&mut self, kind: SyntaxKind) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        if !self.can_parse_semicolon() && self.is_identifier() {
+            children.push(self.create_identifier_node());
+        }
+        self.parse_semicolon(&mut children);
+        return self.finish_node(kind, children);
+    }
+
+    fn parse_return_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        if !self.can_parse_semicolon() {
+            children.push(self.parse_expression(false));
+        }
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ReturnStatement, children);
+    }
+
+    fn parse_with_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        self.parse_parenthesized_expression(&mut children);
+        children.push(self.parse_statement());
+        return self.finish_node(SyntaxKind::WithStatement, children);
+    }
+
+    fn parse_switch_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        self.parse_parenthesized_expression(&mut children);
+        self.parse_expected(SyntaxKind::OpenBraceToken, &mut children);
+        children.push(self.parse_list(ParsingContext::SwitchClauses, Parser::parse_case_or_default_clause));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(SyntaxKind::SwitchStatement, children);
+    }
+
+    fn parse_case_or_default_clause(&mut self) -> GreenNode {
+        let kind = if self.token == SyntaxKind::CaseKeyword {
+            SyntaxKind::CaseClause
+        } else {
+            SyntaxKind::DefaultClause
+        };
+        let mut children = vec![self.create_token_node()];
+        if kind == SyntaxKind::CaseClause {
+            children.push(self.parse_expression(false));
+        }
+        self.parse_expected(SyntaxKind::ColonToken, &mut children);
+        children.push(self.parse_list(ParsingContext::SwitchClauseStatements, Parser::parse_statement));
+        return self.finish_node(kind, children);
+    }
+
+    fn parse_throw_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        if self.scanner.has_preceding_line_break() {
+            self.parse_error_at_current(&diagnostics::LINE_BREAK_NOT_PERMITTED_HERE, &[]);
+        }
+        children.push(self.parse_expression(false));
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ThrowStatement, children);
+    }
+
+    fn parse_try_statement(&mut self) -> GreenNode {
+        let mut children = vec![self.parse_token_and_block(SyntaxKind::TryBlock)];
+        if self.token == SyntaxKind::CatchKeyword {
+            let mut catch_children = vec![self.create_token_node()];
+            self.parse_expected(SyntaxKind::OpenParenToken, &mut catch_children);
+            catch_children.push(self.parse_identifier());
+            self.parse_expected(SyntaxKind::CloseParenToken, &mut catch_children);
+            let block = self.parse_block(SyntaxKind::CatchBlock);
+            catch_children.extend(block.children().iter().cloned());
+            children.push(self.finish_node(SyntaxKind::CatchBlock, catch_children));
+        }
+        if self.token == SyntaxKind::FinallyKeyword {
+            children.push(self.parse_token_and_block(SyntaxKind::FinallyBlock));
+        }
+        if children.len() == 1 {
+            self.parse_error_at_current(&diagnostics::CATCH_OR_FINALLY_EXPECTED, &[]);
+        }
+        return self.finish_node(SyntaxKind::TryStatement, children);
+    }
+
+    // Declarations
+
+    fn can_follow_modifier(&mut self) -> bool {
+        return self.look_ahead(|p| {
+            p.next_token();
+            return !p.scanner.has_preceding_line_break()
+                && (p.is_property_name() || p.token == SyntaxKind::OpenBracketToken);
+        });
+    }
+
+    // Parses the accessibility and `static` modifiers of a class member or parameter property into
+    // children, an accessibility modifier comes first and each modifier appears once. Returns the
+    // position of `static` for the members that cannot be static.
+    fn parse_member_modifiers(&mut self, children: &mut Vec<GreenNode>) -> Option<usize> {
+        let mut seen_accessibility = false;
+        let mut static_pos = None;
+        while matches!(
+            self.token,
+            SyntaxKind::PublicKeyword
+                | SyntaxKind::PrivateKeyword
+                | SyntaxKind::ProtectedKeyword
+                | SyntaxKind::StaticKeyword
+        ) && self.can_follow_modifier()
+        {
+            let text = token_to_string(self.token).unwrap_or("");
+            if self.token == SyntaxKind::StaticKeyword {
+                if static_pos.is_some() {
+                    self.parse_error_at_current(&diagnostics::_0_MODIFIER_ALREADY_SEEN, &[text]);
+                } else {
+                    static_pos = Some(self.scanner.get_token_pos());
+                }
+            } else {
+                if seen_accessibility {
+                    self.parse_error_at_current(&diagnostics::ACCESSIBILITY_MODIFIER_ALREADY_SEEN, &[]);
+                } else if static_pos.is_some() {
+                    self.parse_error_at_current(&diagnostics::_0_MODIFIER_MUST_PRECEDE_1_MODIFIER, &[text, "static"]);
+                }
+                seen_accessibility = true;
+            }
+            children.push(self.create_token_node());
+        }
+        return static_pos;
+    }
+
+    // Parses the `export` and `declare` modifiers of a declaration into children
+    fn parse_declaration_modifiers(&mut self, children: &mut Vec<GreenNode>) {
+        while matches!(self.token, SyntaxKind::ExportKeyword | SyntaxKind::DeclareKeyword) && self.can_follow_modifier()
+        {
+            // The top level of a .d.ts file is ambient but still requires `declare`
+            let is_top_level_of_declaration_file =
+                self.is_declaration_file && self.parsing_context == 1 << ParsingContext::SourceElements as u32;
+            if self.token == SyntaxKind::DeclareKeyword && self.in_ambient_context && !is_top_level_of_declaration_file
+            {
+                self.parse_error_at_current(
+                    &diagnostics::A_DECLARE_MODIFIER_CANNOT_BE_USED_IN_AN_ALREADY_AMBIENT_CONTEXT,
+                    &[],
+                );
+            }
+            children.push(self.create_token_node());
+        }
+    }
+
+    fn parse_export_assignment_or_declaration(&mut self) -> GreenNode {
+        let next = self.look_ahead(|p| p.next_token());
+        if next == SyntaxKind::EqualsToken {
+            let mut children = vec![self.create_token_node(), self.create_token_node()];
+            children.push(self.parse_identifier());
+            self.parse_semicolon(&mut children);
+            return self.finish_node(SyntaxKind::ExportAssignment, children);
+        }
+        if next == SyntaxKind::AsteriskToken || next == SyntaxKind::OpenBraceToken {
+            return self.parse_export_declaration();
+        }
+        let mut modifiers = Vec::new();
+        self.parse_declaration_modifiers(&mut modifiers);
+        if self.is_declaration_start() {
+            return self.parse_declaration(modifiers);
+        }
+        self.parse_error_at_current(&diagnostics::DECLARATION_EXPECTED, &[]);
+        return self.create_missing_node(modifiers);
+    }
+
+    fn parse_declaration(&mut self, modifiers: Vec<GreenNode>) -> GreenNode {
+        let in_ambient_context = self.in_ambient_context;
+        self.in_ambient_context |= modifiers
+            .iter()
+            .any(|modifier| modifier.kind() == SyntaxKind::DeclareKeyword);
+        let declaration = self.parse_declaration_of_kind(modifiers);
+        self.in_ambient_context = in_ambient_context;
+        return declaration;
+    }
+
+    fn parse_declaration_of_kind(&mut self, modifiers: Vec<GreenNode>) -> GreenNode {
+        match self.token {
+            SyntaxKind::VarKeyword => self.parse_variable_statement(modifiers),
+            SyntaxKind::FunctionKeyword => self.parse_function_declaration(modifiers),
+            SyntaxKind::ClassKeyword => self.parse_class_declaration(modifiers),
+            SyntaxKind::InterfaceKeyword => self.parse_interface_declaration(modifiers),
+            SyntaxKind::EnumKeyword => self.parse_enum_declaration(modifiers),
+            SyntaxKind::ModuleKeyword => self.parse_module_declaration(modifiers),
+            SyntaxKind::ImportKeyword => self.parse_import_declaration(modifiers),
+            _ => {
+                self.parse_error_at_current(&diagnostics::DECLARATION_EXPECTED, &[]);
+                return self.create_missing_node(modifiers);
+            }
+        }
+    }
+
+    fn parse_variable_declaration(&mut self, no_in: bool) -> GreenNode {
+        let mut children = vec![self.parse_identifier()];
+        self.parse_type_annotation(&mut children);
+        self.parse_initializer(no_in, &mut children);
+        return self.finish_node(SyntaxKind::VariableDeclaration, children);
+    }
+
+    // Parses `= expression` into children if present
+    fn parse_initializer(&mut self, no_in: bool, children: &mut Vec<GreenNode>) {
+        if self.token != SyntaxKind::EqualsToken {
+            return;
+        }
+        if self.in_ambient_context {
+            self.parse_error_at_current(&diagnostics::INITIALIZERS_ARE_NOT_ALLOWED_IN_AMBIENT_CONTEXTS, &[]);
+        }
+        children.push(self.create_token_node());
+        children.push(self.parse_assignment_expression(no_in));
+    }
+
+    fn parse_variable_declaration_list(&mut self, no_in: bool) -> GreenNode {
+        let parse_element: fn(&mut Parser<'a>) -> GreenNode = if no_in {
+            |p| p.parse_variable_declaration(true)
+        } else {
+            |p| p.parse_variable_declaration(false)
+        };
+        return self.parse_delimited_list(ParsingContext::VariableDeclarations, parse_element, false);
+    }
+
+    fn parse_variable_statement(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::VarKeyword, &mut children);
+        children.push(self.parse_variable_declaration_list(false));
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::VariableStatement, children);
+    }
+
+    fn parse_function_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::FunctionKeyword, &mut children);
+        children.push(self.parse_identifier());
+        self.parse_signature(&mut children);
+        self.parse_function_block_or_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::FunctionDeclaration, children);
+    }
+
+    fn parse_heritage_types(&mut self) -> GreenNode {
+        return self.parse_delimited_list(ParsingContext::BaseTypeReferences, Parser::parse_type_reference, false);
+    }
+
+    fn parse_class_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::ClassKeyword, &mut children);
+        children.push(self.parse_identifier());
+        self.parse_type_parameters(&mut children);
+        if self.parse_optional(SyntaxKind::ExtendsKeyword, &mut children) {
+            children.push(self.parse_type_reference());
+        }
+        if self.parse_optional(SyntaxKind::ImplementsKeyword, &mut children) {
+            children.push(self.parse_heritage_types());
+        }
+        self.parse_expected(SyntaxKind::OpenBraceToken, &mut children);
+        children.push(self.parse_list(ParsingContext::ClassMembers, Parser::parse_class_member));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(SyntaxKind::ClassDeclaration, children);
+    }
+
+    fn is_accessor_start(&mut self) -> bool {
+        if self.token != SyntaxKind::GetKeyword && self.token != SyntaxKind::SetKeyword {
+            return false;
+        }
+        return self.look_ahead(|p| {
+            p.next_token();
+            return p.is_property_name();
+        });
+    }
+
+    fn parse_accessor(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        let kind = if self.token == SyntaxKind::GetKeyword {
+            SyntaxKind::GetAccessor
+        } else {
+            SyntaxKind::SetAccessor
+        };
+        if self.in_ambient_context {
+            self.parse_error_at_current(&diagnostics::AN_ACCESSOR_CANNOT_BE_DECLARED_IN_AN_AMBIENT_CONTEXT, &[]);
+        }
+        children.push(self.create_token_node());
+        children.push(self.parse_property_name());
+        self.parse_signature(&mut children);
+        children.push(self.parse_function_block());
+        return self.finish_node(kind, children);
+    }
+
+    fn is_class_member_start(&mut self) -> bool {
+        return self.is_property_name() || self.token == SyntaxKind::OpenBracketToken;
+    }
+
+    fn parse_class_member(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        let modifier_start = self.scanner.get_token_pos();
+        let modifier_length = self.scanner.get_text_pos() - modifier_start;
+        let static_pos = self.parse_member_modifiers(&mut children);
+        if self.token == SyntaxKind::ConstructorKeyword
+            && self.look_ahead(|p| p.next_token() == SyntaxKind::OpenParenToken)
+        {
+            if let Some(pos) = static_pos {
+                self.parse_error_at(
+                    pos,
+                    "static".len(),
+                    &diagnostics::_0_MODIFIER_CANNOT_APPEAR_ON_A_CONSTRUCTOR_DECLARATION,
+                    &["static"],
+                );
+            }
+            children.push(self.create_token_node());
+            let parameter_properties = self.parse_signature_with_parameter_properties(&mut children);
+            if self.token != SyntaxKind::OpenBraceToken || self.in_ambient_context {
+                self.report_parameter_properties(parameter_properties);
+            }
+            self.parse_function_block_or_semicolon(&mut children);
+            return self.finish_node(SyntaxKind::Constructor, children);
+        }
+        if self.is_accessor_start() {
+            return self.parse_accessor(children);
+        }
+        if self.token == SyntaxKind::OpenBracketToken {
+            if !children.is_empty() {
+                self.parse_error_at(
+                    modifier_start,
+                    modifier_length,
+                    &diagnostics::MODIFIERS_CANNOT_APPEAR_HERE,
+                    &[],
+                );
+            }
+            self.parse_index_signature(&mut children);
+            self.parse_semicolon(&mut children);
+            return self.finish_node(SyntaxKind::IndexSignature, children);
+        }
+        children.push(self.parse_property_name());
+        self.parse_optional(SyntaxKind::QuestionToken, &mut children);
+        if self.token == SyntaxKind::OpenParenToken || self.token == SyntaxKind::LessThanToken {
+            self.parse_signature(&mut children);
+            self.parse_function_block_or_semicolon(&mut children);
+            return self.finish_node(SyntaxKind::Method, children);
+        }
+        self.parse_type_annotation(&mut children);
+        self.parse_initializer(false, &mut children);
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::Property, children);
+    }
+
+    fn parse_interface_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::InterfaceKeyword, &mut children);
+        children.push(self.parse_identifier());
+        self.parse_type_parameters(&mut children);
+        if self.parse_optional(SyntaxKind::ExtendsKeyword, &mut children) {
+            children.push(self.parse_heritage_types());
+        }
+        self.parse_type_members(&mut children);
+        return self.finish_node(SyntaxKind::InterfaceDeclaration, children);
+    }
+
+    // Parses `{ members }` of an interface into children
+    fn parse_type_members(&mut self, children: &mut Vec<GreenNode>) {
+        self.parse_expected(SyntaxKind::OpenBraceToken, children);
+        children.push(self.parse_list(ParsingContext::TypeMembers, Parser::parse_type_member));
+        self.parse_expected(SyntaxKind::CloseBraceToken, children);
+    }
+
+    fn is_type_member_start(&mut self) -> bool {
+        return self.token == SyntaxKind::OpenParenToken
+            || self.token == SyntaxKind::LessThanToken
+            || self.token == SyntaxKind::OpenBracketToken
+            || self.is_property_name();
+    }
+
+    // `new` starts a construct signature when parameters follow, otherwise it names a member
+    fn is_construct_signature_start(&mut self) -> bool {
+        return self.token == SyntaxKind::NewKeyword
+            && self.look_ahead(|p| {
+                p.next_token();
+                return p.token == SyntaxKind::OpenParenToken || p.token == SyntaxKind::LessThanToken;
+            });
+    }
+
+    // Parses `[key: string]: T` into children
+    fn parse_index_signature(&mut self, children: &mut Vec<GreenNode>) {
+        let parameter_properties =
+            self.parse_parameters(SyntaxKind::OpenBracketToken, SyntaxKind::CloseBracketToken, children);
+        self.report_parameter_properties(parameter_properties);
+        self.parse_type_annotation(children);
+    }
+
+    fn parse_type_member(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        let kind = if self.token == SyntaxKind::OpenParenToken || self.token == SyntaxKind::LessThanToken {
+            self.parse_signature(&mut children);
+            SyntaxKind::CallSignature
+        } else if self.is_construct_signature_start() {
+            children.push(self.create_token_node());
+            self.parse_signature(&mut children);
+            SyntaxKind::ConstructSignature
+        } else if self.token == SyntaxKind::OpenBracketToken {
+            self.parse_index_signature(&mut children);
+            SyntaxKind::IndexSignature
+        } else {
+            children.push(self.parse_property_name());
+            self.parse_optional(SyntaxKind::QuestionToken, &mut children);
+            if self.token == SyntaxKind::OpenParenToken || self.token == SyntaxKind::LessThanToken {
+                self.parse_signature(&mut children);
+                SyntaxKind::Method
+            } else {
+                self.parse_type_annotation(&mut children);
+                SyntaxKind::Property
+            }
+        };
+        if !self.parse_optional(SyntaxKind::CommaToken, &mut children) {
+            self.parse_semicolon(&mut children);
+        }
+        return self.finish_node(kind, children);
+    }
+
+    fn parse_enum_member(&mut self) -> GreenNode {
+        let mut children = vec![self.parse_property_name()];
+        if self.parse_optional(SyntaxKind::EqualsToken, &mut children) {
+            let start = self.scanner.get_token_pos();
+            let initializer = self.parse_assignment_expression(false);
+            if self.in_ambient_context && !is_integer_literal(&initializer) {
+                self.parse_error_at(
+                    start,
+                    self.scanner.get_start_pos().saturating_sub(start),
+                    &diagnostics::AMBIENT_ENUM_ELEMENTS_CAN_ONLY_HAVE_INTEGER_LITERAL_INITIALIZERS,
+                    &[],
+                );
+            }
+            children.push(initializer);
+        }
+        return self.finish_node(SyntaxKind::EnumMember, children);
+    }
+
+    fn parse_enum_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::EnumKeyword, &mut children);
+        children.push(self.parse_identifier());
+        self.parse_expected(SyntaxKind::OpenBraceToken, &mut children);
+        children.push(self.parse_delimited_list(ParsingContext::EnumMembers, Parser::parse_enum_member, true));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(SyntaxKind::EnumDeclaration, children);
+    }
+
+    fn parse_module_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::ModuleKeyword, &mut children);
+        if self.token == SyntaxKind::StringLiteral {
+            return self.parse_external_module_tail(children);
+        }
+        return self.parse_internal_module_tail(children);
+    }
+
+    // `declare module "name" { }` describes an external module
+    fn parse_external_module_tail(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        if !self.in_ambient_context {
+            self.parse_error_at_current(&diagnostics::ONLY_AMBIENT_MODULES_CAN_USE_QUOTED_NAMES, &[]);
+        }
+        children.push(self.create_token_node());
+        children.push(self.parse_block(SyntaxKind::ModuleBlock));
+        return self.finish_node(SyntaxKind::ModuleDeclaration, children);
+    }
+
+    // `module A.B.C { }` is parsed as module A containing module B containing module C
+    fn parse_internal_module_tail(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        children.push(self.parse_identifier());
+        if self.parse_optional(SyntaxKind::DotToken, &mut children) {
+            children.push(self.parse_internal_module_tail(Vec::new()));
+        } else {
+            children.push(self.parse_block(SyntaxKind::ModuleBlock));
+        }
+        return self.finish_node(SyntaxKind::ModuleDeclaration, children);
+    }
+
+    fn parse_import_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        self.parse_expected(SyntaxKind::ImportKeyword, &mut children);
+        let is_import_from = match self.token {
+            SyntaxKind::StringLiteral | SyntaxKind::OpenBraceToken | SyntaxKind::AsteriskToken => true,
+            _ => self.is_identifier() && self.look_ahead(|p| p.next_token() != SyntaxKind::EqualsToken),
+        };
+        if is_import_from {
+            return self.parse_import_from_declaration(children);
+        }
+        children.push(self.parse_identifier());
+        self.parse_expected(SyntaxKind::EqualsToken, &mut children);
+        let is_external_module_reference = self.token == SyntaxKind::RequireKeyword
+            && self.look_ahead(|p| p.next_token() == SyntaxKind::OpenParenToken);
+        if is_external_module_reference {
+            children.push(self.create_token_node());
+            self.parse_expected(SyntaxKind::OpenParenToken, &mut children);
+            children.push(self.parse_string_literal());
+            self.parse_expected(SyntaxKind::CloseParenToken, &mut children);
+        } else {
+            children.push(self.parse_entity_name());
+        }
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ImportDeclaration, children);
+    }
+
+    // `import "a";`, `import b, { c as d } from "e";` or `import * as f from "g";`
+    fn parse_import_from_declaration(&mut self, mut children: Vec<GreenNode>) -> GreenNode {
+        if self.token != SyntaxKind::StringLiteral {
+            children.push(self.parse_import_clause());
+            self.parse_expected(SyntaxKind::FromKeyword, &mut children);
+        }
+        children.push(self.parse_string_literal());
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ImportFromDeclaration, children);
+    }
+
+    fn parse_import_clause(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        if self.is_identifier() {
+            children.push(self.parse_identifier());
+            if !self.parse_optional(SyntaxKind::CommaToken, &mut children) {
+                return self.finish_node(SyntaxKind::ImportClause, children);
+            }
+        }
+        if self.token == SyntaxKind::AsteriskToken {
+            let mut namespace = vec![self.create_token_node()];
+            self.parse_expected(SyntaxKind::AsKeyword, &mut namespace);
+            namespace.push(self.parse_identifier());
+            children.push(self.finish_node(SyntaxKind::NamespaceImport, namespace));
+        } else {
+            children.push(self.parse_named_imports_or_exports(SyntaxKind::NamedImports));
+        }
+        return self.finish_node(SyntaxKind::ImportClause, children);
+    }
+
+    fn parse_named_imports_or_exports(&mut self, kind: SyntaxKind) -> GreenNode {
+        let mut children = Vec::new();
+        self.parse_expected(SyntaxKind::OpenBraceToken, &mut children);
+        let parse_specifier = if kind == SyntaxKind::NamedImports {
+            Parser::parse_import_specifier
+        } else {
+            Parser::parse_export_specifier
+        };
+        children.push(self.parse_delimited_list(ParsingContext::ImportOrExportSpecifiers, parse_specifier, true));
+        self.parse_expected(SyntaxKind::CloseBraceToken, &mut children);
+        return self.finish_node(kind, children);
+    }
+
+    // `a` or `b as c`, only the local name c has to be an identifier
+    fn parse_import_specifier(&mut self) -> GreenNode {
+        let mut children = Vec::new();
+        if self.look_ahead(|p| p.next_token() == SyntaxKind::AsKeyword) {
+            children.push(self.parse_identifier_name());
+            children.push(self.create_token_node());
+        }
+        children.push(self.parse_identifier());
+        return self.finish_node(SyntaxKind::ImportSpecifier, children);
+    }
+
+    // `a` or `b as c`, exported names can be keywords
+    fn parse_export_specifier(&mut self) -> GreenNode {
+        let mut children = vec![self.parse_identifier_name()];
+        if self.parse_optional(SyntaxKind::AsKeyword, &mut children) {
+            children.push(self.parse_identifier_name());
+        }
+        return self.finish_node(SyntaxKind::ExportSpecifier, children);
+    }
+
+    // `export * from "a";`, `export { b as c } from "d";` or `export { e };`
+    fn parse_export_declaration(&mut self) -> GreenNode {
+        let mut children = vec![self.create_token_node()];
+        if self.token == SyntaxKind::AsteriskToken {
+            children.push(self.create_token_node());
+            self.parse_expected(SyntaxKind::FromKeyword, &mut children);
+            children.push(self.parse_string_literal());
+        } else {
+            children.push(self.parse_named_imports_or_exports(SyntaxKind::NamedExports));
+            if self.parse_optional(SyntaxKind::FromKeyword, &mut children) {
+                children.push(self.parse_string_literal());
+            }
+        }
+        self.parse_semicolon(&mut children);
+        return self.finish_node(SyntaxKind::ExportDeclaration, children);
+    }
+}
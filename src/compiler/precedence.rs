@@ -0,0 +1,138 @@
+use super::types::{syntax_kind, SyntaxKind};
+
+// Operator precedence and associativity, shared by the parser, the printer and the passes that
+// decide where parentheses are needed. An operand binding looser than its operator has to be
+// parenthesized.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precedence {
+    Comma,
+    Assignment,
+    Conditional,
+    LogicalOr,
+    LogicalAnd,
+    BitwiseOr,
+    BitwiseXor,
+    BitwiseAnd,
+    Equality,
+    Relational,
+    Shift,
+    Additive,
+    Multiplicative,
+    Unary,
+    Postfix,
+    LeftHandSide, // Calls, `new`, property and element access
+    Primary,
+}
+
+impl Precedence {
+    // The next tighter level, the right operand of a left associative operator binds at least this tightly
+    pub fn higher(self) -> Precedence {
+        match self {
+            Precedence::Comma => Precedence::Assignment,
+            Precedence::Assignment => Precedence::Conditional,
+            Precedence::Conditional => Precedence::LogicalOr,
+            Precedence::LogicalOr => Precedence::LogicalAnd,
+            Precedence::LogicalAnd => Precedence::BitwiseOr,
+            Precedence::BitwiseOr => Precedence::BitwiseXor,
+            Precedence::BitwiseXor => Precedence::BitwiseAnd,
+            Precedence::BitwiseAnd => Precedence::Equality,
+            Precedence::Equality => Precedence::Relational,
+            Precedence::Relational => Precedence::Shift,
+            Precedence::Shift => Precedence::Additive,
+            Precedence::Additive => Precedence::Multiplicative,
+            Precedence::Multiplicative => Precedence::Unary,
+            Precedence::Unary => Precedence::Postfix,
+            Precedence::Postfix => Precedence::LeftHandSide,
+            Precedence::LeftHandSide | Precedence::Primary => Precedence::Primary,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Associativity {
+    Left,  // `a - b - c` is `(a - b) - c`
+    Right, // `a = b = c` is `a = (b = c)`
+}
+
+pub fn is_assignment_operator(token: SyntaxKind) -> bool {
+    return token as usize >= syntax_kind::FIRST_ASSIGNMENT as usize
+        && token as usize <= syntax_kind::LAST_ASSIGNMENT as usize;
+}
+
+// Precedence of the operators between two operands, the `?` of a conditional expression included
+pub fn get_binary_operator_precedence(token: SyntaxKind) -> Option<Precedence> {
+    let precedence = match token {
+        SyntaxKind::CommaToken => Precedence::Comma,
+        _ if is_assignment_operator(token) => Precedence::Assignment,
+        SyntaxKind::QuestionToken => Precedence::Conditional,
+        SyntaxKind::BarBarToken => Precedence::LogicalOr,
+        SyntaxKind::AmpersandAmpersandToken => Precedence::LogicalAnd,
+        SyntaxKind::BarToken => Precedence::BitwiseOr,
+        SyntaxKind::CaretToken => Precedence::BitwiseXor,
+        SyntaxKind::AmpersandToken => Precedence::BitwiseAnd,
+        SyntaxKind::EqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsToken
+        | SyntaxKind::EqualsEqualsEqualsToken
+        | SyntaxKind::ExclamationEqualsEqualsToken => Precedence::Equality,
+        SyntaxKind::LessThanToken
+        | SyntaxKind::GreaterThanToken
+        | SyntaxKind::LessThanEqualsToken
+        | SyntaxKind::GreaterThanEqualsToken
+        | SyntaxKind::InstanceOfKeyword
+        | SyntaxKind::InKeyword => Precedence::Relational,
+        SyntaxKind::LessThanLessThanToken
+        | SyntaxKind::GreaterThanGreaterThanToken
+        | SyntaxKind::GreaterThanGreaterThanGreaterThanToken => Precedence::Shift,
+        SyntaxKind::PlusToken | SyntaxKind::MinusToken => Precedence::Additive,
+        SyntaxKind::AsteriskToken | SyntaxKind::SlashToken | SyntaxKind::PercentToken => Precedence::Multiplicative,
+        _ => return None,
+    };
+    return Some(precedence);
+}
+
+pub fn get_prefix_operator_precedence(token: SyntaxKind) -> Option<Precedence> {
+    match token {
+        SyntaxKind::PlusToken
+        | SyntaxKind::MinusToken
+        | SyntaxKind::TildeToken
+        | SyntaxKind::ExclamationToken
+        | SyntaxKind::DeleteKeyword
+        | SyntaxKind::TypeOfKeyword
+        | SyntaxKind::VoidKeyword
+        | SyntaxKind::PlusPlusToken
+        | SyntaxKind::MinusMinusToken => Some(Precedence::Unary),
+        _ => None,
+    }
+}
+
+pub fn get_postfix_operator_precedence(token: SyntaxKind) -> Option<Precedence> {
+    match token {
+        SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken => Some(Precedence::Postfix),
+        _ => None,
+    }
+}
+
+// Assignments, conditional expressions and prefix operators group to the right
+pub fn get_associativity(precedence: Precedence) -> Associativity {
+    match precedence {
+        Precedence::Assignment | Precedence::Conditional | Precedence::Unary => Associativity::Right,
+        _ => Associativity::Left,
+    }
+}
+
+// Precedence of an expression of kind, operator is the operator token of a BinaryExpression
+pub fn get_expression_precedence(kind: SyntaxKind, operator: SyntaxKind) -> Precedence {
+    match kind {
+        SyntaxKind::BinaryExpression => get_binary_operator_precedence(operator).unwrap_or(Precedence::Primary),
+        SyntaxKind::ConditionalExpression => Precedence::Conditional,
+        SyntaxKind::ArrowFunction => Precedence::Assignment,
+        SyntaxKind::PrefixOperator | SyntaxKind::TypeAssertion => Precedence::Unary,
+        SyntaxKind::PostfixOperator => Precedence::Postfix,
+        SyntaxKind::CallExpression
+        | SyntaxKind::NewExpression
+        | SyntaxKind::PropertyAccess
+        | SyntaxKind::IndexedAccess => Precedence::LeftHandSide,
+        _ => Precedence::Primary,
+    }
+}
@@ -0,0 +1,1261 @@
+use super::ast::*;
+use super::precedence::{
+    get_associativity, get_binary_operator_precedence, get_expression_precedence, Associativity, Precedence,
+};
+use super::scanner::token_to_string;
+use super::syntax::SyntaxNode;
+use super::types::SyntaxKind;
+use std::mem;
+
+// Prints syntax trees back to TypeScript. The layout is regenerated, only the comments in front of
+// statements, members and closing braces are kept. Parentheses are added wherever the structure of
+// the tree would not survive a reparse without them, so rewritten trees print as they are built.
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum QuoteStyle {
+    Preserve,
+    Double,
+    Single,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SemicolonPolicy {
+    Always,
+    AsNeeded, // Only where the next statement would otherwise continue the previous one
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrinterOptions {
+    pub indent: String,
+    pub quote_style: QuoteStyle,
+    pub semicolons: SemicolonPolicy,
+}
+
+impl Default for PrinterOptions {
+    fn default() -> Self {
+        return PrinterOptions {
+            indent: "    ".to_string(),
+            quote_style: QuoteStyle::Preserve,
+            semicolons: SemicolonPolicy::Always,
+        };
+    }
+}
+
+// Prints node and everything below it, a SourceFile ends with a line break
+pub fn print_node(node: &SyntaxNode, options: &PrinterOptions) -> String {
+    let mut printer = Printer {
+        options,
+        out: String::new(),
+        indent: 0,
+        no_in: false,
+    };
+    printer.print_any(node);
+    return printer.out;
+}
+
+fn expression_precedence(expression: &Expression) -> Precedence {
+    let operator = match expression {
+        Expression::BinaryExpression(binary) => binary.operator(),
+        _ => SyntaxKind::Unknown,
+    };
+    return get_expression_precedence(expression.syntax().kind(), operator);
+}
+
+fn has_token(node: &SyntaxNode, kind: SyntaxKind) -> bool {
+    return node.children().any(|child| child.kind() == kind);
+}
+
+// `new a.b()` calls b, `new (a().b)()` needs parentheses to call a first
+fn contains_call(expression: &Expression) -> bool {
+    match expression {
+        Expression::CallExpression(_) => return true,
+        Expression::PropertyAccess(access) => return access.expression().is_some_and(|object| contains_call(&object)),
+        Expression::IndexedAccess(access) => return access.object().is_some_and(|object| contains_call(&object)),
+        _ => return false,
+    }
+}
+
+// `if (a) if (b) x; else y;` gives the else to the inner if
+fn ends_with_if_without_else(statement: &Statement) -> bool {
+    let last = match statement {
+        Statement::IfStatement(statement) => match statement.else_statement() {
+            Some(statement) => Some(statement),
+            None => return true,
+        },
+        Statement::WhileStatement(statement) => statement.statement(),
+        Statement::ForStatement(statement) => statement.statement(),
+        Statement::ForInStatement(statement) => statement.statement(),
+        Statement::WithStatement(statement) => statement.statement(),
+        Statement::LabelledStatement(statement) => statement.statement(),
+        _ => return false,
+    };
+    return last.is_some_and(|last| ends_with_if_without_else(&last));
+}
+
+fn starts_with_keyword(text: &str, keyword: &str) -> bool {
+    return text.starts_with(keyword)
+        && !text[keyword.len()..].starts_with(|ch: char| ch.is_alphanumeric() || ch == '_' || ch == '$');
+}
+
+// Statements starting with these continue a previous statement that is not ended by a semicolon, an
+// empty statement would end it instead
+fn continues_previous_statement(text: &str) -> bool {
+    return text.starts_with(['(', '[', '+', '-', '/', '<', '`', ';']);
+}
+
+// The scanner does not support escapes yet, a string keeps its quotes when it holds either quote
+fn quote_string(text: &str, style: QuoteStyle) -> String {
+    let quote = match style {
+        QuoteStyle::Preserve => return text.to_string(),
+        QuoteStyle::Double => '"',
+        QuoteStyle::Single => '\'',
+    };
+    let terminated = text.len() >= 2 && text.starts_with(['"', '\'']) && text.ends_with(&text[..1]);
+    if !terminated {
+        return text.to_string();
+    }
+    let content = &text[1..text.len() - 1];
+    if content.contains('"') || content.contains('\'') {
+        return text.to_string();
+    }
+    return format!("{}{}{}", quote, content, quote);
+}
+
+struct Printer<'a> {
+    options: &'a PrinterOptions,
+    out: String,
+    indent: usize,
+    no_in: bool, // In the initializer of a for statement, where `in` must be parenthesized
+}
+
+impl<'a> Printer<'a> {
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+    }
+
+    // Output never starts with a blank line
+    fn new_line(&mut self) {
+        if self.out.is_empty() {
+            return;
+        }
+        self.out.push('\n');
+        for _ in 0..self.indent {
+            self.out.push_str(&self.options.indent);
+        }
+    }
+
+    // Prints into a separate buffer so the text can be inspected before it is written
+    fn capture<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> (String, T) {
+        let saved = mem::take(&mut self.out);
+        let result = f(self);
+        return (mem::replace(&mut self.out, saved), result);
+    }
+
+    fn print_any(&mut self, node: &SyntaxNode) {
+        if let Some(source_file) = SourceFile::cast(node.clone()) {
+            self.print_statements(source_file.statements());
+            if let Some(end_of_file) = source_file.end_of_file_token() {
+                self.print_leading_comments(&end_of_file);
+            }
+            if !self.out.is_empty() {
+                self.out.push('\n');
+            }
+        } else if let Some(statement) = Statement::cast(node.clone()) {
+            if self.print_statement(&statement) {
+                self.write(";");
+            }
+        } else if let Some(expression) = Expression::cast(node.clone()) {
+            self.print_expression(&expression, Precedence::Comma);
+        } else if let Some(type_node) = TypeNode::cast(node.clone()) {
+            self.print_type(&type_node);
+        } else if let Some(clause) = CaseOrDefaultClause::cast(node.clone()) {
+            self.print_clause(&clause);
+        } else if let Some(declaration) = VariableDeclaration::cast(node.clone()) {
+            self.print_variable_declaration(&declaration);
+        } else if let Some(parameter) = Parameter::cast(node.clone()) {
+            self.print_parameter(&parameter);
+        } else if let Some(parameter) = TypeParameter::cast(node.clone()) {
+            self.print_type_parameter(&parameter);
+        } else if let Some(member) = EnumMember::cast(node.clone()) {
+            self.print_enum_member(&member);
+        } else if let Some(property) = PropertyAssignment::cast(node.clone()) {
+            self.print_property_assignment(&property);
+        } else if let Some(name) = QualifiedName::cast(node.clone()) {
+            self.print_entity_name(Some(EntityName::QualifiedName(name)));
+        } else if ClassElement::can_cast(node.kind()) || TypeMember::can_cast(node.kind()) {
+            if self.print_member(node) {
+                self.write(";");
+            }
+        } else if node.is_token() {
+            self.write(node.token_text());
+        } else {
+            self.write(&node.text());
+        }
+    }
+
+    fn print_leading_comments(&mut self, node: &SyntaxNode) {
+        let token = match node.first_token() {
+            Some(token) => token,
+            None => return,
+        };
+        for trivia in token.leading_trivia() {
+            if matches!(
+                trivia.kind,
+                SyntaxKind::SingleLineCommentTrivia | SyntaxKind::MultiLineCommentTrivia
+            ) {
+                self.new_line();
+                self.write(&trivia.text);
+            }
+        }
+    }
+
+    // Comments in front of the closing token of owner end the list before it
+    fn print_closing_comments(&mut self, owner: &SyntaxNode, kind: SyntaxKind) {
+        if let Some(token) = owner.children().find(|child| child.kind() == kind) {
+            self.print_leading_comments(&token);
+        }
+    }
+
+    // Each statement goes on its own line, the semicolons of the statements that take one are written
+    // here so the policy can look at the statement that follows
+    fn print_statements(&mut self, statements: impl Iterator<Item = Statement>) {
+        let printed: Vec<(SyntaxNode, String, bool)> = statements
+            .map(|statement| {
+                let (text, wants_semicolon) = self.capture(|printer| printer.print_statement(&statement));
+                return (statement.syntax().clone(), text, wants_semicolon);
+            })
+            .collect();
+        for (index, (node, text, wants_semicolon)) in printed.iter().enumerate() {
+            self.print_leading_comments(node);
+            self.new_line();
+            self.write(text);
+            if *wants_semicolon {
+                let next = printed.get(index + 1).map(|(_, text, _)| text.as_str());
+                if self.options.semicolons == SemicolonPolicy::Always || next.is_some_and(continues_previous_statement)
+                {
+                    self.write(";");
+                }
+            }
+        }
+    }
+
+    fn print_block(&mut self, statements: AstChildren<Statement>, owner: &SyntaxNode) {
+        self.write("{");
+        let start = self.out.len();
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.indent += 1;
+        self.print_statements(statements);
+        self.print_closing_comments(owner, SyntaxKind::CloseBraceToken);
+        self.indent -= 1;
+        self.no_in = no_in;
+        if self.out.len() > start {
+            self.new_line();
+        }
+        self.write("}");
+    }
+
+    // Body of a function, method or accessor, true when there is none and a semicolon ends the
+    // declaration instead
+    fn print_body(&mut self, body: Option<FunctionBlock>) -> bool {
+        match body {
+            Some(body) => {
+                self.write(" ");
+                self.print_block(body.statements(), body.syntax());
+                return false;
+            }
+            None => return true,
+        }
+    }
+
+    // Statements of if, while, for and with, a block stays on the same line
+    fn print_embedded_statement(&mut self, statement: Option<Statement>) {
+        if let Some(Statement::Block(block)) = statement {
+            self.write(" ");
+            return self.print_block(block.statements(), block.syntax());
+        }
+        self.indent += 1;
+        self.new_line();
+        if statement.is_none_or(|statement| self.print_statement(&statement)) {
+            self.write(";");
+        }
+        self.indent -= 1;
+    }
+
+    fn print_parenthesized_condition(&mut self, keyword: &str, expression: Option<Expression>) {
+        self.write(keyword);
+        self.write(" (");
+        self.print_optional_expression(expression, Precedence::Comma);
+        self.write(")");
+    }
+
+    // Prints statement without its semicolon, true when it takes one
+    fn print_statement(&mut self, statement: &Statement) -> bool {
+        match statement {
+            Statement::Block(block) => self.print_block(block.statements(), block.syntax()),
+            Statement::VariableStatement(statement) => {
+                self.print_modifiers(statement);
+                self.write("var ");
+                self.print_variable_declarations(statement.declarations());
+                return true;
+            }
+            Statement::EmptyStatement(_) => self.write(";"),
+            Statement::ExpressionStatement(statement) => {
+                let (text, _) = self
+                    .capture(|printer| printer.print_optional_expression(statement.expression(), Precedence::Comma));
+                if text.starts_with('{') || starts_with_keyword(&text, "function") {
+                    self.write("(");
+                    self.write(&text);
+                    self.write(")");
+                } else {
+                    self.write(&text);
+                }
+                return true;
+            }
+            Statement::IfStatement(statement) => self.print_if_statement(statement),
+            Statement::DoStatement(statement) => {
+                self.write("do");
+                let body = statement.statement();
+                let is_block = matches!(body, Some(Statement::Block(_)));
+                self.print_embedded_statement(body);
+                if is_block {
+                    self.write(" ");
+                } else {
+                    self.new_line();
+                }
+                self.print_parenthesized_condition("while", statement.expression());
+                return true;
+            }
+            Statement::WhileStatement(statement) => {
+                self.print_parenthesized_condition("while", statement.expression());
+                self.print_embedded_statement(statement.statement());
+            }
+            Statement::ForStatement(statement) => {
+                self.write("for (");
+                let no_in = mem::replace(&mut self.no_in, true);
+                if has_token(statement.syntax(), SyntaxKind::VarKeyword) {
+                    self.write("var ");
+                    self.print_variable_declarations(statement.declarations());
+                } else {
+                    self.print_optional_expression(statement.initializer(), Precedence::Comma);
+                }
+                self.no_in = no_in;
+                self.write(";");
+                if let Some(condition) = statement.condition() {
+                    self.write(" ");
+                    self.print_expression(&condition, Precedence::Comma);
+                }
+                self.write(";");
+                if let Some(incrementor) = statement.incrementor() {
+                    self.write(" ");
+                    self.print_expression(&incrementor, Precedence::Comma);
+                }
+                self.write(")");
+                self.print_embedded_statement(statement.statement());
+            }
+            Statement::ForInStatement(statement) => {
+                self.write("for (");
+                let no_in = mem::replace(&mut self.no_in, true);
+                if let Some(declaration) = statement.declaration() {
+                    self.write("var ");
+                    self.print_variable_declaration(&declaration);
+                } else {
+                    self.print_optional_expression(statement.variable(), Precedence::LeftHandSide);
+                }
+                self.no_in = no_in;
+                self.write(" in ");
+                self.print_optional_expression(statement.expression(), Precedence::Comma);
+                self.write(")");
+                self.print_embedded_statement(statement.statement());
+            }
+            Statement::ContinueStatement(statement) => {
+                self.write("continue");
+                self.print_label(statement.label());
+                return true;
+            }
+            Statement::BreakStatement(statement) => {
+                self.write("break");
+                self.print_label(statement.label());
+                return true;
+            }
+            Statement::ReturnStatement(statement) => {
+                self.write("return");
+                if let Some(expression) = statement.expression() {
+                    self.write(" ");
+                    self.print_expression(&expression, Precedence::Comma);
+                }
+                return true;
+            }
+            Statement::WithStatement(statement) => {
+                self.print_parenthesized_condition("with", statement.expression());
+                self.print_embedded_statement(statement.statement());
+            }
+            Statement::SwitchStatement(statement) => {
+                self.print_parenthesized_condition("switch", statement.expression());
+                self.write(" {");
+                let start = self.out.len();
+                self.indent += 1;
+                for clause in statement.clauses() {
+                    self.print_leading_comments(clause.syntax());
+                    self.new_line();
+                    self.print_clause(&clause);
+                }
+                self.print_closing_comments(statement.syntax(), SyntaxKind::CloseBraceToken);
+                self.indent -= 1;
+                if self.out.len() > start {
+                    self.new_line();
+                }
+                self.write("}");
+            }
+            Statement::LabelledStatement(statement) => {
+                self.print_optional_identifier(statement.label());
+                self.write(": ");
+                return statement
+                    .statement()
+                    .is_none_or(|statement| self.print_statement(&statement));
+            }
+            Statement::ThrowStatement(statement) => {
+                self.write("throw ");
+                self.print_optional_expression(statement.expression(), Precedence::Comma);
+                return true;
+            }
+            Statement::TryStatement(statement) => {
+                self.write("try ");
+                if let Some(block) = statement.try_block() {
+                    self.print_block(block.statements(), block.syntax());
+                }
+                if let Some(block) = statement.catch_block() {
+                    self.write(" catch (");
+                    self.print_optional_identifier(block.variable());
+                    self.write(") ");
+                    self.print_block(block.statements(), block.syntax());
+                }
+                if let Some(block) = statement.finally_block() {
+                    self.write(" finally ");
+                    self.print_block(block.statements(), block.syntax());
+                }
+            }
+            Statement::DebuggerStatement(_) => {
+                self.write("debugger");
+                return true;
+            }
+            Statement::FunctionDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("function ");
+                self.print_optional_identifier(declaration.name());
+                self.print_signature(declaration);
+                return self.print_body(declaration.body());
+            }
+            Statement::ClassDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("class ");
+                self.print_optional_identifier(declaration.name());
+                self.print_type_parameters(declaration.type_parameters());
+                if let Some(base_type) = declaration.base_type() {
+                    self.write(" extends ");
+                    self.print_type(&TypeNode::TypeReference(base_type));
+                }
+                self.print_heritage(" implements ", declaration.implemented_types());
+                self.write(" ");
+                let members = declaration.members().map(|member| member.syntax().clone());
+                self.print_members(members, declaration.syntax());
+            }
+            Statement::InterfaceDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("interface ");
+                self.print_optional_identifier(declaration.name());
+                self.print_type_parameters(declaration.type_parameters());
+                self.print_heritage(" extends ", declaration.base_types());
+                self.write(" ");
+                let members = declaration.members().map(|member| member.syntax().clone());
+                self.print_members(members, declaration.syntax());
+            }
+            Statement::EnumDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("enum ");
+                self.print_optional_identifier(declaration.name());
+                self.write(" {");
+                let start = self.out.len();
+                self.indent += 1;
+                let members: Vec<EnumMember> = declaration.members().collect();
+                for (index, member) in members.iter().enumerate() {
+                    self.print_leading_comments(member.syntax());
+                    self.new_line();
+                    self.print_enum_member(member);
+                    if index + 1 < members.len() {
+                        self.write(",");
+                    }
+                }
+                self.print_closing_comments(declaration.syntax(), SyntaxKind::CloseBraceToken);
+                self.indent -= 1;
+                if self.out.len() > start {
+                    self.new_line();
+                }
+                self.write("}");
+            }
+            Statement::ModuleDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("module ");
+                let mut declaration = declaration.clone();
+                loop {
+                    self.print_module_name(declaration.name());
+                    match declaration.body() {
+                        Some(ModuleBody::ModuleDeclaration(inner)) => {
+                            self.write(".");
+                            declaration = inner;
+                        }
+                        Some(ModuleBody::ModuleBlock(block)) => {
+                            self.write(" ");
+                            self.print_block(block.statements(), block.syntax());
+                            break;
+                        }
+                        None => break,
+                    }
+                }
+            }
+            Statement::ImportDeclaration(declaration) => {
+                self.print_modifiers(declaration);
+                self.write("import ");
+                self.print_optional_identifier(declaration.name());
+                self.write(" = ");
+                if let Some(name) = declaration.external_module_name() {
+                    self.write("require(");
+                    self.print_literal(name.syntax());
+                    self.write(")");
+                } else {
+                    self.print_entity_name(declaration.entity_name());
+                }
+                return true;
+            }
+            Statement::ExportAssignment(assignment) => {
+                self.write("export = ");
+                self.print_optional_identifier(assignment.export_name());
+                return true;
+            }
+            Statement::ImportFromDeclaration(declaration) => {
+                self.write("import ");
+                if let Some(clause) = declaration.import_clause() {
+                    self.print_import_clause(&clause);
+                    self.write(" from ");
+                }
+                self.print_module_specifier(declaration.module_specifier());
+                return true;
+            }
+            Statement::ExportDeclaration(declaration) => {
+                self.write("export ");
+                if let Some(exports) = declaration.export_clause() {
+                    let specifiers = exports
+                        .elements()
+                        .map(|specifier| (specifier.property_name(), specifier.name()));
+                    self.print_import_or_export_specifiers(specifiers);
+                } else {
+                    self.write("*");
+                }
+                if let Some(specifier) = declaration.module_specifier() {
+                    self.write(" from ");
+                    self.print_module_specifier(Some(specifier));
+                }
+                return true;
+            }
+        }
+        return false;
+    }
+
+    fn print_import_clause(&mut self, clause: &ImportClause) {
+        let name = clause.name();
+        let bindings = clause.named_bindings();
+        if name.is_some() {
+            self.print_optional_identifier(name);
+            if bindings.is_some() {
+                self.write(", ");
+            }
+        }
+        match bindings {
+            Some(NamedImportBindings::NamespaceImport(namespace)) => {
+                self.write("* as ");
+                self.print_optional_identifier(namespace.name());
+            }
+            Some(NamedImportBindings::NamedImports(imports)) => {
+                let specifiers = imports
+                    .elements()
+                    .map(|specifier| (specifier.property_name(), specifier.name()));
+                self.print_import_or_export_specifiers(specifiers);
+            }
+            None => {}
+        }
+    }
+
+    // `{ a, b as c }`
+    fn print_import_or_export_specifiers(
+        &mut self,
+        specifiers: impl Iterator<Item = (Option<Identifier>, Option<Identifier>)>,
+    ) {
+        self.write("{");
+        for (index, (property_name, name)) in specifiers.enumerate() {
+            self.write(if index == 0 { " " } else { ", " });
+            if property_name.is_some() {
+                self.print_optional_identifier(property_name);
+                self.write(" as ");
+            }
+            self.print_optional_identifier(name);
+            self.write(" ");
+        }
+        self.write("}");
+    }
+
+    fn print_module_specifier(&mut self, specifier: Option<LiteralExpression>) {
+        if let Some(specifier) = specifier {
+            self.print_literal(specifier.syntax());
+        }
+    }
+
+    fn print_if_statement(&mut self, statement: &IfStatement) {
+        self.print_parenthesized_condition("if", statement.expression());
+        let then_statement = statement.then_statement();
+        let else_statement = statement.else_statement();
+        let braced = match then_statement {
+            Some(Statement::Block(block)) => {
+                self.write(" ");
+                self.print_block(block.statements(), block.syntax());
+                true
+            }
+            Some(then_statement) if else_statement.is_some() && ends_with_if_without_else(&then_statement) => {
+                self.write(" {");
+                self.indent += 1;
+                self.new_line();
+                if self.print_statement(&then_statement) {
+                    self.write(";");
+                }
+                self.indent -= 1;
+                self.new_line();
+                self.write("}");
+                true
+            }
+            then_statement => {
+                self.print_embedded_statement(then_statement);
+                false
+            }
+        };
+        let else_statement = match else_statement {
+            Some(else_statement) => else_statement,
+            None => return,
+        };
+        if braced {
+            self.write(" else");
+        } else {
+            self.new_line();
+            self.write("else");
+        }
+        match else_statement {
+            Statement::IfStatement(statement) => {
+                self.write(" ");
+                self.print_if_statement(&statement);
+            }
+            statement => self.print_embedded_statement(Some(statement)),
+        }
+    }
+
+    fn print_clause(&mut self, clause: &CaseOrDefaultClause) {
+        let statements = match clause {
+            CaseOrDefaultClause::CaseClause(clause) => {
+                self.write("case ");
+                self.print_optional_expression(clause.expression(), Precedence::Comma);
+                clause.statements()
+            }
+            CaseOrDefaultClause::DefaultClause(clause) => {
+                self.write("default");
+                clause.statements()
+            }
+        };
+        self.write(":");
+        self.indent += 1;
+        self.print_statements(statements);
+        self.indent -= 1;
+    }
+
+    fn print_label(&mut self, label: Option<Identifier>) {
+        if let Some(label) = label {
+            self.write(" ");
+            self.write(label.syntax().token_text());
+        }
+    }
+
+    fn print_modifiers(&mut self, node: &impl ModifiersOwner) {
+        for modifier in node.modifiers() {
+            self.write(modifier.token_text());
+            self.write(" ");
+        }
+    }
+
+    fn print_variable_declarations(&mut self, declarations: AstChildren<VariableDeclaration>) {
+        for (index, declaration) in declarations.enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.print_variable_declaration(&declaration);
+        }
+    }
+
+    fn print_variable_declaration(&mut self, declaration: &VariableDeclaration) {
+        self.print_optional_identifier(declaration.name());
+        self.print_type_annotation(declaration.type_annotation());
+        self.print_initializer(declaration.initializer());
+    }
+
+    fn print_type_annotation(&mut self, type_node: Option<TypeNode>) {
+        if let Some(type_node) = type_node {
+            self.write(": ");
+            self.print_type(&type_node);
+        }
+    }
+
+    fn print_initializer(&mut self, initializer: Option<Expression>) {
+        if let Some(initializer) = initializer {
+            self.write(" = ");
+            self.print_expression(&initializer, Precedence::Assignment);
+        }
+    }
+
+    fn print_heritage(&mut self, keyword: &str, types: AstChildren<TypeReference>) {
+        for (index, type_reference) in types.enumerate() {
+            self.write(if index == 0 { keyword } else { ", " });
+            self.print_type(&TypeNode::TypeReference(type_reference));
+        }
+    }
+
+    fn print_enum_member(&mut self, member: &EnumMember) {
+        self.print_property_name(member.name());
+        self.print_initializer(member.initializer());
+    }
+
+    fn print_members(&mut self, members: impl Iterator<Item = SyntaxNode>, owner: &SyntaxNode) {
+        self.write("{");
+        let start = self.out.len();
+        self.indent += 1;
+        for member in members {
+            self.print_leading_comments(&member);
+            self.new_line();
+            if self.print_member(&member) {
+                self.write(";");
+            }
+        }
+        self.print_closing_comments(owner, SyntaxKind::CloseBraceToken);
+        self.indent -= 1;
+        if self.out.len() > start {
+            self.new_line();
+        }
+        self.write("}");
+    }
+
+    // Prints a member of a class, interface, type literal or object literal, true when it ends with
+    // a semicolon
+    fn print_member(&mut self, member: &SyntaxNode) -> bool {
+        if let Some(property) = Property::cast(member.clone()) {
+            self.print_modifiers(&property);
+            self.print_property_name(property.name());
+            if property.question_token().is_some() {
+                self.write("?");
+            }
+            self.print_type_annotation(property.type_annotation());
+            self.print_initializer(property.initializer());
+            return true;
+        }
+        if let Some(method) = Method::cast(member.clone()) {
+            self.print_modifiers(&method);
+            self.print_property_name(method.name());
+            if method.question_token().is_some() {
+                self.write("?");
+            }
+            self.print_signature(&method);
+            return self.print_body(method.body());
+        }
+        if let Some(constructor) = Constructor::cast(member.clone()) {
+            self.print_modifiers(&constructor);
+            self.write("constructor");
+            self.print_signature(&constructor);
+            return self.print_body(constructor.body());
+        }
+        if let Some(accessor) = GetAccessor::cast(member.clone()) {
+            self.print_modifiers(&accessor);
+            self.write("get ");
+            self.print_property_name(accessor.name());
+            self.print_signature(&accessor);
+            return self.print_body(accessor.body());
+        }
+        if let Some(accessor) = SetAccessor::cast(member.clone()) {
+            self.print_modifiers(&accessor);
+            self.write("set ");
+            self.print_property_name(accessor.name());
+            self.print_signature(&accessor);
+            return self.print_body(accessor.body());
+        }
+        if let Some(signature) = CallSignature::cast(member.clone()) {
+            self.print_signature(&signature);
+            return true;
+        }
+        if let Some(signature) = ConstructSignature::cast(member.clone()) {
+            self.write("new ");
+            self.print_signature(&signature);
+            return true;
+        }
+        if let Some(signature) = IndexSignature::cast(member.clone()) {
+            self.print_modifiers(&signature);
+            self.write("[");
+            self.print_parameters(signature.parameters());
+            self.write("]");
+            self.print_type_annotation(signature.type_annotation());
+            return true;
+        }
+        self.write(&member.text());
+        return false;
+    }
+
+    fn print_signature(&mut self, signature: &impl SignatureDeclaration) {
+        self.print_type_parameters(signature.type_parameters());
+        self.write("(");
+        self.print_parameters(signature.parameters());
+        self.write(")");
+        self.print_type_annotation(signature.return_type());
+    }
+
+    fn print_type_parameters(&mut self, type_parameters: AstChildren<TypeParameter>) {
+        let mut any = false;
+        for (index, type_parameter) in type_parameters.enumerate() {
+            self.write(if index == 0 { "<" } else { ", " });
+            self.print_type_parameter(&type_parameter);
+            any = true;
+        }
+        if any {
+            self.write(">");
+        }
+    }
+
+    fn print_type_parameter(&mut self, type_parameter: &TypeParameter) {
+        self.print_optional_identifier(type_parameter.name());
+        if let Some(constraint) = type_parameter.constraint() {
+            self.write(" extends ");
+            self.print_type(&constraint);
+        }
+    }
+
+    fn print_parameters(&mut self, parameters: AstChildren<Parameter>) {
+        for (index, parameter) in parameters.enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.print_parameter(&parameter);
+        }
+    }
+
+    fn print_parameter(&mut self, parameter: &Parameter) {
+        self.print_modifiers(parameter);
+        if parameter.dot_dot_dot_token().is_some() {
+            self.write("...");
+        }
+        self.print_optional_identifier(parameter.name());
+        if parameter.question_token().is_some() {
+            self.write("?");
+        }
+        self.print_type_annotation(parameter.type_annotation());
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.print_initializer(parameter.initializer());
+        self.no_in = no_in;
+    }
+
+    fn print_optional_identifier(&mut self, identifier: Option<Identifier>) {
+        if let Some(identifier) = identifier {
+            self.write(identifier.syntax().token_text());
+        }
+    }
+
+    fn print_literal(&mut self, token: &SyntaxNode) {
+        if token.kind() == SyntaxKind::StringLiteral {
+            let text = quote_string(token.token_text(), self.options.quote_style);
+            return self.write(&text);
+        }
+        self.write(token.token_text());
+    }
+
+    fn print_property_name(&mut self, name: Option<PropertyName>) {
+        match name {
+            Some(PropertyName::Identifier(identifier)) => self.write(identifier.syntax().token_text()),
+            Some(PropertyName::LiteralExpression(literal)) => self.print_literal(literal.syntax()),
+            None => {}
+        }
+    }
+
+    fn print_module_name(&mut self, name: Option<ModuleName>) {
+        match name {
+            Some(ModuleName::Identifier(identifier)) => self.write(identifier.syntax().token_text()),
+            Some(ModuleName::LiteralExpression(literal)) => self.print_literal(literal.syntax()),
+            None => {}
+        }
+    }
+
+    fn print_entity_name(&mut self, name: Option<EntityName>) {
+        match name {
+            Some(EntityName::Identifier(identifier)) => self.write(identifier.syntax().token_text()),
+            Some(EntityName::QualifiedName(name)) => {
+                self.print_entity_name(name.left());
+                self.write(".");
+                self.print_optional_identifier(name.right());
+            }
+            None => {}
+        }
+    }
+
+    fn print_type(&mut self, type_node: &TypeNode) {
+        match type_node {
+            TypeNode::KeywordType(keyword) => self.write(keyword.syntax().token_text()),
+            TypeNode::TypeReference(reference) => {
+                self.print_entity_name(reference.type_name());
+                self.print_type_arguments(reference.type_arguments());
+            }
+            TypeNode::TypeQuery(query) => {
+                self.write("typeof ");
+                self.print_entity_name(query.expr_name());
+            }
+            TypeNode::TypeLiteral(literal) => self.print_type_literal(literal, true),
+            TypeNode::ArrayType(array) => {
+                // Types cannot be parenthesized, `(() => T)[]` is written `{ (): T }[]`
+                match array.element_type() {
+                    Some(TypeNode::TypeLiteral(literal)) => self.print_type_literal(&literal, false),
+                    Some(element_type) => self.print_type(&element_type),
+                    None => {}
+                }
+                self.write("[]");
+            }
+        }
+    }
+
+    fn print_type_literal(&mut self, literal: &TypeLiteral, allow_function_type: bool) {
+        let members: Vec<TypeMember> = literal.members().collect();
+        if allow_function_type && literal.is_function_type() {
+            match members.first() {
+                Some(TypeMember::CallSignature(signature)) => return self.print_function_type(signature),
+                Some(TypeMember::ConstructSignature(signature)) => {
+                    self.write("new ");
+                    return self.print_function_type(signature);
+                }
+                _ => {}
+            }
+        }
+        if members.is_empty() {
+            return self.write("{}");
+        }
+        self.write("{ ");
+        for member in members {
+            if self.print_member(member.syntax()) {
+                self.write(";");
+            }
+            self.write(" ");
+        }
+        self.write("}");
+    }
+
+    fn print_function_type(&mut self, signature: &impl SignatureDeclaration) {
+        self.print_type_parameters(signature.type_parameters());
+        self.write("(");
+        self.print_parameters(signature.parameters());
+        self.write(") => ");
+        if let Some(return_type) = signature.return_type() {
+            self.print_type(&return_type);
+        }
+    }
+
+    fn print_type_arguments(&mut self, type_arguments: AstChildren<TypeNode>) {
+        let mut any = false;
+        for (index, type_argument) in type_arguments.enumerate() {
+            self.write(if index == 0 { "<" } else { ", " });
+            self.print_type(&type_argument);
+            any = true;
+        }
+        if any {
+            self.write(">");
+        }
+    }
+
+    fn print_optional_expression(&mut self, expression: Option<Expression>, min_precedence: Precedence) {
+        if let Some(expression) = expression {
+            self.print_expression(&expression, min_precedence);
+        }
+    }
+
+    // Prints expression, in parentheses when it binds looser than min_precedence
+    fn print_expression(&mut self, expression: &Expression, min_precedence: Precedence) {
+        let is_in =
+            matches!(expression, Expression::BinaryExpression(binary) if binary.operator() == SyntaxKind::InKeyword);
+        if expression_precedence(expression) < min_precedence || (is_in && self.no_in) {
+            return self.print_parenthesized(expression);
+        }
+        self.print_unparenthesized(expression);
+    }
+
+    fn print_parenthesized(&mut self, expression: &Expression) {
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.write("(");
+        self.print_unparenthesized(expression);
+        self.write(")");
+        self.no_in = no_in;
+    }
+
+    // Expressions where `in` is allowed again, inside brackets and argument lists
+    fn print_nested_expression(&mut self, expression: Option<Expression>, min_precedence: Precedence) {
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.print_optional_expression(expression, min_precedence);
+        self.no_in = no_in;
+    }
+
+    // The object of a member access or the callee of a call. `new X` needs parentheses not to take
+    // the arguments, `1.x` would scan as a number.
+    fn print_member_object(&mut self, expression: Option<Expression>, is_property_access: bool) {
+        let needs_parentheses = match &expression {
+            Some(Expression::NewExpression(new)) => !has_token(new.syntax(), SyntaxKind::OpenParenToken),
+            Some(Expression::LiteralExpression(literal)) => {
+                is_property_access
+                    && literal.kind() == SyntaxKind::NumericLiteral
+                    && literal.syntax().token_text().bytes().all(|byte| byte.is_ascii_digit())
+            }
+            _ => false,
+        };
+        match expression {
+            Some(expression) if needs_parentheses => self.print_parenthesized(&expression),
+            expression => self.print_optional_expression(expression, Precedence::LeftHandSide),
+        }
+    }
+
+    fn print_arguments(&mut self, arguments: AstChildren<Expression>) {
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.write("(");
+        for (index, argument) in arguments.enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.print_expression(&argument, Precedence::Assignment);
+        }
+        self.write(")");
+        self.no_in = no_in;
+    }
+
+    fn print_unparenthesized(&mut self, expression: &Expression) {
+        match expression {
+            Expression::Identifier(identifier) => self.write(identifier.syntax().token_text()),
+            Expression::LiteralExpression(literal) => self.print_literal(literal.syntax()),
+            Expression::ThisExpression(_) => self.write("this"),
+            Expression::SuperExpression(_) => self.write("super"),
+            Expression::ArrayLiteral(array) => {
+                let no_in = mem::replace(&mut self.no_in, false);
+                let elements: Vec<Expression> = array.elements().collect();
+                self.write("[");
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.print_expression(element, Precedence::Assignment);
+                }
+                // A hole at the end needs a comma after it to count
+                if let Some(Expression::OmittedExpression(_)) = elements.last() {
+                    self.write(",");
+                }
+                self.write("]");
+                self.no_in = no_in;
+            }
+            Expression::ObjectLiteral(object) => self.print_object_literal(object),
+            Expression::PropertyAccess(access) => {
+                self.print_member_object(access.expression(), true);
+                self.write(".");
+                self.print_optional_identifier(access.name());
+            }
+            Expression::IndexedAccess(access) => {
+                self.print_member_object(access.object(), false);
+                self.write("[");
+                self.print_nested_expression(access.index(), Precedence::Comma);
+                self.write("]");
+            }
+            Expression::CallExpression(call) => {
+                self.print_member_object(call.expression(), false);
+                self.print_type_arguments(call.type_arguments());
+                self.print_arguments(call.arguments());
+            }
+            Expression::NewExpression(new) => {
+                self.write("new ");
+                let has_arguments = has_token(new.syntax(), SyntaxKind::OpenParenToken);
+                match new.expression() {
+                    Some(callee) if contains_call(&callee) => self.print_parenthesized(&callee),
+                    // `new new X` needs no parentheses, only arguments would be taken by the inner `new`
+                    callee if !has_arguments => self.print_optional_expression(callee, Precedence::LeftHandSide),
+                    callee => self.print_member_object(callee, false),
+                }
+                self.print_type_arguments(new.type_arguments());
+                if has_arguments {
+                    self.print_arguments(new.arguments());
+                }
+            }
+            Expression::TypeAssertion(assertion) => {
+                self.write("<");
+                if let Some(type_node) = assertion.type_node() {
+                    self.print_type(&type_node);
+                }
+                self.write(">");
+                self.print_optional_expression(assertion.operand(), Precedence::Unary);
+            }
+            Expression::ParenExpression(paren) => {
+                let no_in = mem::replace(&mut self.no_in, false);
+                self.write("(");
+                self.print_optional_expression(paren.expression(), Precedence::Comma);
+                self.write(")");
+                self.no_in = no_in;
+            }
+            Expression::FunctionExpression(function) => {
+                self.write("function ");
+                self.print_optional_identifier(function.name());
+                self.print_signature(function);
+                self.print_body(function.body());
+            }
+            Expression::ArrowFunction(arrow) => self.print_arrow_function(arrow),
+            Expression::PrefixOperator(prefix) => {
+                let operator = prefix.operator();
+                let (operand, _) =
+                    self.capture(|printer| printer.print_optional_expression(prefix.operand(), Precedence::Unary));
+                self.write(token_to_string(operator).unwrap_or(""));
+                // `- -x` is not `--x`
+                let separated = match operator {
+                    SyntaxKind::TypeOfKeyword | SyntaxKind::VoidKeyword | SyntaxKind::DeleteKeyword => true,
+                    SyntaxKind::PlusToken | SyntaxKind::PlusPlusToken => operand.starts_with('+'),
+                    SyntaxKind::MinusToken | SyntaxKind::MinusMinusToken => operand.starts_with('-'),
+                    _ => false,
+                };
+                if separated {
+                    self.write(" ");
+                }
+                self.write(&operand);
+            }
+            Expression::PostfixOperator(postfix) => {
+                self.print_optional_expression(postfix.operand(), Precedence::LeftHandSide);
+                self.write(token_to_string(postfix.operator()).unwrap_or(""));
+            }
+            Expression::BinaryExpression(binary) => self.print_binary_expression(binary),
+            Expression::ConditionalExpression(conditional) => {
+                self.print_optional_expression(conditional.condition(), Precedence::LogicalOr);
+                self.write(" ? ");
+                self.print_nested_expression(conditional.when_true(), Precedence::Assignment);
+                self.write(" : ");
+                self.print_optional_expression(conditional.when_false(), Precedence::Assignment);
+            }
+            Expression::OmittedExpression(_) => {}
+        }
+    }
+
+    fn print_binary_expression(&mut self, binary: &BinaryExpression) {
+        let operator = binary.operator();
+        let precedence = get_binary_operator_precedence(operator).unwrap_or(Precedence::Primary);
+        // Only a left hand side expression can be assigned to
+        let (left_precedence, right_precedence) = match get_associativity(precedence) {
+            Associativity::Left => (precedence, precedence.higher()),
+            Associativity::Right => (Precedence::LeftHandSide, precedence),
+        };
+        let (right, _) = self.capture(|printer| printer.print_optional_expression(binary.right(), right_precedence));
+        // `a < b > (c)` would parse as a call with type arguments
+        let left = binary.left();
+        let is_type_argument_like = operator == SyntaxKind::GreaterThanToken
+            && right.starts_with('(')
+            && matches!(&left, Some(Expression::BinaryExpression(left)) if left.operator() == SyntaxKind::LessThanToken);
+        match left {
+            Some(left) if is_type_argument_like => self.print_parenthesized(&left),
+            left => self.print_optional_expression(left, left_precedence),
+        }
+        if operator == SyntaxKind::CommaToken {
+            self.write(", ");
+        } else {
+            self.write(" ");
+            self.write(token_to_string(operator).unwrap_or(""));
+            self.write(" ");
+        }
+        self.write(&right);
+    }
+
+    fn print_arrow_function(&mut self, arrow: &ArrowFunction) {
+        let parameters: Vec<Parameter> = arrow.parameters().collect();
+        let is_simple = !has_token(arrow.syntax(), SyntaxKind::OpenParenToken)
+            && parameters.len() == 1
+            && parameters[0].syntax().child_count() == 1;
+        if is_simple {
+            self.print_parameter(&parameters[0]);
+        } else {
+            self.print_signature(arrow);
+        }
+        self.write(" => ");
+        match arrow.body() {
+            Some(ArrowFunctionBody::FunctionBlock(body)) => self.print_block(body.statements(), body.syntax()),
+            Some(ArrowFunctionBody::Expression(body)) => {
+                let (text, _) = self.capture(|printer| printer.print_expression(&body, Precedence::Assignment));
+                // A body starting with `{` would be a block
+                if text.starts_with('{') {
+                    let (text, _) = self.capture(|printer| printer.print_parenthesized(&body));
+                    self.write(&text);
+                } else {
+                    self.write(&text);
+                }
+            }
+            None => {}
+        }
+    }
+
+    fn print_object_literal(&mut self, object: &ObjectLiteral) {
+        let no_in = mem::replace(&mut self.no_in, false);
+        self.indent += 1;
+        let properties: Vec<String> = object
+            .properties()
+            .map(|property| {
+                self.capture(|printer| printer.print_object_literal_element(&property))
+                    .0
+            })
+            .collect();
+        self.indent -= 1;
+        self.no_in = no_in;
+        if properties.is_empty() {
+            return self.write("{}");
+        }
+        let length: usize = properties.iter().map(|property| property.len() + 2).sum();
+        if length <= 80 && properties.iter().all(|property| !property.contains('\n')) {
+            self.write("{ ");
+            self.write(&properties.join(", "));
+            return self.write(" }");
+        }
+        self.write("{");
+        self.indent += 1;
+        for (index, property) in properties.iter().enumerate() {
+            self.new_line();
+            self.write(property);
+            if index + 1 < properties.len() {
+                self.write(",");
+            }
+        }
+        self.indent -= 1;
+        self.new_line();
+        self.write("}");
+    }
+
+    fn print_object_literal_element(&mut self, element: &ObjectLiteralElement) {
+        match element {
+            ObjectLiteralElement::PropertyAssignment(property) => self.print_property_assignment(property),
+            element => {
+                self.print_member(element.syntax());
+            }
+        }
+    }
+
+    fn print_property_assignment(&mut self, property: &PropertyAssignment) {
+        self.print_property_name(property.name());
+        let initializer = property.initializer();
+        // `m() {}` is the shorthand of `m: function () {}`
+        if !has_token(property.syntax(), SyntaxKind::ColonToken) {
+            if let Some(Expression::FunctionExpression(function)) = initializer {
+                self.print_signature(&function);
+                self.print_body(function.body());
+                return;
+            }
+        }
+        self.write(": ");
+        self.print_optional_expression(initializer, Precedence::Assignment);
+    }
+}
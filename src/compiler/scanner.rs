@@ -1,10 +1,12 @@
-use super::types::{character_codes, syntax_kind, SyntaxKind};
+use super::diagnostics;
+use super::types::{character_codes, syntax_kind, DiagnosticMessage, Directive, DirectiveKind, SyntaxKind, Trivia};
 use std::collections::HashMap;
 
 lazy_static! {
     pub static ref TEXT_TO_TOKEN: HashMap<&'static str, SyntaxKind> = {
         let mut m = HashMap::new();
         m.insert("any", SyntaxKind::AnyKeyword);
+        m.insert("as", SyntaxKind::AsKeyword);
         m.insert("boolean", SyntaxKind::BooleanKeyword);
         m.insert("break", SyntaxKind::BreakKeyword);
         m.insert("case", SyntaxKind::CaseKeyword);
@@ -25,6 +27,7 @@ lazy_static! {
         m.insert("false", SyntaxKind::FalseKeyword);
         m.insert("finally", SyntaxKind::FinallyKeyword);
         m.insert("for", SyntaxKind::ForKeyword);
+        m.insert("from", SyntaxKind::FromKeyword);
         m.insert("function", SyntaxKind::FunctionKeyword);
         m.insert("get", SyntaxKind::GetKeyword);
         m.insert("if", SyntaxKind::IfKeyword);
@@ -59,9 +62,107 @@ lazy_static! {
         m.insert("while", SyntaxKind::WhileKeyword);
         m.insert("with", SyntaxKind::WithKeyword);
         m.insert("yield", SyntaxKind::YieldKeyword);
+        m.insert("{", SyntaxKind::OpenBraceToken);
+        m.insert("}", SyntaxKind::CloseBraceToken);
+        m.insert("(", SyntaxKind::OpenParenToken);
+        m.insert(")", SyntaxKind::CloseParenToken);
+        m.insert("[", SyntaxKind::OpenBracketToken);
+        m.insert("]", SyntaxKind::CloseBracketToken);
+        m.insert(".", SyntaxKind::DotToken);
+        m.insert("...", SyntaxKind::DotDotDotToken);
+        m.insert(";", SyntaxKind::SemicolonToken);
+        m.insert(",", SyntaxKind::CommaToken);
+        m.insert("<", SyntaxKind::LessThanToken);
+        m.insert(">", SyntaxKind::GreaterThanToken);
+        m.insert("<=", SyntaxKind::LessThanEqualsToken);
+        m.insert(">=", SyntaxKind::GreaterThanEqualsToken);
+        m.insert("==", SyntaxKind::EqualsEqualsToken);
+        m.insert("!=", SyntaxKind::ExclamationEqualsToken);
+        m.insert("===", SyntaxKind::EqualsEqualsEqualsToken);
+        m.insert("!==", SyntaxKind::ExclamationEqualsEqualsToken);
+        m.insert("=>", SyntaxKind::EqualsGreaterThanToken);
+        m.insert("+", SyntaxKind::PlusToken);
+        m.insert("-", SyntaxKind::MinusToken);
+        m.insert("*", SyntaxKind::AsteriskToken);
+        m.insert("/", SyntaxKind::SlashToken);
+        m.insert("%", SyntaxKind::PercentToken);
+        m.insert("++", SyntaxKind::PlusPlusToken);
+        m.insert("--", SyntaxKind::MinusMinusToken);
+        m.insert("<<", SyntaxKind::LessThanLessThanToken);
+        m.insert(">>", SyntaxKind::GreaterThanGreaterThanToken);
+        m.insert(">>>", SyntaxKind::GreaterThanGreaterThanGreaterThanToken);
+        m.insert("&", SyntaxKind::AmpersandToken);
+        m.insert("|", SyntaxKind::BarToken);
+        m.insert("^", SyntaxKind::CaretToken);
+        m.insert("!", SyntaxKind::ExclamationToken);
+        m.insert("~", SyntaxKind::TildeToken);
+        m.insert("&&", SyntaxKind::AmpersandAmpersandToken);
+        m.insert("||", SyntaxKind::BarBarToken);
+        m.insert("?", SyntaxKind::QuestionToken);
+        m.insert(":", SyntaxKind::ColonToken);
+        m.insert("=", SyntaxKind::EqualsToken);
+        m.insert("+=", SyntaxKind::PlusEqualsToken);
+        m.insert("-=", SyntaxKind::MinusEqualsToken);
+        m.insert("*=", SyntaxKind::AsteriskEqualsToken);
+        m.insert("/=", SyntaxKind::SlashEqualsToken);
+        m.insert("%=", SyntaxKind::PercentEqualsToken);
+        m.insert("<<=", SyntaxKind::LessThanLessThanEqualsToken);
+        m.insert(">>=", SyntaxKind::GreaterThanGreaterThanEqualsToken);
+        m.insert(">>>=", SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken);
+        m.insert("&=", SyntaxKind::AmpersandEqualsToken);
+        m.insert("|=", SyntaxKind::BarEqualsToken);
+        m.insert("^=", SyntaxKind::CaretEqualsToken);
         m
     };
+    pub static ref TOKEN_TO_TEXT: HashMap<SyntaxKind, &'static str> = {
+        let mut m = HashMap::new();
+        for (&text, &token) in TEXT_TO_TOKEN.iter() {
+            m.insert(token, text);
+        }
+        m
+    };
+}
+
+pub fn token_to_string(token: SyntaxKind) -> Option<&'static str> {
+    return TOKEN_TO_TEXT.get(&token).copied();
+}
+
+// Text between two positions, clamped to the text
+pub fn sub_str(text: &[u8], start_pos: usize, end_pos: usize) -> String {
+    if start_pos >= end_pos {
+        return String::new();
+    }
+    let end_pos = end_pos.min(text.len());
+    return String::from_utf8_lossy(&text[start_pos.min(end_pos)..end_pos]).into_owned();
+}
+
+// Start positions of the lines of text, CR LF ends a single line
+pub fn compute_line_starts(text: &str) -> Vec<usize> {
+    let bytes = text.as_bytes();
+    let mut result = vec![0];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        let ch = bytes[pos];
+        pos += 1;
+        if ch == character_codes::CARRIAGE_RETURN && bytes.get(pos) == Some(&character_codes::LINE_FEED) {
+            pos += 1;
+        }
+        if ch == character_codes::CARRIAGE_RETURN || ch == character_codes::LINE_FEED {
+            result.push(pos);
+        }
+    }
+    return result;
+}
+
+// One-based line and character of a position, characters are counted in bytes like positions
+pub fn get_line_and_character_of_position(line_starts: &[usize], pos: usize) -> (usize, usize) {
+    let line = match line_starts.binary_search(&pos) {
+        Ok(line) => line,
+        Err(next_line) => next_line - 1,
+    };
+    return (line + 1, pos - line_starts[line] + 1);
 }
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Scanner<'a> {
     text: &'a [u8],
@@ -72,6 +173,9 @@ pub struct Scanner<'a> {
     token: SyntaxKind,
     token_value: String,
     preceding_line_break: bool,
+    directives: Vec<Directive>,  // Triple-slash directives and pragmas found in comments
+    leading_trivia: Vec<Trivia>, // Whitespace, line breaks and comments before current token
+    errors: Vec<(usize, usize, DiagnosticMessage)>, // Start, length and message of errors not taken yet
 }
 
 impl<'a> Scanner<'a> {
@@ -85,6 +189,9 @@ impl<'a> Scanner<'a> {
             token: SyntaxKind::Unknown,
             token_value: "".to_string(),
             preceding_line_break: false,
+            directives: Vec::new(),
+            leading_trivia: Vec::new(),
+            errors: Vec::new(),
         };
     }
 
@@ -112,6 +219,23 @@ impl<'a> Scanner<'a> {
         return self.token_value.as_str();
     }
 
+    pub fn get_directives(&self) -> &[Directive] {
+        return &self.directives;
+    }
+
+    // Errors found since the last call
+    pub fn take_errors(&mut self) -> Vec<(usize, usize, DiagnosticMessage)> {
+        return std::mem::take(&mut self.errors);
+    }
+
+    fn error(&mut self, start: usize, length: usize, message: &DiagnosticMessage) {
+        self.errors.push((start, length, *message));
+    }
+
+    pub fn get_leading_trivia(&self) -> &[Trivia] {
+        return &self.leading_trivia;
+    }
+
     pub fn has_preceding_line_break(&self) -> bool {
         return self.preceding_line_break;
     }
@@ -169,11 +293,15 @@ impl<'a> Scanner<'a> {
             let mut in_escape = false;
             let mut in_character_class = false;
 
-            while let Some(&ch) = self.text.get(p) {
+            loop {
                 // Line breaks are not permissible in the middle of a RegExp.
-                if Scanner::is_line_break(ch) {
-                    return self.token;
-                }
+                let ch = match self.text.get(p) {
+                    Some(&ch) if !Scanner::is_line_break(ch) => ch,
+                    _ => {
+                        self.error(p, 0, &diagnostics::UNTERMINATED_REGULAR_EXPRESSION_LITERAL);
+                        break;
+                    }
+                };
                 if in_escape {
                     // Parsing an escape character;
                     // reset the flag and just advance to the next char.
@@ -191,7 +319,9 @@ impl<'a> Scanner<'a> {
                 }
                 p += 1;
             }
-            p += 1;
+            if self.compare_code(p, character_codes::SLASH) {
+                p += 1;
+            }
             while let Some(&current) = self.text.get(p) {
                 if !Scanner::is_identifier_part(current) {
                     break;
@@ -208,6 +338,7 @@ impl<'a> Scanner<'a> {
     pub fn scan(&mut self) -> SyntaxKind {
         self.start_pos = self.pos;
         self.preceding_line_break = false;
+        self.leading_trivia.clear();
         loop {
             self.token_pos = self.pos;
             match self.text.get(self.pos) {
@@ -219,13 +350,20 @@ impl<'a> Scanner<'a> {
                 Some(&ch) => match ch {
                     character_codes::LINE_FEED | character_codes::CARRIAGE_RETURN => {
                         self.preceding_line_break = true;
+                        if ch == character_codes::CARRIAGE_RETURN
+                            && self.compare_code(self.pos + 1, character_codes::LINE_FEED)
+                        {
+                            self.pos += 1;
+                        }
                         self.pos += 1;
+                        self.add_trivia(SyntaxKind::NewLineTrivia);
                     }
                     character_codes::TAB
                     | character_codes::VERTICAL_TAB
                     | character_codes::FORM_FEED
                     | character_codes::SPACE => {
                         self.pos += 1;
+                        self.add_trivia(SyntaxKind::WhitespaceTrivia);
                     }
                     character_codes::EXCLAMATION => {
                         if self.compare_code(self.pos + 1, character_codes::EQUALS) {
@@ -354,6 +492,8 @@ impl<'a> Scanner<'a> {
                                 }
                                 self.pos += 1;
                             }
+                            self.scan_directive(self.token_pos, self.pos);
+                            self.add_trivia(SyntaxKind::SingleLineCommentTrivia);
                             continue;
                         }
                         // Multi-line comment
@@ -374,8 +514,9 @@ impl<'a> Scanner<'a> {
                                 self.pos += 1;
                             }
                             if !comment_closed {
-                                println!("'*/' expected.")
+                                self.error(self.pos, 0, &diagnostics::ASTERISK_SLASH_EXPECTED);
                             }
+                            self.add_trivia(SyntaxKind::MultiLineCommentTrivia);
                             continue;
                         }
                         if self.compare_code(self.pos + 1, character_codes::EQUALS) {
@@ -514,7 +655,7 @@ impl<'a> Scanner<'a> {
                     }
                     character_codes::BACKSLASH => {
                         // TODO:deal Unicode
-                        println!("Invalid character.");
+                        self.error(self.pos, 1, &diagnostics::INVALID_CHARACTER);
                         self.pos += 1;
                         self.token = SyntaxKind::Unknown;
                         return self.token;
@@ -536,14 +677,23 @@ impl<'a> Scanner<'a> {
                             return self.get_identifier_token();
                         } else if Scanner::is_white_space(default) {
                             self.pos += 1;
+                            self.add_trivia(SyntaxKind::WhitespaceTrivia);
                             continue;
                         } else if Scanner::is_line_break(default) {
                             self.preceding_line_break = true;
                             self.pos += 1;
+                            self.add_trivia(SyntaxKind::NewLineTrivia);
                             continue;
                         }
-                        println!("Invalid character.");
+                        self.error(self.pos, 1, &diagnostics::INVALID_CHARACTER);
                         self.pos += 1;
+                        // Keep the rest of a multi-byte character in the same token
+                        while let Some(&current) = self.text.get(self.pos) {
+                            if current & 0xC0 != 0x80 {
+                                break;
+                            }
+                            self.pos += 1;
+                        }
                         self.token = SyntaxKind::Unknown;
                         return self.token;
                     }
@@ -564,13 +714,17 @@ impl<'a> Scanner<'a> {
         self.token = SyntaxKind::Unknown;
         self.token_value = String::new();
         self.preceding_line_break = false;
+        self.leading_trivia.clear();
+        self.errors.clear();
+        // Directives behind the new position will be collected again when rescanned
+        self.directives.retain(|directive| directive.pos < pos);
     }
 
     pub fn get_identifier_token(&mut self) -> SyntaxKind {
         let temp_len = self.token_value.len();
-        if temp_len >= 2 && temp_len <= 11 {
-            if let Some(ch) = self.token_value.bytes().nth(0) {
-                if ch >= character_codes::_A && ch <= character_codes::_Z {
+        if (2..=11).contains(&temp_len) {
+            if let Some(&ch) = self.token_value.as_bytes().first() {
+                if (character_codes::_A..=character_codes::_Z).contains(&ch) {
                     if let Some(&token) = TEXT_TO_TOKEN.get(self.token_value.as_str()) {
                         self.token = token;
                         return self.token;
@@ -595,7 +749,6 @@ impl<'a> Scanner<'a> {
         }
         let end = self.pos;
         // TODO:deal scientific notation
-        if self.compare_code(self.pos, character_codes::E) || self.compare_code(self.pos, character_codes::_E) {}
         return self.sub_str(start, end);
     }
 
@@ -607,7 +760,7 @@ impl<'a> Scanner<'a> {
             match self.text.get(self.pos) {
                 None => {
                     result = self.sub_str(start, self.pos);
-                    println!("Unexpected end of text.");
+                    self.error(self.pos, 0, &diagnostics::UNEXPECTED_END_OF_TEXT);
                     break;
                 }
                 Some(&current) => {
@@ -617,10 +770,9 @@ impl<'a> Scanner<'a> {
                         break;
                     }
                     // TODO:deal backslash
-                    if current == character_codes::BACKSLASH {}
                     if Scanner::is_line_break(current) {
                         result = self.sub_str(start, self.pos);
-                        println!("Unterminated string literal.");
+                        self.error(self.pos, 0, &diagnostics::UNTERMINATED_STRING_LITERAL);
                         break;
                     }
                     self.pos += 1;
@@ -630,26 +782,129 @@ impl<'a> Scanner<'a> {
         return result;
     }
 
+    // Recognizes the single-line comment between start_pos and end_pos as a directive:
+    //   /// <reference path="..." />  (only in the comments leading the file)
+    //   // @name value
+    fn scan_directive(&mut self, start_pos: usize, end_pos: usize) {
+        let comment = self.sub_str(start_pos + 2, end_pos);
+        if let Some(rest) = comment.strip_prefix('/') {
+            if self.start_pos != 0 {
+                return;
+            }
+            let rest = rest.trim();
+            if !rest.starts_with('<') || !rest.ends_with("/>") {
+                return;
+            }
+            let body = &rest[1..rest.len() - 2];
+            let tag_end = body.find(|c: char| c.is_whitespace()).unwrap_or(body.len());
+            let tag = &body[..tag_end];
+            let attributes = Scanner::parse_directive_attributes(&body[tag_end..]);
+            let attribute = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.clone())
+            };
+            let (kind, value) = match tag {
+                "reference" => match attribute("path") {
+                    Some(path) => (DirectiveKind::Reference, path),
+                    None => match attribute("no-default-lib") {
+                        Some(value) => (DirectiveKind::NoDefaultLib, value),
+                        None => return,
+                    },
+                },
+                "amd-dependency" => match attribute("path") {
+                    Some(path) => (DirectiveKind::AmdDependency, path),
+                    None => return,
+                },
+                "amd-module" => match attribute("name") {
+                    Some(name) => (DirectiveKind::AmdModule, name),
+                    None => return,
+                },
+                _ => return,
+            };
+            self.directives.push(Directive {
+                kind,
+                pos: start_pos,
+                end: end_pos,
+                name: tag.to_string(),
+                value,
+            });
+            return;
+        }
+        if let Some(rest) = comment.trim_start().strip_prefix('@') {
+            let name_end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '$'))
+                .unwrap_or(rest.len());
+            if name_end == 0 {
+                return;
+            }
+            let value = rest[name_end..].trim_start();
+            let value = value.strip_prefix(':').unwrap_or(value).trim();
+            self.directives.push(Directive {
+                kind: DirectiveKind::Pragma,
+                pos: start_pos,
+                end: end_pos,
+                name: rest[..name_end].to_string(),
+                value: value.to_string(),
+            });
+        }
+    }
+
+    // Parses `name="value" name='value' ...` of a triple-slash directive.
+    fn parse_directive_attributes(text: &str) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        let mut rest = text.trim_start();
+        while let Some(equals) = rest.find('=') {
+            let name = rest[..equals].trim();
+            let value = rest[equals + 1..].trim_start();
+            let quote = match value.chars().next() {
+                Some(quote) if quote == '"' || quote == '\'' => quote,
+                _ => break,
+            };
+            let value = &value[1..];
+            let close = match value.find(quote) {
+                Some(close) => close,
+                None => break,
+            };
+            result.push((name.to_string(), value[..close].to_string()));
+            rest = value[close + 1..].trim_start();
+        }
+        return result;
+    }
+
+    // Adds the text between token_pos and pos to the leading trivia, runs of whitespace are merged
+    fn add_trivia(&mut self, kind: SyntaxKind) {
+        let text = self.sub_str(self.token_pos, self.pos);
+        if let Some(last) = self.leading_trivia.last_mut() {
+            if kind == SyntaxKind::WhitespaceTrivia && last.kind == kind {
+                last.text.push_str(&text);
+                return;
+            }
+        }
+        self.leading_trivia.push(Trivia { kind, text });
+    }
+
     fn is_identifier_start(ch: u8) -> bool {
         // TODO: || ch > character_codes::MAX_ASCII_CHARACTER && isUnicodeIdentifierStart()
-        return ch >= character_codes::A && ch <= character_codes::Z
-            || ch >= character_codes::_A && ch <= character_codes::_Z
+        return (character_codes::A..=character_codes::Z).contains(&ch)
+            || (character_codes::_A..=character_codes::_Z).contains(&ch)
             || ch == character_codes::DOLLAR
             || ch == character_codes::UNDERLINE;
     }
 
     fn is_identifier_part(ch: u8) -> bool {
         // TODO: || ch > character_codes::MAX_ASCII_CHARACTER && isUnicodeIdentifierPart()
-        return ch >= character_codes::A && ch <= character_codes::Z
-            || ch >= character_codes::_A && ch <= character_codes::_Z
-            || ch >= character_codes::_0 && ch <= character_codes::_9
+        return (character_codes::A..=character_codes::Z).contains(&ch)
+            || (character_codes::_A..=character_codes::_Z).contains(&ch)
+            || (character_codes::_0..=character_codes::_9).contains(&ch)
             || ch == character_codes::DOLLAR
             || ch == character_codes::UNDERLINE;
     }
 
     fn is_digit(&self, pos: usize) -> bool {
         match self.text.get(pos) {
-            Some(&next) => next >= character_codes::_0 && next <= character_codes::_9,
+            Some(&next) => (character_codes::_0..=character_codes::_9).contains(&next),
             None => false,
         }
     }
@@ -657,7 +912,7 @@ impl<'a> Scanner<'a> {
     #[allow(dead_code)]
     fn is_octal_digit(&self, pos: usize) -> bool {
         match self.text.get(pos) {
-            Some(&next) => next >= character_codes::_0 && next <= character_codes::_7,
+            Some(&next) => (character_codes::_0..=character_codes::_7).contains(&next),
             None => false,
         }
     }
@@ -671,26 +926,14 @@ impl<'a> Scanner<'a> {
     }
 
     fn is_line_break(ch: u8) -> bool {
-        match ch {
-            character_codes::LINE_FEED | character_codes::CARRIAGE_RETURN | character_codes::NEXT_LINE => true,
-            _ => false,
-        }
+        return matches!(
+            ch,
+            character_codes::LINE_FEED | character_codes::CARRIAGE_RETURN | character_codes::NEXT_LINE
+        );
     }
 
     fn sub_str(&self, start_pos: usize, end_pos: usize) -> String {
-        if start_pos >= end_pos {
-            return String::new();
-        }
-        let mut result = String::new();
-        let mut pos = start_pos;
-        while let Some(&current) = self.text.get(pos) {
-            if pos >= end_pos {
-                break;
-            }
-            result.push(current as char);
-            pos += 1;
-        }
-        return result;
+        return sub_str(self.text, start_pos, end_pos);
     }
 
     fn compare_code(&self, pos: usize, code: u8) -> bool {
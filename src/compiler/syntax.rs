@@ -0,0 +1,344 @@
+use super::types::{syntax_kind, SyntaxKind, Trivia};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+
+// The syntax tree is lossless: every token keeps its text and the trivia in front of it, so
+// printing a tree reproduces its source byte-for-byte.
+//
+// Green nodes are immutable and only know their width, so unchanged subtrees can be shared between
+// trees. Red nodes (SyntaxNode) are created on demand over green nodes and add the absolute
+// position and the parent.
+
+#[derive(Debug, PartialEq)]
+struct GreenNodeData {
+    kind: SyntaxKind,
+    width: usize, // Width of the text including leading trivia
+    leading_trivia: Vec<Trivia>,
+    text: String,  // Text of a token
+    value: String, // Text of identifiers and value of literals
+    children: Vec<GreenNode>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GreenNode(Rc<GreenNodeData>);
+
+impl GreenNode {
+    pub fn new_token(kind: SyntaxKind, leading_trivia: Vec<Trivia>, text: String, value: String) -> Self {
+        let width = leading_trivia.iter().map(|trivia| trivia.text.len()).sum::<usize>() + text.len();
+        return GreenNode(Rc::new(GreenNodeData {
+            kind,
+            width,
+            leading_trivia,
+            text,
+            value,
+            children: Vec::new(),
+        }));
+    }
+
+    pub fn new_node(kind: SyntaxKind, children: Vec<GreenNode>) -> Self {
+        let width = children.iter().map(|child| child.width()).sum();
+        return GreenNode(Rc::new(GreenNodeData {
+            kind,
+            width,
+            leading_trivia: Vec::new(),
+            text: String::new(),
+            value: String::new(),
+            children,
+        }));
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        return self.0.kind;
+    }
+
+    pub fn width(&self) -> usize {
+        return self.0.width;
+    }
+
+    pub fn is_token(&self) -> bool {
+        return self.0.kind as usize <= syntax_kind::LAST_TOKEN as usize;
+    }
+
+    // Tokens the parser expected but did not find have no text, Missing nodes stand for other
+    // expected nodes and hold the tokens skipped in their place
+    pub fn is_missing(&self) -> bool {
+        if self.is_token() {
+            return self.0.text.is_empty() && self.0.kind != SyntaxKind::EndOfFileToken;
+        }
+        return self.0.kind == SyntaxKind::Missing;
+    }
+
+    pub fn leading_trivia(&self) -> &[Trivia] {
+        return &self.0.leading_trivia;
+    }
+
+    // Width of the trivia before the first token
+    pub fn leading_trivia_width(&self) -> usize {
+        if self.is_token() {
+            return self.0.width - self.0.text.len();
+        }
+        match self.0.children.iter().find(|child| child.width() > 0) {
+            Some(child) => child.leading_trivia_width(),
+            None => 0,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        return &self.0.text;
+    }
+
+    pub fn value(&self) -> &str {
+        return &self.0.value;
+    }
+
+    pub fn children(&self) -> &[GreenNode] {
+        return &self.0.children;
+    }
+
+    pub fn ptr_eq(&self, other: &GreenNode) -> bool {
+        return Rc::ptr_eq(&self.0, &other.0);
+    }
+
+    pub fn write_to(&self, out: &mut String) {
+        for trivia in &self.0.leading_trivia {
+            out.push_str(&trivia.text);
+        }
+        out.push_str(&self.0.text);
+        for child in &self.0.children {
+            child.write_to(out);
+        }
+    }
+}
+
+impl fmt::Display for GreenNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut out = String::with_capacity(self.width());
+        self.write_to(&mut out);
+        return f.write_str(&out);
+    }
+}
+
+struct SyntaxNodeData {
+    green: GreenNode,
+    parent: Option<SyntaxNode>,
+    index: usize, // Index in the children of parent
+    pos: usize,
+}
+
+#[derive(Clone)]
+pub struct SyntaxNode(Rc<SyntaxNodeData>);
+
+impl SyntaxNode {
+    pub fn new_root(green: GreenNode) -> Self {
+        return SyntaxNode(Rc::new(SyntaxNodeData {
+            green,
+            parent: None,
+            index: 0,
+            pos: 0,
+        }));
+    }
+
+    fn new_child(parent: &SyntaxNode, green: GreenNode, index: usize, pos: usize) -> Self {
+        return SyntaxNode(Rc::new(SyntaxNodeData {
+            green,
+            parent: Some(parent.clone()),
+            index,
+            pos,
+        }));
+    }
+
+    pub fn green(&self) -> &GreenNode {
+        return &self.0.green;
+    }
+
+    pub fn kind(&self) -> SyntaxKind {
+        return self.0.green.kind();
+    }
+
+    pub fn is_token(&self) -> bool {
+        return self.0.green.is_token();
+    }
+
+    pub fn is_missing(&self) -> bool {
+        return self.0.green.is_missing();
+    }
+
+    // Start position of the trivia before the node
+    pub fn pos(&self) -> usize {
+        return self.0.pos;
+    }
+
+    // Start position of the first token of the node
+    pub fn token_pos(&self) -> usize {
+        return self.0.pos + self.0.green.leading_trivia_width();
+    }
+
+    pub fn end(&self) -> usize {
+        return self.0.pos + self.0.green.width();
+    }
+
+    pub fn width(&self) -> usize {
+        return self.0.green.width();
+    }
+
+    pub fn leading_trivia(&self) -> &[Trivia] {
+        return self.0.green.leading_trivia();
+    }
+
+    // Text of a token without its trivia
+    pub fn token_text(&self) -> &str {
+        return self.0.green.text();
+    }
+
+    pub fn value(&self) -> &str {
+        return self.0.green.value();
+    }
+
+    // Text of the node including the trivia before it
+    pub fn full_text(&self) -> String {
+        return self.0.green.to_string();
+    }
+
+    // Text of the node without the trivia before it
+    pub fn text(&self) -> String {
+        let full_text = self.full_text();
+        return full_text[self.0.green.leading_trivia_width()..].to_string();
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        return self.0.parent.clone();
+    }
+
+    pub fn index(&self) -> usize {
+        return self.0.index;
+    }
+
+    pub fn root(&self) -> SyntaxNode {
+        let mut node = self.clone();
+        while let Some(parent) = node.parent() {
+            node = parent;
+        }
+        return node;
+    }
+
+    pub fn children(&self) -> SyntaxNodeChildren {
+        return SyntaxNodeChildren {
+            parent: self.clone(),
+            index: 0,
+            pos: self.pos(),
+        };
+    }
+
+    pub fn child_count(&self) -> usize {
+        return self.0.green.children().len();
+    }
+
+    pub fn child(&self, index: usize) -> Option<SyntaxNode> {
+        let green = self.0.green.children().get(index)?;
+        let offset: usize = self.0.green.children()[..index].iter().map(|child| child.width()).sum();
+        return Some(SyntaxNode::new_child(self, green.clone(), index, self.pos() + offset));
+    }
+
+    pub fn first_child(&self) -> Option<SyntaxNode> {
+        return self.child(0);
+    }
+
+    pub fn last_child(&self) -> Option<SyntaxNode> {
+        return self.child(self.child_count().checked_sub(1)?);
+    }
+
+    pub fn next_sibling(&self) -> Option<SyntaxNode> {
+        let parent = self.0.parent.as_ref()?;
+        let green = parent.0.green.children().get(self.0.index + 1)?;
+        return Some(SyntaxNode::new_child(
+            parent,
+            green.clone(),
+            self.0.index + 1,
+            self.end(),
+        ));
+    }
+
+    pub fn prev_sibling(&self) -> Option<SyntaxNode> {
+        let parent = self.0.parent.as_ref()?;
+        let index = self.0.index.checked_sub(1)?;
+        let green = &parent.0.green.children()[index];
+        return Some(SyntaxNode::new_child(
+            parent,
+            green.clone(),
+            index,
+            self.pos() - green.width(),
+        ));
+    }
+
+    // Parent, grandparent... up to the root
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        return std::iter::successors(self.parent(), |node| node.parent());
+    }
+
+    // The node and all nodes below it in source order
+    pub fn descendants(&self) -> impl Iterator<Item = SyntaxNode> {
+        let mut stack = vec![self.clone()];
+        return std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            let mut children: Vec<SyntaxNode> = node.children().collect();
+            children.reverse();
+            stack.extend(children);
+            return Some(node);
+        });
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = SyntaxNode> {
+        return self.descendants().filter(|node| node.is_token());
+    }
+
+    pub fn first_token(&self) -> Option<SyntaxNode> {
+        return self.tokens().next();
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &SyntaxNode) -> bool {
+        return self.0.green.ptr_eq(&other.0.green) && self.0.pos == other.0.pos;
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl Hash for SyntaxNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0.green.0).hash(state);
+        self.0.pos.hash(state);
+    }
+}
+
+impl fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{:?}@{}..{}", self.kind(), self.pos(), self.end());
+    }
+}
+
+impl fmt::Display for SyntaxNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return fmt::Display::fmt(&self.0.green, f);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxNodeChildren {
+    parent: SyntaxNode,
+    index: usize,
+    pos: usize,
+}
+
+impl Iterator for SyntaxNodeChildren {
+    type Item = SyntaxNode;
+
+    fn next(&mut self) -> Option<SyntaxNode> {
+        let green = self.parent.0.green.children().get(self.index)?;
+        let node = SyntaxNode::new_child(&self.parent, green.clone(), self.index, self.pos);
+        self.index += 1;
+        self.pos += green.width();
+        return Some(node);
+    }
+}
@@ -1,8 +1,16 @@
+use super::syntax::SyntaxNode;
+use std::collections::HashMap;
+
 // token > SyntaxKind.Identifer => token is a keyword
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum SyntaxKind {
     Unknown,
     EndOfFileToken,
+    // Trivia
+    SingleLineCommentTrivia,
+    MultiLineCommentTrivia,
+    NewLineTrivia,
+    WhitespaceTrivia,
     // Literals
     NumericLiteral,
     StringLiteral,
@@ -110,9 +118,11 @@ pub enum SyntaxKind {
     YieldKeyword,
     // TypeScript keywords
     AnyKeyword,
+    AsKeyword,
     BooleanKeyword,
     ConstructorKeyword,
     DeclareKeyword,
+    FromKeyword,
     GetKeyword,
     ModuleKeyword,
     RequireKeyword,
@@ -191,6 +201,14 @@ pub enum SyntaxKind {
     ModuleBlock,
     ImportDeclaration,
     ExportAssignment,
+    ImportFromDeclaration,
+    ImportClause,
+    NamespaceImport,
+    NamedImports,
+    ImportSpecifier,
+    ExportDeclaration,
+    NamedExports,
+    ExportSpecifier,
     // Enum
     EnumMember,
     // Top-level nodes
@@ -217,6 +235,235 @@ pub mod syntax_kind {
     pub const LAST_TYPE_NODE: SyntaxKind = SyntaxKind::ArrayType;
     pub const FIRST_PUNCTUATION: SyntaxKind = SyntaxKind::OpenBraceToken;
     pub const LAST_PUNCTUATION: SyntaxKind = SyntaxKind::CaretEqualsToken;
+    pub const FIRST_TOKEN: SyntaxKind = SyntaxKind::Unknown;
+    pub const LAST_TOKEN: SyntaxKind = SyntaxKind::StringKeyword;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DirectiveKind {
+    Reference,     // /// <reference path="..." />
+    NoDefaultLib,  // /// <reference no-default-lib="true" />
+    AmdDependency, // /// <amd-dependency path="..." />
+    AmdModule,     // /// <amd-module name="..." />
+    Pragma,        // // @name value
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    pub kind: DirectiveKind,
+    pub pos: usize,    // Start position of the comment
+    pub end: usize,    // End position of the comment
+    pub name: String,  // Tag of a triple-slash directive or name of a pragma
+    pub value: String, // Path or name of a triple-slash directive or text following a pragma
+}
+
+// Whitespace, line break or comment, always attached to the token following it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia {
+    pub kind: SyntaxKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceFile {
+    pub file_name: String,
+    pub text: String,
+    pub root: SyntaxNode,          // Node of kind SyntaxKind::SourceFile
+    pub is_declaration_file: bool, // A .d.ts file, which only holds ambient declarations
+    pub directives: Vec<Directive>,
+    pub parse_diagnostics: Vec<Diagnostic>,
+}
+
+// An edit of a text: old_length bytes at start were replaced by new_length bytes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TextChange {
+    pub start: usize,
+    pub old_length: usize,
+    pub new_length: usize,
+}
+
+impl TextChange {
+    pub fn old_end(&self) -> usize {
+        return self.start + self.old_length;
+    }
+
+    pub fn new_end(&self) -> usize {
+        return self.start + self.new_length;
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DiagnosticCategory {
+    Warning,
+    Error,
+    Message,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DiagnosticMessage {
+    pub code: u32,
+    pub category: DiagnosticCategory,
+    pub message: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub file_name: String,
+    pub start: usize,
+    pub length: usize,
+    pub message_text: String,
+    pub category: DiagnosticCategory,
+    pub code: u32,
+}
+
+impl Diagnostic {
+    // Replaces {0}, {1}... in the message with args
+    pub fn create(file_name: &str, start: usize, length: usize, message: &DiagnosticMessage, args: &[&str]) -> Self {
+        let mut message_text = message.message.to_string();
+        for (i, arg) in args.iter().enumerate() {
+            message_text = message_text.replace(&format!("{{{}}}", i), arg);
+        }
+        return Diagnostic {
+            file_name: file_name.to_string(),
+            start,
+            length,
+            message_text,
+            category: message.category,
+            code: message.code,
+        };
+    }
+}
+
+// Orders diagnostics by file and position, passes that report the same error twice leave one
+pub fn sort_and_deduplicate_diagnostics(diagnostics: &mut Vec<Diagnostic>) {
+    diagnostics.sort_by(|a, b| {
+        return (&a.file_name, a.start, a.length, a.code, &a.message_text).cmp(&(
+            &b.file_name,
+            b.start,
+            b.length,
+            b.code,
+            &b.message_text,
+        ));
+    });
+    diagnostics.dedup();
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SymbolId(u32);
+
+impl SymbolId {
+    pub fn new(index: usize) -> Self {
+        return SymbolId(index as u32);
+    }
+
+    pub fn index(self) -> usize {
+        return self.0 as usize;
+    }
+}
+
+pub type SymbolTable = HashMap<String, SymbolId>;
+
+// What a symbol declares, a symbol gets the flags of all its declarations
+pub mod symbol_flags {
+    pub const NONE: u32 = 0;
+    pub const VARIABLE: u32 = 1 << 0;
+    pub const PARAMETER: u32 = 1 << 1;
+    pub const PROPERTY: u32 = 1 << 2;
+    pub const ENUM_MEMBER: u32 = 1 << 3;
+    pub const FUNCTION: u32 = 1 << 4;
+    pub const CLASS: u32 = 1 << 5;
+    pub const INTERFACE: u32 = 1 << 6;
+    pub const ENUM: u32 = 1 << 7;
+    pub const VALUE_MODULE: u32 = 1 << 8; // A module with values in it, it exists at run time
+    pub const NAMESPACE_MODULE: u32 = 1 << 9; // A module with only types in it
+    pub const TYPE_LITERAL: u32 = 1 << 10;
+    pub const OBJECT_LITERAL: u32 = 1 << 11;
+    pub const METHOD: u32 = 1 << 12;
+    pub const CONSTRUCTOR: u32 = 1 << 13;
+    pub const GET_ACCESSOR: u32 = 1 << 14;
+    pub const SET_ACCESSOR: u32 = 1 << 15;
+    pub const CALL_SIGNATURE: u32 = 1 << 16;
+    pub const CONSTRUCT_SIGNATURE: u32 = 1 << 17;
+    pub const INDEX_SIGNATURE: u32 = 1 << 18;
+    pub const TYPE_PARAMETER: u32 = 1 << 19;
+    pub const IMPORT: u32 = 1 << 20;
+    pub const MERGED: u32 = 1 << 21; // Made by merging the symbols of several files
+
+    // Meanings, a name can have one declaration of each
+    pub const VALUE: u32 = VARIABLE
+        | PARAMETER
+        | PROPERTY
+        | ENUM_MEMBER
+        | FUNCTION
+        | CLASS
+        | ENUM
+        | VALUE_MODULE
+        | METHOD
+        | GET_ACCESSOR
+        | SET_ACCESSOR;
+    pub const TYPE: u32 = CLASS | INTERFACE | ENUM | TYPE_LITERAL | OBJECT_LITERAL | TYPE_PARAMETER;
+    pub const NAMESPACE: u32 = VALUE_MODULE | NAMESPACE_MODULE;
+
+    pub const MODULE: u32 = VALUE_MODULE | NAMESPACE_MODULE;
+    pub const ACCESSOR: u32 = GET_ACCESSOR | SET_ACCESSOR;
+    pub const SIGNATURE: u32 = CALL_SIGNATURE | CONSTRUCT_SIGNATURE | INDEX_SIGNATURE;
+
+    // Declarations a declaration cannot share its name with. Variables can be redeclared, functions
+    // and methods overloaded, and a get and a set accessor make up one property. Interfaces merge,
+    // modules reopen and merge with classes, functions and enums, and modules without values merge
+    // with anything.
+    pub const VARIABLE_EXCLUDES: u32 = VALUE & !(VARIABLE | PARAMETER);
+    pub const PARAMETER_EXCLUDES: u32 = VALUE;
+    pub const PROPERTY_EXCLUDES: u32 = VALUE;
+    pub const ENUM_MEMBER_EXCLUDES: u32 = VALUE;
+    pub const FUNCTION_EXCLUDES: u32 = VALUE & !(FUNCTION | VALUE_MODULE);
+    pub const CLASS_EXCLUDES: u32 = (VALUE | TYPE) & !VALUE_MODULE;
+    pub const INTERFACE_EXCLUDES: u32 = TYPE & !INTERFACE;
+    pub const ENUM_EXCLUDES: u32 = (VALUE | TYPE) & !(ENUM | VALUE_MODULE);
+    pub const VALUE_MODULE_EXCLUDES: u32 = VALUE & !(FUNCTION | CLASS | ENUM | VALUE_MODULE);
+    pub const NAMESPACE_MODULE_EXCLUDES: u32 = NONE;
+    pub const METHOD_EXCLUDES: u32 = VALUE & !METHOD;
+    pub const GET_ACCESSOR_EXCLUDES: u32 = VALUE & !SET_ACCESSOR;
+    pub const SET_ACCESSOR_EXCLUDES: u32 = VALUE & !GET_ACCESSOR;
+    pub const TYPE_PARAMETER_EXCLUDES: u32 = TYPE;
+    pub const IMPORT_EXCLUDES: u32 = IMPORT;
+
+    // What a symbol with flags cannot be merged with
+    pub fn get_excluded_symbol_flags(flags: u32) -> u32 {
+        let mut result = NONE;
+        for (flag, excludes) in [
+            (VARIABLE, VARIABLE_EXCLUDES),
+            (PARAMETER, PARAMETER_EXCLUDES),
+            (PROPERTY, PROPERTY_EXCLUDES),
+            (ENUM_MEMBER, ENUM_MEMBER_EXCLUDES),
+            (FUNCTION, FUNCTION_EXCLUDES),
+            (CLASS, CLASS_EXCLUDES),
+            (INTERFACE, INTERFACE_EXCLUDES),
+            (ENUM, ENUM_EXCLUDES),
+            (VALUE_MODULE, VALUE_MODULE_EXCLUDES),
+            (NAMESPACE_MODULE, NAMESPACE_MODULE_EXCLUDES),
+            (METHOD, METHOD_EXCLUDES),
+            (GET_ACCESSOR, GET_ACCESSOR_EXCLUDES),
+            (SET_ACCESSOR, SET_ACCESSOR_EXCLUDES),
+            (TYPE_PARAMETER, TYPE_PARAMETER_EXCLUDES),
+            (IMPORT, IMPORT_EXCLUDES),
+        ] {
+            if flags & flag != 0 {
+                result |= excludes;
+            }
+        }
+        return result;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    pub name: String,
+    pub flags: u32, // symbol_flags
+    pub declarations: Vec<SyntaxNode>,
+    pub parent: Option<SymbolId>, // The symbol this is a member or an export of
+    pub members: SymbolTable,     // Instance members of classes, members of interfaces and literals
+    pub exports: SymbolTable,     // Exports of modules, static members of classes, members of enums
 }
 
 pub mod character_codes {
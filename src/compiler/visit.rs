@@ -0,0 +1,197 @@
+use super::ast::*;
+use super::syntax::{GreenNode, SyntaxNode};
+use super::types::SyntaxKind;
+
+// Traversals shared by the passes over syntax trees. A Visitor has one method per kind of node,
+// each walks the children of the node unless overridden. A Fold rebuilds the tree from what its
+// methods return for each node: the node itself, a replacement, nothing or several nodes.
+
+// Calls f for every child of node, the elements of a SyntaxList are children of the node owning it
+pub fn for_each_child(node: &SyntaxNode, f: &mut impl FnMut(&SyntaxNode)) {
+    for child in node.children() {
+        if child.kind() == SyntaxKind::SyntaxList {
+            for_each_child(&child, f);
+        } else {
+            f(&child);
+        }
+    }
+}
+
+// Visits the children of node
+pub fn walk<V: Visitor + ?Sized>(visitor: &mut V, node: &SyntaxNode) {
+    for_each_child(node, &mut |child| visitor.visit_node(child));
+}
+
+// Rebuilds node from the folded children, the node is shared when no child changed
+pub fn fold_children<F: Fold + ?Sized>(folder: &mut F, node: &SyntaxNode) -> GreenNode {
+    let mut changed = false;
+    let mut children = Vec::with_capacity(node.child_count());
+    for child in node.children() {
+        let folded = if child.kind() == SyntaxKind::SyntaxList {
+            vec![fold_children(folder, &child)]
+        } else {
+            folder.fold_node(&child)
+        };
+        changed |= folded.len() != 1 || !folded[0].ptr_eq(child.green());
+        children.extend(folded);
+    }
+    if !changed {
+        return node.green().clone();
+    }
+    return GreenNode::new_node(node.kind(), children);
+}
+
+// Folds a whole tree, the root must fold into a single node
+pub fn fold<F: Fold + ?Sized>(folder: &mut F, root: &SyntaxNode) -> SyntaxNode {
+    let mut folded = folder.fold_node(root);
+    assert_eq!(folded.len(), 1, "the root of a tree must fold into a single node");
+    return SyntaxNode::new_root(folded.remove(0));
+}
+
+// Tokens that stand for a node by themselves, like identifiers and literals, are dispatched as
+// nodes. The `void` of `void x` is an operator, not a type.
+fn is_operator_token(token: &SyntaxNode) -> bool {
+    return token.index() == 0 && token.parent().map(|parent| parent.kind()) == Some(SyntaxKind::PrefixOperator);
+}
+
+macro_rules! traversals {
+    ($($node:ident => $visit:ident, $fold:ident;)+) => {
+        pub trait Visitor {
+            // Dispatches node to the method of its kind
+            fn visit_node(&mut self, node: &SyntaxNode) {
+                if node.is_token() && is_operator_token(node) {
+                    return self.visit_token(node);
+                }
+                $(if $node::can_cast(node.kind()) {
+                    return self.$visit(&$node::cast(node.clone()).unwrap());
+                })+
+                if node.kind() == SyntaxKind::Missing {
+                    return self.visit_missing(node);
+                }
+                return self.visit_token(node);
+            }
+
+            // Keywords, punctuation and operators
+            fn visit_token(&mut self, _token: &SyntaxNode) {}
+
+            // Nodes the parser expected but did not find, they hold the tokens skipped in their place
+            fn visit_missing(&mut self, node: &SyntaxNode) {
+                walk(self, node);
+            }
+
+            $(fn $visit(&mut self, node: &$node) {
+                walk(self, node.syntax());
+            })+
+        }
+
+        pub trait Fold {
+            // Dispatches node to the method of its kind
+            fn fold_node(&mut self, node: &SyntaxNode) -> Vec<GreenNode> {
+                if node.is_token() && is_operator_token(node) {
+                    return self.fold_token(node);
+                }
+                $(if $node::can_cast(node.kind()) {
+                    return self.$fold(&$node::cast(node.clone()).unwrap());
+                })+
+                if node.kind() == SyntaxKind::Missing {
+                    return self.fold_missing(node);
+                }
+                return self.fold_token(node);
+            }
+
+            fn fold_token(&mut self, token: &SyntaxNode) -> Vec<GreenNode> {
+                return vec![token.green().clone()];
+            }
+
+            fn fold_missing(&mut self, node: &SyntaxNode) -> Vec<GreenNode> {
+                return vec![fold_children(self, node)];
+            }
+
+            $(fn $fold(&mut self, node: &$node) -> Vec<GreenNode> {
+                return vec![fold_children(self, node.syntax())];
+            })+
+        }
+    };
+}
+
+traversals! {
+    Identifier => visit_identifier, fold_identifier;
+    QualifiedName => visit_qualified_name, fold_qualified_name;
+    TypeParameter => visit_type_parameter, fold_type_parameter;
+    Parameter => visit_parameter, fold_parameter;
+    Property => visit_property, fold_property;
+    Method => visit_method, fold_method;
+    Constructor => visit_constructor, fold_constructor;
+    GetAccessor => visit_get_accessor, fold_get_accessor;
+    SetAccessor => visit_set_accessor, fold_set_accessor;
+    CallSignature => visit_call_signature, fold_call_signature;
+    ConstructSignature => visit_construct_signature, fold_construct_signature;
+    IndexSignature => visit_index_signature, fold_index_signature;
+    KeywordType => visit_keyword_type, fold_keyword_type;
+    TypeReference => visit_type_reference, fold_type_reference;
+    TypeQuery => visit_type_query, fold_type_query;
+    TypeLiteral => visit_type_literal, fold_type_literal;
+    ArrayType => visit_array_type, fold_array_type;
+    LiteralExpression => visit_literal_expression, fold_literal_expression;
+    ThisExpression => visit_this_expression, fold_this_expression;
+    SuperExpression => visit_super_expression, fold_super_expression;
+    ArrayLiteral => visit_array_literal, fold_array_literal;
+    ObjectLiteral => visit_object_literal, fold_object_literal;
+    PropertyAssignment => visit_property_assignment, fold_property_assignment;
+    PropertyAccess => visit_property_access, fold_property_access;
+    IndexedAccess => visit_indexed_access, fold_indexed_access;
+    CallExpression => visit_call_expression, fold_call_expression;
+    NewExpression => visit_new_expression, fold_new_expression;
+    TypeAssertion => visit_type_assertion, fold_type_assertion;
+    ParenExpression => visit_paren_expression, fold_paren_expression;
+    FunctionExpression => visit_function_expression, fold_function_expression;
+    ArrowFunction => visit_arrow_function, fold_arrow_function;
+    PrefixOperator => visit_prefix_operator, fold_prefix_operator;
+    PostfixOperator => visit_postfix_operator, fold_postfix_operator;
+    BinaryExpression => visit_binary_expression, fold_binary_expression;
+    ConditionalExpression => visit_conditional_expression, fold_conditional_expression;
+    OmittedExpression => visit_omitted_expression, fold_omitted_expression;
+    Block => visit_block, fold_block;
+    VariableStatement => visit_variable_statement, fold_variable_statement;
+    EmptyStatement => visit_empty_statement, fold_empty_statement;
+    ExpressionStatement => visit_expression_statement, fold_expression_statement;
+    IfStatement => visit_if_statement, fold_if_statement;
+    DoStatement => visit_do_statement, fold_do_statement;
+    WhileStatement => visit_while_statement, fold_while_statement;
+    ForStatement => visit_for_statement, fold_for_statement;
+    ForInStatement => visit_for_in_statement, fold_for_in_statement;
+    ContinueStatement => visit_continue_statement, fold_continue_statement;
+    BreakStatement => visit_break_statement, fold_break_statement;
+    ReturnStatement => visit_return_statement, fold_return_statement;
+    WithStatement => visit_with_statement, fold_with_statement;
+    SwitchStatement => visit_switch_statement, fold_switch_statement;
+    CaseClause => visit_case_clause, fold_case_clause;
+    DefaultClause => visit_default_clause, fold_default_clause;
+    LabelledStatement => visit_labelled_statement, fold_labelled_statement;
+    ThrowStatement => visit_throw_statement, fold_throw_statement;
+    TryStatement => visit_try_statement, fold_try_statement;
+    TryBlock => visit_try_block, fold_try_block;
+    CatchBlock => visit_catch_block, fold_catch_block;
+    FinallyBlock => visit_finally_block, fold_finally_block;
+    DebuggerStatement => visit_debugger_statement, fold_debugger_statement;
+    VariableDeclaration => visit_variable_declaration, fold_variable_declaration;
+    FunctionDeclaration => visit_function_declaration, fold_function_declaration;
+    FunctionBlock => visit_function_block, fold_function_block;
+    ClassDeclaration => visit_class_declaration, fold_class_declaration;
+    InterfaceDeclaration => visit_interface_declaration, fold_interface_declaration;
+    EnumDeclaration => visit_enum_declaration, fold_enum_declaration;
+    ModuleDeclaration => visit_module_declaration, fold_module_declaration;
+    ModuleBlock => visit_module_block, fold_module_block;
+    ImportDeclaration => visit_import_declaration, fold_import_declaration;
+    ExportAssignment => visit_export_assignment, fold_export_assignment;
+    ImportFromDeclaration => visit_import_from_declaration, fold_import_from_declaration;
+    ImportClause => visit_import_clause, fold_import_clause;
+    NamespaceImport => visit_namespace_import, fold_namespace_import;
+    NamedImports => visit_named_imports, fold_named_imports;
+    ImportSpecifier => visit_import_specifier, fold_import_specifier;
+    ExportDeclaration => visit_export_declaration, fold_export_declaration;
+    NamedExports => visit_named_exports, fold_named_exports;
+    ExportSpecifier => visit_export_specifier, fold_export_specifier;
+    EnumMember => visit_enum_member, fold_enum_member;
+    SourceFile => visit_source_file, fold_source_file;
+}
@@ -0,0 +1,161 @@
+use crate::compiler::scanner::{compute_line_starts, get_line_and_character_of_position};
+use crate::compiler::types::{Diagnostic, DiagnosticCategory};
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// Baselines are the outputs of the compiler for a test case, checked in as references so a change in
+// any of them shows up as a diff
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BaselineResult {
+    Passed,
+    Failed(String), // Differs from the reference, holds the diff
+    Missing,        // No reference yet
+    Accepted,       // The reference was replaced with the new baseline
+}
+
+impl BaselineResult {
+    pub fn is_passed(&self) -> bool {
+        return matches!(self, BaselineResult::Passed | BaselineResult::Accepted);
+    }
+}
+
+fn category_name(category: DiagnosticCategory) -> &'static str {
+    match category {
+        DiagnosticCategory::Warning => "warning",
+        DiagnosticCategory::Error => "error",
+        DiagnosticCategory::Message => "message",
+    }
+}
+
+fn format_diagnostic(diagnostic: &Diagnostic) -> String {
+    return format!(
+        "{} TS{}: {}",
+        category_name(diagnostic.category),
+        diagnostic.code,
+        diagnostic.message_text
+    );
+}
+
+// Width in characters of text[start..end]
+fn width(text: &str, start: usize, end: usize) -> usize {
+    return text.get(start..end).map_or(end - start, |text| text.chars().count());
+}
+
+// The diagnostics of a file in the layout of TypeScript's .errors.txt baselines: the list of errors,
+// then the source with each error underlined and its message after the last line it covers. None
+// when there are no diagnostics, such cases have no .errors.txt.
+pub fn errors_baseline(file_name: &str, text: &str, diagnostics: &[Diagnostic]) -> Option<String> {
+    if diagnostics.is_empty() {
+        return None;
+    }
+    let line_starts = compute_line_starts(text);
+    let mut out = String::new();
+    for diagnostic in diagnostics {
+        let (line, character) = get_line_and_character_of_position(&line_starts, diagnostic.start);
+        let _ = writeln!(
+            out,
+            "{}({},{}): {}",
+            file_name,
+            line,
+            character,
+            format_diagnostic(diagnostic)
+        );
+    }
+    let _ = write!(out, "\n\n==== {} ({} errors) ====\n", file_name, diagnostics.len());
+    for (line, &line_start) in line_starts.iter().enumerate() {
+        let line_end = line_starts.get(line + 1).copied().unwrap_or(text.len());
+        let content = text[line_start..line_end].trim_end_matches(['\r', '\n']);
+        let content_end = line_start + content.len();
+        let _ = writeln!(out, "    {}", content);
+        for diagnostic in diagnostics {
+            let start = diagnostic.start;
+            let end = start + diagnostic.length;
+            let first_line = get_line_and_character_of_position(&line_starts, start).0 - 1;
+            let last_line = get_line_and_character_of_position(&line_starts, end.max(start + 1) - 1).0 - 1;
+            if line < first_line || line > last_line {
+                continue;
+            }
+            let from = start.clamp(line_start, content_end);
+            let to = if line == last_line {
+                end.min(content_end)
+            } else {
+                content_end
+            };
+            let _ = writeln!(
+                out,
+                "    {}{}",
+                " ".repeat(width(text, line_start, from)),
+                "~".repeat(width(text, from, to.max(from)).max(1))
+            );
+            if line == last_line {
+                let _ = writeln!(out, "!!! {}", format_diagnostic(diagnostic));
+            }
+        }
+    }
+    return Some(out);
+}
+
+// The first line where actual departs from expected, with the lines around it
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let first = match (0..expected.len().max(actual.len())).find(|&i| expected.get(i) != actual.get(i)) {
+        Some(first) => first,
+        None => return "the baselines differ only in line breaks\n".to_string(),
+    };
+    let mut out = format!("@@ line {} @@\n", first + 1);
+    let context = first.saturating_sub(2);
+    for line in &expected[context.min(expected.len())..first.min(expected.len())] {
+        let _ = writeln!(out, " {}", line);
+    }
+    for line in expected.iter().skip(first).take(3) {
+        let _ = writeln!(out, "-{}", line);
+    }
+    for line in actual.iter().skip(first).take(3) {
+        let _ = writeln!(out, "+{}", line);
+    }
+    return out;
+}
+
+// Writes actual to local and compares it with reference. None means the case produces no baseline
+// of this kind, so there should be no reference either. With accept the reference is brought up to
+// date instead.
+pub fn compare_baseline(
+    reference: &Path,
+    local: &Path,
+    actual: Option<&str>,
+    accept: bool,
+) -> io::Result<BaselineResult> {
+    let expected = match fs::read_to_string(reference) {
+        Ok(expected) => Some(expected),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+    match actual {
+        Some(actual) => fs::write(local, actual)?,
+        None if local.exists() => fs::remove_file(local)?,
+        None => {}
+    }
+    if expected.as_deref() == actual {
+        return Ok(BaselineResult::Passed);
+    }
+    if accept {
+        match actual {
+            Some(actual) => fs::write(reference, actual)?,
+            None => fs::remove_file(reference)?,
+        }
+        return Ok(BaselineResult::Accepted);
+    }
+    match (expected, actual) {
+        (None, _) => return Ok(BaselineResult::Missing),
+        (Some(_), None) => {
+            return Ok(BaselineResult::Failed(
+                "the reference exists but no baseline was produced\n".to_string(),
+            ))
+        }
+        (Some(expected), Some(actual)) => return Ok(BaselineResult::Failed(diff_lines(&expected, actual))),
+    }
+}
@@ -0,0 +1,198 @@
+use super::baseline::{compare_baseline, errors_baseline, BaselineResult};
+use crate::compiler::checker::{create_checker, CheckerOptions};
+use crate::compiler::dump::{source_file_to_json, tokens_to_json};
+use crate::compiler::grammar::check_grammar;
+use crate::compiler::parse::create_source_file;
+use crate::compiler::types::sort_and_deduplicate_diagnostics;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Runs every .ts case of a directory through the compiler and compares what each phase produces with
+// the reference baselines, the way TypeScript's own conformance suite measures the compiler.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Scanner,
+    Parser,
+    Checker,
+}
+
+// There is no JavaScript emitter yet, so no emitter phase to measure
+pub const PHASES: [Phase; 3] = [Phase::Scanner, Phase::Parser, Phase::Checker];
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Scanner => "scanner",
+            Phase::Parser => "parser",
+            Phase::Checker => "checker",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Phase::Scanner => ".tokens",
+            Phase::Parser => ".ast",
+            Phase::Checker => ".errors.txt",
+        }
+    }
+}
+
+// Options a case turns on with `// @noUnusedLocals: true` comments, as TypeScript's own cases do
+fn get_checker_options(text: &str) -> CheckerOptions {
+    let mut options = CheckerOptions::default();
+    for line in text.lines() {
+        let setting = match line.trim().strip_prefix("// @") {
+            Some(setting) => setting,
+            None => continue,
+        };
+        let (name, value) = match setting.split_once(':') {
+            Some((name, value)) => (name.trim().to_ascii_lowercase(), value.trim() == "true"),
+            None => continue,
+        };
+        match name.as_str() {
+            "nounusedlocals" => options.no_unused_locals = value,
+            "nounusedparameters" => options.no_unused_parameters = value,
+            "nounusedimports" => options.no_unused_imports = value,
+            "nounusedprivatemembers" => options.no_unused_private_members = value,
+            "nowriteonlyvariables" => options.no_write_only_variables = value,
+            _ => {}
+        }
+    }
+    return options;
+}
+
+// The baseline of each phase for a case, the checker has none when the case has no errors
+pub fn generate_baselines(file_name: &str, text: &str) -> Vec<(Phase, Option<String>)> {
+    let source_file = create_source_file(file_name, text);
+    let mut diagnostics = source_file.parse_diagnostics.clone();
+    diagnostics.extend(check_grammar(&source_file));
+    diagnostics.extend_from_slice(
+        create_checker(std::slice::from_ref(&source_file), &get_checker_options(text)).get_diagnostics(),
+    );
+    sort_and_deduplicate_diagnostics(&mut diagnostics);
+    return vec![
+        (Phase::Scanner, Some(tokens_to_json(file_name, text))),
+        (Phase::Parser, Some(source_file_to_json(&source_file))),
+        (Phase::Checker, errors_baseline(file_name, text, &diagnostics)),
+    ];
+}
+
+#[derive(Debug, Clone)]
+pub struct ConformanceOptions {
+    pub cases: PathBuf,     // Directory of .ts cases
+    pub reference: PathBuf, // Checked in baselines
+    pub local: PathBuf,     // Baselines of this run, to diff or copy over the references by hand
+    pub accept: bool,       // Replace the references that differ
+}
+
+impl Default for ConformanceOptions {
+    fn default() -> Self {
+        return ConformanceOptions {
+            cases: PathBuf::from("tests/cases/conformance"),
+            reference: PathBuf::from("tests/baselines/reference"),
+            local: PathBuf::from("tests/baselines/local"),
+            accept: false,
+        };
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub name: String,
+    pub results: Vec<(Phase, BaselineResult)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConformanceSummary {
+    pub cases: Vec<CaseResult>,
+}
+
+impl ConformanceSummary {
+    // Number of cases whose baseline for phase matches the reference
+    pub fn passed(&self, phase: Phase) -> usize {
+        return self
+            .cases
+            .iter()
+            .filter(|case| case.results.iter().any(|(p, result)| *p == phase && result.is_passed()))
+            .count();
+    }
+
+    pub fn is_success(&self) -> bool {
+        return self
+            .cases
+            .iter()
+            .all(|case| case.results.iter().all(|(_, result)| result.is_passed()));
+    }
+
+    // The differences found, then the pass rate of each phase
+    pub fn report(&self) -> String {
+        let mut out = String::new();
+        for case in &self.cases {
+            for (phase, result) in &case.results {
+                match result {
+                    BaselineResult::Failed(diff) => {
+                        let _ = write!(out, "FAIL {} ({})\n{}", case.name, phase.name(), diff);
+                    }
+                    BaselineResult::Missing => {
+                        let _ = writeln!(out, "NEW  {} ({}), no reference baseline", case.name, phase.name());
+                    }
+                    BaselineResult::Accepted => {
+                        let _ = writeln!(out, "ACCEPTED {} ({})", case.name, phase.name());
+                    }
+                    BaselineResult::Passed => {}
+                }
+            }
+        }
+        let total = self.cases.len();
+        for phase in PHASES {
+            let passed = self.passed(phase);
+            let rate = if total == 0 {
+                100.0
+            } else {
+                passed as f64 * 100.0 / total as f64
+            };
+            let _ = writeln!(out, "{:<8} {}/{} passed ({:.1}%)", phase.name(), passed, total, rate);
+        }
+        return out;
+    }
+}
+
+fn read_cases(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut cases = Vec::new();
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.is_file() && path.to_string_lossy().ends_with(".ts") {
+            cases.push(path);
+        }
+    }
+    cases.sort();
+    return Ok(cases);
+}
+
+pub fn run_conformance(options: &ConformanceOptions) -> io::Result<ConformanceSummary> {
+    fs::create_dir_all(&options.reference)?;
+    fs::create_dir_all(&options.local)?;
+    let mut summary = ConformanceSummary::default();
+    for path in read_cases(&options.cases)? {
+        // Baselines are named and refer to the case by its file name, wherever the suite is run from
+        let name = path.file_name().unwrap().to_string_lossy().to_string();
+        let text = fs::read_to_string(&path)?;
+        let stem = &name[..name.len() - ".ts".len()];
+        let mut results = Vec::new();
+        for (phase, baseline) in generate_baselines(&name, &text) {
+            let file_name = format!("{}{}", stem, phase.extension());
+            let result = compare_baseline(
+                &options.reference.join(&file_name),
+                &options.local.join(&file_name),
+                baseline.as_deref(),
+                options.accept,
+            )?;
+            results.push((phase, result));
+        }
+        summary.cases.push(CaseResult { name, results });
+    }
+    return Ok(summary);
+}
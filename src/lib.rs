@@ -1,7 +1,11 @@
+// Functions end in an explicit `return`, as in the TypeScript compiler this crate is a port of, so
+// that ported code reads like its source. The lint would flag nearly every function of the crate.
+#![allow(clippy::needless_return)]
 #[macro_use]
 extern crate lazy_static;
 
 pub mod compiler;
+pub mod harness;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,84 @@
+use typeshell::compiler::ast::{AstNode, ModuleDeclaration, ModuleName, Statement};
+
+mod common;
+use common::{error_codes, parse_file};
+
+#[test]
+fn declaration_files_by_extension() {
+    let text = std::fs::read_to_string("tests/corpus/ambient.d.ts").unwrap();
+    let file = parse_file("tests/corpus/ambient.d.ts", &text);
+    assert!(file.is_declaration_file);
+    assert_eq!(error_codes(&file), vec![]);
+    assert!(!parse_file("a.ts", "").is_declaration_file);
+    assert!(!parse_file("a.d.ts.ts", "").is_declaration_file);
+
+    // Top level declarations of a .d.ts file need `declare`, except for interfaces and imports
+    let file = parse_file("a.d.ts", "var a;\nfunction f();\ninterface I {}\nexport var b;\n");
+    assert_eq!(error_codes(&file), vec![(0, 1046), (7, 1046)]);
+    let file = parse_file("a.d.ts", "declare var a;\nf();\nif (a) { b(); }\n");
+    assert_eq!(error_codes(&file), vec![(15, 1036), (20, 1036)]);
+    let file = parse_file("a.d.ts", "declare module M { declare var a; }");
+    assert_eq!(error_codes(&file), vec![(19, 1038)]);
+}
+
+#[test]
+fn external_module_declarations() {
+    let file = parse_file(
+        "a.ts",
+        "declare module \"fs\" { export function readFile(path: string): string; }",
+    );
+    assert_eq!(error_codes(&file), vec![]);
+    let module = match file.ast().statements().next() {
+        Some(Statement::ModuleDeclaration(module)) => module,
+        statement => panic!("{:?} is a module", statement),
+    };
+    match module.name() {
+        Some(ModuleName::LiteralExpression(name)) => assert_eq!(name.value(), "fs"),
+        name => panic!("{:?} is a string literal", name),
+    }
+    let body = module.body().unwrap();
+    assert_eq!(
+        body.syntax().to_string(),
+        " { export function readFile(path: string): string; }"
+    );
+
+    let file = parse_file("a.ts", "module \"fs\" { }");
+    assert_eq!(error_codes(&file), vec![(7, 1035)]);
+    assert!(file
+        .root
+        .descendants()
+        .any(|node| ModuleDeclaration::can_cast(node.kind())));
+}
+
+#[test]
+fn bodies_and_initializers_in_ambient_contexts() {
+    let cases: &[(&str, Vec<(usize, u32)>)] = &[
+        ("declare var a = 1, b;", vec![(14, 1039)]),
+        ("declare function f(a = 1) { return a; }", vec![(21, 1039), (26, 1184)]),
+        (
+            "declare class C { constructor() { } m() { } get p() { return 1; } q = 1; }",
+            vec![(32, 1184), (40, 1184), (44, 1086), (68, 1039)],
+        ),
+        ("declare module M { a = 1; var b = 2; }", vec![(19, 1036), (32, 1039)]),
+        // The body is reported once, the statements in it are not ambient
+        ("declare module M { function f() { g(); } }", vec![(32, 1184)]),
+        ("declare enum E { A = 1, B = -2, C = A, D }", vec![(36, 1066)]),
+        ("enum E { A = 1, B = A }", vec![]),
+    ];
+    for (text, expected) in cases {
+        assert_eq!(&error_codes(&parse_file("a.ts", text)), expected, "{}", text);
+    }
+}
+
+#[test]
+fn declare_is_an_identifier() {
+    let file = parse_file(
+        "a.ts",
+        "var declare = 1;\ndeclare = 2;\ndeclare\nvar x;\ndeclare.module = 3;",
+    );
+    assert_eq!(error_codes(&file), vec![]);
+    assert_eq!(file.ast().statements().count(), 5);
+    let file = parse_file("a.ts", "export declare class C {}\ndeclare var v: number;");
+    assert_eq!(error_codes(&file), vec![]);
+    assert_eq!(file.ast().statements().count(), 2);
+}
@@ -0,0 +1,132 @@
+use typeshell::compiler::ast::{ArrowFunction, ArrowFunctionBody, AstNode, SignatureDeclaration};
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::SyntaxKind;
+
+// The initializer of `var x = text;`, which must parse without errors
+fn initializer(text: &str) -> SyntaxNode {
+    let source = format!("var x = {};", text);
+    let file = create_source_file("test.ts", &source);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    let declaration = file
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::VariableDeclaration)
+        .unwrap();
+    declaration.last_child().unwrap()
+}
+
+fn arrow(text: &str) -> ArrowFunction {
+    let node = initializer(text);
+    ArrowFunction::cast(node.clone()).unwrap_or_else(|| panic!("{} parsed as {:?}", text, node.kind()))
+}
+
+fn parameter_names(arrow: &ArrowFunction) -> Vec<String> {
+    arrow
+        .parameters()
+        .map(|parameter| parameter.name().unwrap().syntax().text())
+        .collect()
+}
+
+fn body_kind(arrow: &ArrowFunction) -> SyntaxKind {
+    match arrow.body().unwrap() {
+        ArrowFunctionBody::FunctionBlock(block) => block.syntax().kind(),
+        ArrowFunctionBody::Expression(expression) => expression.kind(),
+    }
+}
+
+#[test]
+fn parenthesized_parameters() {
+    let function = arrow("(a, b) => a + b");
+    assert_eq!(parameter_names(&function), vec!["a", "b"]);
+    assert_eq!(body_kind(&function), SyntaxKind::BinaryExpression);
+
+    let function = arrow("(a: number): string => \"\" + a");
+    assert_eq!(parameter_names(&function), vec!["a"]);
+    assert_eq!(
+        function.return_type().unwrap().syntax().kind(),
+        SyntaxKind::StringKeyword
+    );
+
+    let function = arrow("() => { return 1; }");
+    assert_eq!(parameter_names(&function), Vec::<String>::new());
+    assert_eq!(body_kind(&function), SyntaxKind::FunctionBlock);
+
+    assert_eq!(parameter_names(&arrow("(...rest) => rest")), vec!["rest"]);
+    assert_eq!(parameter_names(&arrow("(a = 1, b?) => a")), vec!["a", "b"]);
+    assert_eq!(parameter_names(&arrow("(x) => x")), vec!["x"]);
+}
+
+#[test]
+fn single_identifier_parameter() {
+    let function = arrow("x => x * 2");
+    assert_eq!(parameter_names(&function), vec!["x"]);
+    assert_eq!(body_kind(&function), SyntaxKind::BinaryExpression);
+
+    let curried = arrow("x => y => x + y");
+    assert_eq!(parameter_names(&curried), vec!["x"]);
+    assert_eq!(body_kind(&curried), SyntaxKind::ArrowFunction);
+}
+
+#[test]
+fn generic_arrow_function() {
+    let function = arrow("<T>(x: T) => x");
+    assert_eq!(function.type_parameters().count(), 1);
+    assert_eq!(parameter_names(&function), vec!["x"]);
+}
+
+#[test]
+fn parenthesized_expressions_are_not_arrow_functions() {
+    assert_eq!(initializer("(x)").kind(), SyntaxKind::ParenExpression);
+    assert_eq!(initializer("(x, y)").kind(), SyntaxKind::ParenExpression);
+    assert_eq!(initializer("(x + 1)").kind(), SyntaxKind::ParenExpression);
+    assert_eq!(initializer("(x)(y)").kind(), SyntaxKind::CallExpression);
+    assert_eq!(initializer("<T>x").kind(), SyntaxKind::TypeAssertion);
+    assert_eq!(initializer("<T>(x)").kind(), SyntaxKind::TypeAssertion);
+    assert_eq!(initializer("a ? (b) : c").kind(), SyntaxKind::ConditionalExpression);
+    assert_eq!(
+        initializer("a ? (b) : (c) => c").kind(),
+        SyntaxKind::ConditionalExpression
+    );
+}
+
+#[test]
+fn arrow_functions_as_arguments() {
+    let call = initializer("list.map(x => x + 1, (a, b) => { }, (c))");
+    let arguments: Vec<SyntaxKind> = call
+        .descendants()
+        .filter(|node| node.parent().and_then(|list| list.parent()).as_ref() == Some(&call))
+        .filter(|node| !node.is_token())
+        .map(|node| node.kind())
+        .collect();
+    assert_eq!(
+        arguments,
+        vec![
+            SyntaxKind::ArrowFunction,
+            SyntaxKind::ArrowFunction,
+            SyntaxKind::ParenExpression
+        ]
+    );
+}
+
+#[test]
+fn definite_arrow_function_without_arrow_is_an_error() {
+    let file = create_source_file("test.ts", "var f = (a: number) a;");
+    let errors: Vec<(usize, String)> = file
+        .parse_diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.start, diagnostic.message_text.clone()))
+        .collect();
+    assert_eq!(errors[0], (20, "'=>' expected.".to_string()));
+    assert!(file
+        .root
+        .descendants()
+        .any(|node| node.kind() == SyntaxKind::ArrowFunction));
+}
+
+#[test]
+fn failed_speculation_reports_nothing() {
+    // `(a, b` is parsed as parameters first, the errors of that attempt are discarded
+    let file = create_source_file("test.ts", "var x = (a, b + 1);");
+    assert_eq!(file.parse_diagnostics, Vec::new());
+}
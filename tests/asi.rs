@@ -0,0 +1,141 @@
+use typeshell::compiler::ast::{AstNode, Statement};
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{errors, find, parse, parse_without_errors};
+
+// Kind and text of the top-level statements of text, which must parse without errors
+fn statements(text: &str) -> Vec<(SyntaxKind, String)> {
+    common::statements(text)
+        .iter()
+        .map(|statement| (statement.kind(), statement.syntax().text()))
+        .collect()
+}
+
+fn expression_kind(statement: &Statement) -> SyntaxKind {
+    match statement {
+        Statement::ExpressionStatement(statement) => statement.expression().unwrap().kind(),
+        _ => panic!("{:?} is not an expression statement", statement),
+    }
+}
+
+#[test]
+fn inserts_after_line_break() {
+    assert_eq!(
+        statements("var a = 1\nvar b = 2\na = b\n"),
+        vec![
+            (SyntaxKind::VariableStatement, "var a = 1".to_string()),
+            (SyntaxKind::VariableStatement, "var b = 2".to_string()),
+            (SyntaxKind::ExpressionStatement, "a = b".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn inserts_before_close_brace_and_at_end_of_file() {
+    let result = statements("function f() { return 1 }\nif (a) { b() } else { c() }\nd()");
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[2], (SyntaxKind::ExpressionStatement, "d()".to_string()));
+    assert_eq!(statements("{ a }"), vec![(SyntaxKind::Block, "{ a }".to_string())]);
+}
+
+#[test]
+fn does_not_insert_on_the_same_line() {
+    assert_eq!(errors(&parse("a = 1 b = 2")), vec![(6, "';' expected.")]);
+    assert_eq!(errors(&parse("var a = 1 var b")), vec![(10, "',' expected.")]);
+}
+
+#[test]
+fn line_starting_with_paren_continues_the_expression() {
+    let result = statements("a = b\n(c + d).print()");
+    assert_eq!(
+        result,
+        vec![(SyntaxKind::ExpressionStatement, "a = b\n(c + d).print()".to_string())]
+    );
+    let file = parse("a = b\n(c)");
+    let call = find(&file.root, SyntaxKind::CallExpression).unwrap();
+    assert_eq!(call.text(), "b\n(c)");
+}
+
+#[test]
+fn line_starting_with_bracket_continues_the_expression() {
+    let file = parse_without_errors("a = b\n[1, 2].forEach(f)");
+    let statement = file.ast().statements().next().unwrap();
+    assert_eq!(expression_kind(&statement), SyntaxKind::BinaryExpression);
+    let access = find(&file.root, SyntaxKind::IndexedAccess).unwrap();
+    assert_eq!(access.text(), "b\n[1, 2]");
+}
+
+#[test]
+fn line_starting_with_binary_operator_continues_the_expression() {
+    assert_eq!(statements("a = b\n+ c\n- d").len(), 1);
+    assert_eq!(statements("a = b\n? c\n: d").len(), 1);
+}
+
+#[test]
+fn return_is_restricted() {
+    let file = parse_without_errors("function f() {\n    return\n    a + b\n}");
+    let statement = find(&file.root, SyntaxKind::ReturnStatement).unwrap();
+    assert_eq!(statement.text(), "return");
+    let block = find(&file.root, SyntaxKind::FunctionBlock).unwrap();
+    let list = block
+        .children()
+        .find(|node| node.kind() == SyntaxKind::SyntaxList)
+        .unwrap();
+    assert_eq!(list.child_count(), 2);
+    assert_eq!(statements("function f() { return a }").len(), 1);
+}
+
+#[test]
+fn break_and_continue_are_restricted() {
+    let file = parse_without_errors("l: while (a) {\n    break\n    l\n    continue\n    l\n    break l\n}");
+    let texts: Vec<String> = file
+        .root
+        .descendants()
+        .filter(|node| matches!(node.kind(), SyntaxKind::BreakStatement | SyntaxKind::ContinueStatement))
+        .map(|node| node.text())
+        .collect();
+    assert_eq!(texts, vec!["break", "continue", "break l"]);
+}
+
+#[test]
+fn throw_is_restricted() {
+    assert_eq!(
+        errors(&parse("throw\nnew Error()")),
+        vec![(6, "Line break not permitted here.")]
+    );
+    assert_eq!(statements("throw new Error()\nx").len(), 2);
+}
+
+#[test]
+fn postfix_operators_are_restricted() {
+    let result = statements("a\n++b\nc\n--d\ne++");
+    let kinds: Vec<SyntaxKind> = result.iter().map(|(kind, _)| *kind).collect();
+    assert_eq!(kinds, vec![SyntaxKind::ExpressionStatement; 5]);
+    let file = parse("a\n++b\ne++");
+    let expressions: Vec<SyntaxKind> = file.ast().statements().map(|s| expression_kind(&s)).collect();
+    assert_eq!(
+        expressions,
+        vec![
+            SyntaxKind::Identifier,
+            SyntaxKind::PrefixOperator,
+            SyntaxKind::PostfixOperator
+        ]
+    );
+}
+
+#[test]
+fn members_and_declarations_without_semicolons() {
+    let text = "interface I {\n    a: string\n    b(): void\n}\nclass C {\n    x = 1\n    y: number\n    m() { }\n}\nimport fs = require(\"fs\")\nexport = C\n";
+    assert_eq!(statements(text).len(), 4);
+}
+
+#[test]
+fn for_header_semicolons_are_never_inserted() {
+    assert_eq!(errors(&parse("for (a\nb; c; d) { }"))[0], (7, "';' expected."));
+}
+
+#[test]
+fn do_while_semicolon_is_optional() {
+    assert_eq!(statements("do x++; while (x < 3) y()").len(), 2);
+}
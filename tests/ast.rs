@@ -0,0 +1,219 @@
+use typeshell::compiler::ast::*;
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::SyntaxKind;
+
+fn root(text: &str) -> SyntaxNode {
+    let file = create_source_file("test.ts", text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    file.root
+}
+
+fn first<T: AstNode>(root: &SyntaxNode) -> T {
+    root.descendants().find_map(T::cast).unwrap()
+}
+
+// Source text of a view, without the trivia before it
+fn text(node: &impl AstNode) -> String {
+    node.syntax().text().trim().to_string()
+}
+
+fn texts<T: AstNode>(nodes: impl Iterator<Item = T>) -> Vec<String> {
+    nodes.map(|node| text(&node)).collect()
+}
+
+#[test]
+fn views_cast_only_their_kinds() {
+    let root = root("x + 1;");
+    let binary = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::BinaryExpression)
+        .unwrap();
+    assert!(BinaryExpression::cast(binary.clone()).is_some());
+    assert!(ExpressionStatement::cast(binary.clone()).is_none());
+    assert!(Statement::cast(binary.clone()).is_none());
+    match Expression::cast(binary).unwrap() {
+        Expression::BinaryExpression(_) => {}
+        expression => panic!("{:?} is a binary expression", expression),
+    }
+    // Identifiers and literals are tokens
+    let identifier = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Identifier)
+        .unwrap();
+    assert!(matches!(
+        Expression::cast(identifier.clone()),
+        Some(Expression::Identifier(_))
+    ));
+    assert!(EntityName::can_cast(SyntaxKind::Identifier));
+    assert!(!EntityName::can_cast(SyntaxKind::PropertyAccess));
+    assert_eq!(Identifier::cast(identifier).unwrap().text(), "x");
+}
+
+#[test]
+fn expression_children() {
+    let root = root("a ? b.c[d] : new N<T>(e, f);\n-g++;\nh(i)<j>(k);");
+    let conditional: ConditionalExpression = first(&root);
+    assert_eq!(text(&conditional.condition().unwrap()), "a");
+    assert_eq!(text(&conditional.when_true().unwrap()), "b.c[d]");
+    assert_eq!(text(&conditional.when_false().unwrap()), "new N<T>(e, f)");
+
+    let indexed: IndexedAccess = first(&root);
+    assert_eq!(text(&indexed.object().unwrap()), "b.c");
+    assert_eq!(text(&indexed.index().unwrap()), "d");
+    let access: PropertyAccess = first(&root);
+    assert_eq!(text(&access.expression().unwrap()), "b");
+    assert_eq!(access.name().unwrap().text(), "c");
+
+    let new: NewExpression = first(&root);
+    assert_eq!(text(&new.expression().unwrap()), "N");
+    assert_eq!(texts(new.type_arguments()), vec!["T"]);
+    assert_eq!(texts(new.arguments()), vec!["e", "f"]);
+
+    let prefix: PrefixOperator = first(&root);
+    assert_eq!(prefix.operator(), SyntaxKind::MinusToken);
+    let postfix = match prefix.operand().unwrap() {
+        Expression::PostfixOperator(postfix) => postfix,
+        operand => panic!("{:?} is a postfix operator", operand),
+    };
+    assert_eq!(postfix.operator(), SyntaxKind::PlusPlusToken);
+    assert_eq!(text(&postfix.operand().unwrap()), "g");
+
+    // The outer call is the first in document order
+    let call: CallExpression = first(&root);
+    assert_eq!(text(&call.expression().unwrap()), "h(i)");
+    assert_eq!(texts(call.type_arguments()), vec!["j"]);
+    assert_eq!(texts(call.arguments()), vec!["k"]);
+}
+
+#[test]
+fn binary_expressions_and_literals() {
+    let root = root("x = [1, , { a: 2, 'b': 3, c() { }, get d() { return 4; } }];");
+    let binary: BinaryExpression = first(&root);
+    assert_eq!(binary.operator(), SyntaxKind::EqualsToken);
+    assert_eq!(text(&binary.left().unwrap()), "x");
+    let array = match binary.right().unwrap() {
+        Expression::ArrayLiteral(array) => array,
+        right => panic!("{:?} is an array literal", right),
+    };
+    let kinds: Vec<SyntaxKind> = array.elements().map(|element| element.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::NumericLiteral,
+            SyntaxKind::OmittedExpression,
+            SyntaxKind::ObjectLiteral
+        ]
+    );
+    let object: ObjectLiteral = first(&root);
+    let properties: Vec<(String, SyntaxKind)> = object
+        .properties()
+        .map(|property| match property {
+            ObjectLiteralElement::PropertyAssignment(property) => {
+                (text(&property.name().unwrap()), property.initializer().unwrap().kind())
+            }
+            property => (text(&property), property.kind()),
+        })
+        .collect();
+    assert_eq!(
+        properties,
+        vec![
+            ("a".to_string(), SyntaxKind::NumericLiteral),
+            ("'b'".to_string(), SyntaxKind::NumericLiteral),
+            ("c".to_string(), SyntaxKind::FunctionExpression),
+            ("get d() { return 4; }".to_string(), SyntaxKind::GetAccessor),
+        ]
+    );
+}
+
+#[test]
+fn statement_children() {
+    let root = root("for (var i = 0, j; i < n; i++) x;\nfor (;;) ;\nfor (k in o) ;\nif (a) b; else c;\nif (d) e;");
+    let statements: Vec<Statement> = root
+        .descendants()
+        .find_map(SourceFile::cast)
+        .unwrap()
+        .statements()
+        .collect();
+    let full = match &statements[0] {
+        Statement::ForStatement(statement) => statement.clone(),
+        statement => panic!("{:?} is a for statement", statement),
+    };
+    assert_eq!(texts(full.declarations()), vec!["i = 0", "j"]);
+    assert!(full.initializer().is_none());
+    assert_eq!(text(&full.condition().unwrap()), "i < n");
+    assert_eq!(text(&full.incrementor().unwrap()), "i++");
+    assert_eq!(text(&full.statement().unwrap()), "x;");
+
+    let empty = match &statements[1] {
+        Statement::ForStatement(statement) => statement.clone(),
+        statement => panic!("{:?} is a for statement", statement),
+    };
+    assert_eq!(empty.declarations().count(), 0);
+    assert!(empty.initializer().is_none());
+    assert!(empty.condition().is_none());
+    assert!(empty.incrementor().is_none());
+    assert_eq!(empty.statement().unwrap().kind(), SyntaxKind::EmptyStatement);
+
+    let for_in: ForInStatement = first(&root);
+    assert!(for_in.declaration().is_none());
+    assert_eq!(text(&for_in.variable().unwrap()), "k");
+    assert_eq!(text(&for_in.expression().unwrap()), "o");
+
+    let ifs: Vec<IfStatement> = statements
+        .iter()
+        .filter_map(|statement| IfStatement::cast(statement.syntax().clone()))
+        .collect();
+    assert_eq!(text(&ifs[0].expression().unwrap()), "a");
+    assert_eq!(text(&ifs[0].then_statement().unwrap()), "b;");
+    assert_eq!(text(&ifs[0].else_statement().unwrap()), "c;");
+    assert!(ifs[1].else_statement().is_none());
+}
+
+#[test]
+fn declaration_children() {
+    let root = root("function f<T extends U>(a, ...b?: T[]) { try { } catch (e) { } }\nvar v: M.N = 1;");
+    let function: FunctionDeclaration = first(&root);
+    assert_eq!(function.name().unwrap().text(), "f");
+    let type_parameter = function.type_parameters().next().unwrap();
+    assert_eq!(type_parameter.name().unwrap().text(), "T");
+    assert_eq!(text(&type_parameter.constraint().unwrap()), "U");
+    let parameters: Vec<Parameter> = function.parameters().collect();
+    assert_eq!(parameters.len(), 2);
+    assert!(parameters[0].dot_dot_dot_token().is_none());
+    assert!(parameters[0].type_annotation().is_none());
+    assert!(parameters[1].dot_dot_dot_token().is_some());
+    assert!(parameters[1].question_token().is_some());
+    assert_eq!(text(&parameters[1].type_annotation().unwrap()), "T[]");
+    assert!(function.return_type().is_none());
+    assert_eq!(function.body().unwrap().statements().count(), 1);
+
+    let try_statement: TryStatement = first(&root);
+    assert!(try_statement.try_block().is_some());
+    assert_eq!(try_statement.catch_block().unwrap().variable().unwrap().text(), "e");
+    assert!(try_statement.finally_block().is_none());
+
+    let declaration: VariableDeclaration = first(&root);
+    assert_eq!(declaration.name().unwrap().text(), "v");
+    let qualified: QualifiedName = first(&root);
+    assert_eq!(text(&qualified.left().unwrap()), "M");
+    assert_eq!(qualified.right().unwrap().text(), "N");
+    assert_eq!(text(&declaration.initializer().unwrap()), "1");
+}
+
+#[test]
+fn missing_children_are_none() {
+    // Recovery leaves a Missing node or a missing token where the child was expected
+    let file = create_source_file("test.ts", "var a = ;\nif () b;\nx.;\nf(;");
+    assert!(!file.parse_diagnostics.is_empty());
+    let declaration: VariableDeclaration = first(&file.root);
+    assert_eq!(declaration.name().unwrap().text(), "a");
+    assert!(declaration.initializer().is_none());
+    let statement: IfStatement = first(&file.root);
+    assert!(statement.expression().is_none());
+    assert_eq!(text(&statement.then_statement().unwrap()), "b;");
+    let access: PropertyAccess = first(&file.root);
+    assert!(access.name().unwrap().syntax().is_missing());
+    let call: CallExpression = first(&file.root);
+    assert_eq!(call.arguments().count(), 0);
+}
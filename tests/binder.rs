@@ -0,0 +1,247 @@
+use typeshell::compiler::binder::{bind_source_file, BoundFile};
+use typeshell::compiler::grammar::check_grammar;
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::{sort_and_deduplicate_diagnostics, symbol_flags, SourceFile, SymbolTable, SyntaxKind};
+
+fn bind(text: &str) -> (SourceFile, BoundFile) {
+    let file = create_source_file("test.ts", text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    let bound = bind_source_file(&file);
+    (file, bound)
+}
+
+// Names in table with the flags of their symbols, sorted by name
+fn names(bound: &BoundFile, table: &SymbolTable) -> Vec<(String, u32)> {
+    let mut names: Vec<(String, u32)> = table
+        .iter()
+        .map(|(name, &id)| (name.clone(), bound.symbol(id).flags))
+        .collect();
+    names.sort();
+    names
+}
+
+fn first(file: &SourceFile, kind: SyntaxKind) -> SyntaxNode {
+    file.root.descendants().find(|node| node.kind() == kind).unwrap()
+}
+
+// Text and start of each duplicate identifier error
+fn duplicates(text: &str) -> Vec<String> {
+    let (_, bound) = bind(text);
+    bound
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            assert_eq!(diagnostic.code, 2300);
+            format!(
+                "{}@{}",
+                &text[diagnostic.start..diagnostic.start + diagnostic.length],
+                diagnostic.start
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn file_and_function_locals() {
+    let text = "var a;\nfunction f<T>(p: T) { var v; function g() { } try { } catch (e) { var w; } }\ninterface I { }\nenum E { }\nclass C { }";
+    let (file, bound) = bind(text);
+    assert_eq!(bound.file_symbol, None);
+    assert_eq!(
+        names(&bound, bound.get_locals(&file.root).unwrap()),
+        vec![
+            ("C".to_string(), symbol_flags::CLASS),
+            ("E".to_string(), symbol_flags::ENUM),
+            ("I".to_string(), symbol_flags::INTERFACE),
+            ("a".to_string(), symbol_flags::VARIABLE),
+            ("f".to_string(), symbol_flags::FUNCTION),
+        ]
+    );
+    let function = first(&file, SyntaxKind::FunctionDeclaration);
+    assert_eq!(
+        names(&bound, bound.get_locals(&function).unwrap()),
+        vec![
+            ("T".to_string(), symbol_flags::TYPE_PARAMETER),
+            ("g".to_string(), symbol_flags::FUNCTION),
+            ("p".to_string(), symbol_flags::PARAMETER),
+            ("v".to_string(), symbol_flags::VARIABLE),
+            ("w".to_string(), symbol_flags::VARIABLE),
+        ]
+    );
+    let catch = first(&file, SyntaxKind::CatchBlock);
+    assert_eq!(
+        names(&bound, bound.get_locals(&catch).unwrap()),
+        vec![("e".to_string(), symbol_flags::VARIABLE)]
+    );
+    let symbol = bound.symbol(bound.get_symbol_of_node(&function).unwrap());
+    assert_eq!(symbol.name, "f");
+    assert_eq!(symbol.declarations, vec![function]);
+}
+
+#[test]
+fn module_exports_and_locals() {
+    let text = "module A.B { export var x; var y; export interface I { } }\nmodule T { interface J { } }\ndeclare module D { var z; import q = A; }";
+    let (file, bound) = bind(text);
+    let globals = bound.get_locals(&file.root).unwrap();
+    assert_eq!(
+        names(&bound, globals),
+        vec![
+            ("A".to_string(), symbol_flags::VALUE_MODULE),
+            ("D".to_string(), symbol_flags::VALUE_MODULE),
+            ("T".to_string(), symbol_flags::NAMESPACE_MODULE),
+        ]
+    );
+    let a = bound.symbol(globals["A"]);
+    assert_eq!(
+        names(&bound, &a.exports),
+        vec![("B".to_string(), symbol_flags::VALUE_MODULE)]
+    );
+    let b_id = a.exports["B"];
+    let b = bound.symbol(b_id);
+    assert_eq!(b.parent, Some(globals["A"]));
+    assert_eq!(
+        names(&bound, &b.exports),
+        vec![
+            ("I".to_string(), symbol_flags::INTERFACE),
+            ("x".to_string(), symbol_flags::VARIABLE),
+        ]
+    );
+    let b_node = &b.declarations[0];
+    assert_eq!(
+        names(&bound, bound.get_locals(b_node).unwrap()),
+        vec![("y".to_string(), symbol_flags::VARIABLE)]
+    );
+    // Declarations of ambient modules are exported, imports only when they say so
+    let d = bound.symbol(globals["D"]);
+    assert_eq!(
+        names(&bound, &d.exports),
+        vec![("z".to_string(), symbol_flags::VARIABLE)]
+    );
+    assert_eq!(
+        names(&bound, bound.get_locals(&d.declarations[0]).unwrap()),
+        vec![("q".to_string(), symbol_flags::IMPORT)]
+    );
+}
+
+#[test]
+fn external_module_exports() {
+    let text = "import fs = require('fs');\nimport d, { a as b } from 'm';\nexport class C { }\nvar local;";
+    let (file, bound) = bind(text);
+    let module = bound.symbol(bound.file_symbol.unwrap());
+    assert_eq!(module.name, "\"test\"");
+    assert_eq!(
+        names(&bound, &module.exports),
+        vec![("C".to_string(), symbol_flags::CLASS)]
+    );
+    assert_eq!(
+        names(&bound, bound.get_locals(&file.root).unwrap()),
+        vec![
+            ("b".to_string(), symbol_flags::IMPORT),
+            ("d".to_string(), symbol_flags::IMPORT),
+            ("fs".to_string(), symbol_flags::IMPORT),
+            ("local".to_string(), symbol_flags::VARIABLE),
+        ]
+    );
+}
+
+#[test]
+fn class_and_literal_members() {
+    let text = "class C<T> {\n    a: T;\n    static b = 1;\n    m() { }\n    get p() { return 1; }\n    set p(v) { }\n    [k: string]: any;\n    constructor(public c, d) { }\n}\nvar o: { (): void; x: number } = { y: 1, 'z': 2 };";
+    let (file, bound) = bind(text);
+    let class_node = first(&file, SyntaxKind::ClassDeclaration);
+    let class = bound.symbol(bound.get_symbol_of_node(&class_node).unwrap());
+    assert_eq!(
+        names(&bound, &class.members),
+        vec![
+            ("__constructor".to_string(), symbol_flags::CONSTRUCTOR),
+            ("__index".to_string(), symbol_flags::INDEX_SIGNATURE),
+            ("a".to_string(), symbol_flags::PROPERTY),
+            ("c".to_string(), symbol_flags::PROPERTY),
+            ("m".to_string(), symbol_flags::METHOD),
+            ("p".to_string(), symbol_flags::ACCESSOR),
+        ]
+    );
+    assert_eq!(
+        names(&bound, &class.exports),
+        vec![("b".to_string(), symbol_flags::PROPERTY)]
+    );
+    assert_eq!(
+        names(&bound, bound.get_locals(&class_node).unwrap()),
+        vec![("T".to_string(), symbol_flags::TYPE_PARAMETER)]
+    );
+    // The parameter property is a parameter of the constructor as well
+    let constructor = first(&file, SyntaxKind::Constructor);
+    assert_eq!(
+        names(&bound, bound.get_locals(&constructor).unwrap()),
+        vec![
+            ("c".to_string(), symbol_flags::PARAMETER),
+            ("d".to_string(), symbol_flags::PARAMETER),
+        ]
+    );
+    let literal = bound.symbol(
+        bound
+            .get_symbol_of_node(&first(&file, SyntaxKind::TypeLiteral))
+            .unwrap(),
+    );
+    assert_eq!(literal.name, "__type");
+    assert_eq!(
+        names(&bound, &literal.members),
+        vec![
+            ("__call".to_string(), symbol_flags::CALL_SIGNATURE),
+            ("x".to_string(), symbol_flags::PROPERTY),
+        ]
+    );
+    let object = bound.symbol(
+        bound
+            .get_symbol_of_node(&first(&file, SyntaxKind::ObjectLiteral))
+            .unwrap(),
+    );
+    assert_eq!(
+        names(&bound, &object.members),
+        vec![
+            ("y".to_string(), symbol_flags::PROPERTY),
+            ("z".to_string(), symbol_flags::PROPERTY),
+        ]
+    );
+}
+
+#[test]
+fn duplicate_identifiers_across_meanings() {
+    // A name can have a value, a type and a namespace meaning
+    assert_eq!(
+        duplicates("var a; var a; function f(); function f() { }"),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        duplicates("var I; interface I { } module I { interface J { } }"),
+        Vec::<String>::new()
+    );
+    assert_eq!(duplicates("function f(a, b) { var a; }"), Vec::<String>::new());
+    assert_eq!(
+        duplicates("class C { get x() { return 1; } set x(v) { } static x; }"),
+        Vec::<String>::new()
+    );
+
+    assert_eq!(duplicates("var x; function x() { }"), vec!["x@4", "x@16"]);
+    assert_eq!(duplicates("class C { } interface C { }"), vec!["C@6", "C@22"]);
+    assert_eq!(duplicates("function f(a, a) { }"), vec!["a@11", "a@14"]);
+    assert_eq!(duplicates("enum E { A, A }"), vec!["A@9", "A@12"]);
+    assert_eq!(duplicates("var m; module m { var v; }"), vec!["m@4", "m@14"]);
+    assert_eq!(duplicates("class C { x; x() { } }"), vec!["x@10", "x@13"]);
+    assert_eq!(duplicates("var o = { a: 1, 'a': 2 };"), vec!["a@10", "'a'@16"]);
+    assert_eq!(duplicates("interface I<T, T> { }"), vec!["T@12", "T@15"]);
+}
+
+#[test]
+fn strict_mode_duplicate_parameters_are_reported_once() {
+    // The grammar checker reports the second parameter as well, the same diagnostic is kept once
+    let (file, bound) = bind("function f(a, a) { 'use strict'; }");
+    let mut diagnostics = check_grammar(&file);
+    diagnostics.extend_from_slice(&bound.diagnostics);
+    sort_and_deduplicate_diagnostics(&mut diagnostics);
+    let errors: Vec<(u32, usize)> = diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.code, diagnostic.start))
+        .collect();
+    assert_eq!(errors, vec![(2300, 11), (2300, 14)]);
+}
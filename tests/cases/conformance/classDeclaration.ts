@@ -0,0 +1,5 @@
+class Point {
+    static origin = new Point(0, 0);
+    constructor(public x: number, private y: number) { }
+    get length(): number { return Math.sqrt(this.x * this.x + this.y * this.y); }
+}
@@ -0,0 +1,32 @@
+interface Host {
+    name: string;
+}
+interface Host {
+    version(): number;
+}
+
+function tool(args: string[]): number;
+function tool(args: string[], verbose: boolean): number;
+function tool(args: string[], verbose?: boolean) {
+    return 0;
+}
+module tool {
+    export var defaults = [];
+}
+
+module Registry {
+    export function lookup(name: string);
+    function lookup(name: string, index: number) { }
+}
+
+class Plugin { }
+class Plugin { }
+
+module Late {
+    var loaded = true;
+}
+function Late() { }
+
+function helper(): void;
+var between;
+function helper() { }
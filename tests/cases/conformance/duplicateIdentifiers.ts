@@ -0,0 +1,23 @@
+var a = 1;
+var a = 2;
+function a() { }
+class C { }
+interface C { }
+enum E { X, Y, X }
+module M {
+    export var v;
+    var w;
+}
+function f(p, q, p) {
+    var p;
+    try { } catch (e) { var e; }
+}
+class D {
+    x: number;
+    x() { }
+    static x;
+    get y() { return 1; }
+    set y(value) { }
+    constructor(public z, private x) { }
+}
+var o = { a: 1, a: 2 };
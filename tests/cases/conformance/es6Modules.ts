@@ -0,0 +1,4 @@
+import D, { a as b, c } from "./m";
+import * as ns from "./n";
+export { b as e, c };
+export * from "./o";
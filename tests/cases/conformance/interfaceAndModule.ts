@@ -0,0 +1,8 @@
+interface Shape {
+    area(): number;
+    [name: string]: any;
+}
+module Geometry.Shapes {
+    export var unit: Shape;
+}
+import G = Geometry;
@@ -0,0 +1,34 @@
+count = total(values);
+
+function total(items) {
+    var sum = 0;
+    for (var i = 0; i < items.length; i++) {
+        sum += items[i];
+    }
+    try {
+        check(sum);
+    } catch (error) {
+        report(error);
+    }
+    return sum;
+    function check(value) { }
+}
+
+var count, values = [1, 2, 3];
+
+module Shapes {
+    export module Metrics {
+        export interface Size { width: number; }
+        export var zero = 0;
+    }
+}
+import Metrics = Shapes.Metrics;
+var size: Metrics.Size = { width: Shapes.Metrics.zero };
+var area: Shapes.Metrics.Area;
+
+total(error);
+
+var options;
+with (options) {
+    verbose = true;
+}
@@ -0,0 +1,5 @@
+var a = 1, b = 2, c = 3;
+var x = a + b * c - -a;
+x = a ? b : c ? a : b;
+x += a << b >>> c;
+var y = (a, b) => a || b && !c;
@@ -0,0 +1,5 @@
+var a = ;
+function f(x: number {
+    return x;
+}
+class C { public public m() { } }
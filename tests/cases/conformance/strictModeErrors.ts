@@ -0,0 +1,8 @@
+"use strict";
+var eval = 1;
+with (a) { }
+function f(p, p) {
+    delete p;
+    return 010;
+}
+L: L: break L;
@@ -0,0 +1,28 @@
+// @noUnusedLocals: true
+// @noUnusedParameters: true
+// @noUnusedImports: true
+// @noUnusedPrivateMembers: true
+// @noWriteOnlyVariables: true
+
+module Shapes {
+    export class Point {
+        private cache;
+        private scale = 1;
+        constructor(public x: number, public y: number, private origin?: Point) { }
+        length(_precision?: number) {
+            return (this.x * this.x + this.y * this.y) * this.scale;
+        }
+    }
+    var count = 0;
+    var last;
+    export function record(point: Point, label: string) {
+        count++;
+        last = point;
+        return count;
+    }
+    function helper() { }
+}
+
+import Point = Shapes.Point;
+import record = Shapes.record;
+export var origin = new Point(0, 0);
@@ -0,0 +1,395 @@
+use typeshell::compiler::checker::{create_checker, Checker, CheckerOptions};
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::{symbol_flags, DiagnosticCategory, SourceFile, SyntaxKind};
+
+fn check(files: &[(&str, &str)]) -> (Vec<SourceFile>, Checker) {
+    check_with_options(files, &CheckerOptions::default())
+}
+
+fn check_with_options(files: &[(&str, &str)], options: &CheckerOptions) -> (Vec<SourceFile>, Checker) {
+    let files: Vec<SourceFile> = files
+        .iter()
+        .map(|(file_name, text)| create_source_file(file_name, text))
+        .collect();
+    for file in &files {
+        assert_eq!(file.parse_diagnostics, Vec::new(), "{}", file.file_name);
+    }
+    let checker = create_checker(&files, options);
+    (files, checker)
+}
+
+// Code, file and text of each error
+fn errors(files: &[(&str, &str)]) -> Vec<String> {
+    let (_, checker) = check(files);
+    checker
+        .get_diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            let text = files.iter().find(|(name, _)| *name == diagnostic.file_name).unwrap().1;
+            format!(
+                "{} {}:{}",
+                diagnostic.code,
+                diagnostic.file_name,
+                &text[diagnostic.start..diagnostic.start + diagnostic.length]
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn modules_and_interfaces_merge_across_files() {
+    let (files, checker) = check(&[
+        ("a.ts", "module A { export var x; }\ninterface I { a: number; }"),
+        (
+            "b.ts",
+            "module A { export var y; }\ninterface I { b: string; }\nmodule A.B { }",
+        ),
+        ("c.ts", "export var notGlobal;"),
+    ]);
+    assert_eq!(checker.get_diagnostics(), &[]);
+    let a = checker.symbol(checker.globals()["A"]);
+    assert_eq!(a.flags & symbol_flags::MERGED, symbol_flags::MERGED);
+    assert_eq!(a.declarations.len(), 3);
+    let mut exports: Vec<&String> = a.exports.keys().collect();
+    exports.sort();
+    assert_eq!(exports, vec!["B", "x", "y"]);
+    let mut members: Vec<&String> = checker.symbol(checker.globals()["I"]).members.keys().collect();
+    members.sort();
+    assert_eq!(members, vec!["a", "b"]);
+    assert!(!checker.globals().contains_key("notGlobal"));
+
+    // The symbol of each file leads to the merged one
+    let module = files[0]
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::ModuleDeclaration)
+        .unwrap();
+    assert_eq!(checker.get_symbol_of_node(&module), Some(checker.globals()["A"]));
+}
+
+#[test]
+fn illegal_merges_are_duplicates() {
+    assert_eq!(
+        errors(&[("a.ts", "class C { }\nmodule C { }\nfunction f() { }\nmodule f { var v; }\nenum E { A }\nmodule E { export var b; }")]),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        errors(&[("a.ts", "class C { }"), ("b.ts", "class C { }")]),
+        vec!["2300 b.ts:C"]
+    );
+    assert_eq!(
+        errors(&[
+            ("a.ts", "module M { export var x; }"),
+            ("b.ts", "module M { export function x() { } }")
+        ]),
+        vec!["2300 b.ts:x"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "interface I { }"), ("b.ts", "var I; enum I { }")]),
+        vec!["2300 b.ts:I", "2300 b.ts:I"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "interface I<T> { }\ninterface I<U> { }")]),
+        vec!["2428 a.ts:I"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "enum E { A }\nenum E { B }\nenum E { C = 2 }")]),
+        vec!["2432 a.ts:B"]
+    );
+}
+
+#[test]
+fn modules_merge_after_classes_and_functions() {
+    assert_eq!(
+        errors(&[(
+            "a.ts",
+            "module C { var v; }\nclass C { }\nmodule f { var v; }\nfunction f() { }\nmodule E { var v; }\nenum E { }"
+        )]),
+        vec!["2434 a.ts:C", "2434 a.ts:f"]
+    );
+    assert_eq!(
+        errors(&[
+            ("a.ts", "class C { }"),
+            ("b.ts", "module C { var v; }\ndeclare module C { }")
+        ]),
+        vec!["2433 b.ts:C"]
+    );
+}
+
+#[test]
+fn overloads_come_before_the_implementation() {
+    assert_eq!(
+        errors(&[("a.ts", "function f(): void;\nfunction f(x) { }\ndeclare function g();\ndeclare function g(x);\ninterface I { m(); m(x); }")]),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        errors(&[("a.ts", "function f(): void;\nvar x;\nfunction f(x) { }\nfunction g();")]),
+        vec!["2391 a.ts:f", "2391 a.ts:g"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "function f(): void;\nfunction g() { }")]),
+        vec!["2389 a.ts:g"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "function f() { }\nfunction f() { }")]),
+        vec!["2393 a.ts:f", "2393 a.ts:f"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "class C {\n    constructor();\n    m();\n    static m() { }\n}")]),
+        vec!["2390 a.ts:constructor();", "2388 a.ts:m"]
+    );
+}
+
+#[test]
+fn overloads_agree_on_export_and_declare() {
+    assert_eq!(
+        errors(&[("a.ts", "module M {\n    export function f();\n    function f() { }\n}")]),
+        vec!["2383 a.ts:f"]
+    );
+    // An overload list disagreeing on `export` is not also reported as a merged declaration
+    let (files, checker) = check(&[("a.ts", "module M {\n    function f();\n    export function f() { }\n}")]);
+    assert_eq!(checker.get_diagnostics().len(), 1);
+    assert_eq!(checker.get_diagnostics()[0].code, 2383);
+    assert_eq!(checker.get_diagnostics()[0].start, at(&files[0].text, "f();"));
+    assert_eq!(
+        errors(&[("a.ts", "export function f(): void;\nfunction f() { }")]),
+        vec!["2383 a.ts:f"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "declare function f();\nfunction f() { }")]),
+        vec!["2384 a.ts:f"]
+    );
+    assert_eq!(
+        errors(&[("a.ts", "class C {\n    private m();\n    m() { }\n}")]),
+        vec!["2385 a.ts:m"]
+    );
+    assert_eq!(
+        errors(&[(
+            "a.ts",
+            "declare module M {\n    function f();\n    export function f(x);\n}"
+        )]),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        errors(&[(
+            "a.ts",
+            "module M {\n    export interface I { }\n    interface I { }\n    export var v;\n    interface v { }\n}"
+        )]),
+        vec!["2395 a.ts:I"]
+    );
+    assert_eq!(
+        errors(&[(
+            "a.ts",
+            "module M {\n    export function f() { }\n    module f { export var x; }\n}"
+        )]),
+        vec!["2395 a.ts:f"]
+    );
+}
+
+// The identifier tokens with text, in source order
+fn identifiers(file: &SourceFile, text: &str) -> Vec<SyntaxNode> {
+    file.root
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::Identifier && node.token_text() == text)
+        .collect()
+}
+
+fn at(text: &str, pattern: &str) -> usize {
+    text.find(pattern).unwrap()
+}
+
+// Kind and start of the first declaration of what the nth identifier with text refers to
+fn resolved(checker: &Checker, file: &SourceFile, text: &str, n: usize) -> Option<(SyntaxKind, usize)> {
+    let id = checker.resolve_name(&identifiers(file, text)[n])?;
+    let declaration = &checker.symbol(id).declarations[0];
+    Some((declaration.kind(), declaration.token_pos()))
+}
+
+#[test]
+fn names_resolve_to_hoisted_declarations() {
+    let text = "f(x);\nfunction f(p) {\n    g(p, v, arguments);\n    try { } catch (e) { e; v = e; }\n    var v;\n    function g() { }\n    e;\n}\nvar x = undefined;\nvar h = function h() { h; };";
+    let (files, checker) = check(&[("a.ts", text)]);
+    let file = &files[0];
+    assert_eq!(
+        resolved(&checker, file, "f", 0),
+        Some((SyntaxKind::FunctionDeclaration, at(text, "function f")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "x", 0),
+        Some((SyntaxKind::VariableDeclaration, at(text, "x = undefined")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "g", 0),
+        Some((SyntaxKind::FunctionDeclaration, at(text, "function g")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "p", 1),
+        Some((SyntaxKind::Parameter, at(text, "p) {")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "v", 0),
+        Some((SyntaxKind::VariableDeclaration, at(text, "v;\n    function")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "e", 1),
+        Some((SyntaxKind::CatchBlock, at(text, "catch")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "h", 2),
+        Some((SyntaxKind::FunctionExpression, at(text, "function h")))
+    );
+    let arguments = checker.resolve_name(&identifiers(file, "arguments")[0]).unwrap();
+    assert_eq!(checker.symbol(arguments).name, "arguments");
+    // Declarations are not uses, and the catch variable is only in scope in its block
+    assert_eq!(checker.resolve_name(&identifiers(file, "f")[1]), None);
+    assert_eq!(resolved(&checker, file, "e", 3), None);
+    assert_eq!(errors(&[("a.ts", text)]), vec!["2304 a.ts:e"]);
+}
+
+#[test]
+fn with_statements_block_resolution() {
+    let text = "var o;\nwith (o) {\n    y;\n    o;\n}";
+    let (files, checker) = check(&[("a.ts", text)]);
+    assert_eq!(
+        resolved(&checker, &files[0], "o", 1),
+        Some((SyntaxKind::VariableDeclaration, at(text, "o;")))
+    );
+    assert_eq!(checker.resolve_name(&identifiers(&files[0], "o")[2]), None);
+    assert_eq!(checker.resolve_name(&identifiers(&files[0], "y")[0]), None);
+    assert_eq!(errors(&[("a.ts", text)]), vec!["2410 a.ts:o"]);
+}
+
+#[test]
+fn qualified_names_resolve_through_modules() {
+    let text = "module A {\n    export module B {\n        export interface I { }\n        export var v;\n    }\n    var hidden;\n}\nvar i: A.B.I;\nA.B.v;\nimport C = A.B;\nvar k: C.I = C.v;\nvar j: A.B.J;\nvar l: A.hidden;";
+    let (files, checker) = check(&[("a.ts", text)]);
+    let file = &files[0];
+    assert_eq!(
+        resolved(&checker, file, "I", 1),
+        Some((SyntaxKind::InterfaceDeclaration, at(text, "export interface")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "v", 1),
+        Some((SyntaxKind::VariableDeclaration, at(text, "v;\n    }")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "I", 2),
+        Some((SyntaxKind::InterfaceDeclaration, at(text, "export interface")))
+    );
+    assert_eq!(
+        resolved(&checker, file, "v", 2),
+        Some((SyntaxKind::VariableDeclaration, at(text, "v;\n    }")))
+    );
+    let c = checker.resolve_name(&identifiers(file, "C")[1]).unwrap();
+    assert_eq!(checker.symbol(c).flags, symbol_flags::IMPORT);
+    let b = checker.get_import_target(c).unwrap();
+    assert_eq!(checker.symbol(b).name, "B");
+    assert_eq!(errors(&[("a.ts", text)]), vec!["2305 a.ts:J", "2305 a.ts:hidden"]);
+}
+
+#[test]
+fn external_module_imports_resolve_to_modules() {
+    let files = [
+        ("lib/m.ts", "export var x;\nexport interface T { }"),
+        ("lib/e.ts", "function f() { }\nexport = f;"),
+        ("host.d.ts", "declare module \"fs\" {\n    export function read();\n}"),
+        (
+            "lib/a.ts",
+            "import m = require('./m');\nimport e = require('./e');\nimport fs = require('fs');\nimport n = require('../missing');\nvar t: m.T = m.x;\ne();\nfs.read();",
+        ),
+    ];
+    let (sources, checker) = check(&files);
+    let a = &sources[3];
+    let m = checker.resolve_name(&identifiers(a, "m")[1]).unwrap();
+    assert_eq!(checker.symbol(checker.get_import_target(m).unwrap()).name, "\"lib/m\"");
+    assert_eq!(
+        resolved(&checker, a, "T", 0),
+        Some((SyntaxKind::InterfaceDeclaration, at(files[0].1, "export interface")))
+    );
+    assert_eq!(
+        resolved(&checker, a, "x", 0),
+        Some((SyntaxKind::VariableDeclaration, at(files[0].1, "x;")))
+    );
+    let e = checker.resolve_name(&identifiers(a, "e")[1]).unwrap();
+    let f = checker.symbol(checker.get_import_target(e).unwrap());
+    assert_eq!(
+        (f.name.as_str(), f.declarations[0].kind()),
+        ("f", SyntaxKind::FunctionDeclaration)
+    );
+    assert_eq!(
+        resolved(&checker, a, "read", 0),
+        Some((SyntaxKind::FunctionDeclaration, at(files[2].1, "export function")))
+    );
+    assert_eq!(errors(&files), vec!["2307 lib/a.ts:'../missing'"]);
+}
+
+// Code and text of each warning about the declarations of an external module
+fn unused(text: &str, options: &CheckerOptions) -> Vec<String> {
+    let (_, checker) = check_with_options(&[("test.ts", text)], options);
+    checker
+        .get_diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.category == DiagnosticCategory::Warning)
+        .map(|diagnostic| {
+            format!(
+                "{} {}",
+                diagnostic.code,
+                &text[diagnostic.start..diagnostic.start + diagnostic.length]
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn unused_declarations_are_warnings_when_asked_for() {
+    let text = "export function f(a, _b, c) {\n    var used = c, unused, written;\n    written = used;\n    function g() { }\n    return a;\n}";
+    assert_eq!(unused(text, &CheckerOptions::default()), Vec::<String>::new());
+    let options = CheckerOptions {
+        no_unused_locals: true,
+        ..CheckerOptions::default()
+    };
+    assert_eq!(unused(text, &options), vec!["6133 unused", "6133 g"]);
+    let options = CheckerOptions {
+        no_unused_parameters: true,
+        no_write_only_variables: true,
+        ..CheckerOptions::default()
+    };
+    assert_eq!(unused(text, &options), vec!["6137 written"]);
+    assert_eq!(
+        unused(
+            "export function f(a, _b) { }\ndeclare function d(x);\nvar s: (y) => void;",
+            &options
+        ),
+        vec!["6134 a"]
+    );
+
+    // Compound assignments read the variable, a script's globals may be used by other files
+    assert_eq!(
+        unused("function f() { var n = 0; n += 1; var m; m = 1; }", &options),
+        vec!["6137 m"]
+    );
+    let options = CheckerOptions {
+        no_unused_locals: true,
+        ..CheckerOptions::default()
+    };
+    assert_eq!(unused("var global; function h() { }", &options), Vec::<String>::new());
+    // The implementation of an exported overload list is used through the export
+    assert_eq!(
+        unused("export function f(): void;\nfunction f() { }", &options),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn unused_imports_and_private_members() {
+    let options = CheckerOptions {
+        no_unused_imports: true,
+        no_unused_private_members: true,
+        ..CheckerOptions::default()
+    };
+    let text = "module M { export var x; }\nimport a = M;\nimport b = M;\nexport import c = M;\nexport var y = a.x;";
+    assert_eq!(unused(text, &options), vec!["6135 b"]);
+    let text = "export class C {\n    private a;\n    private b;\n    private static s;\n    private get p() { return this['a']; }\n    constructor(private c, private d) { }\n    m() { return C.s + this.c; }\n}";
+    assert_eq!(unused(text, &options), vec!["6136 b", "6136 p", "6136 d"]);
+}
@@ -0,0 +1,205 @@
+use typeshell::compiler::ast::{
+    AstNode, ClassDeclaration, ClassElement, InterfaceDeclaration, ModifiersOwner, SignatureDeclaration, TypeMember,
+};
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{errors, parse, parse_without_errors};
+
+fn class(text: &str) -> ClassDeclaration {
+    parse_without_errors(text)
+        .root
+        .descendants()
+        .find_map(ClassDeclaration::cast)
+        .unwrap()
+}
+
+fn modifier_texts(owner: &impl ModifiersOwner) -> Vec<String> {
+    owner
+        .modifiers()
+        .iter()
+        .map(|modifier| modifier.token_text().to_string())
+        .collect()
+}
+
+#[test]
+fn heritage_clauses() {
+    let declaration = class("class C<T> extends B<T> implements I, J.K<T> { }");
+    assert_eq!(declaration.base_type().unwrap().syntax().text().trim(), "B<T>");
+    let implemented: Vec<String> = declaration
+        .implemented_types()
+        .map(|reference| reference.syntax().text().trim().to_string())
+        .collect();
+    assert_eq!(implemented, vec!["I", "J.K<T>"]);
+
+    let file = parse("interface I<T> extends A, B.C<T> { }");
+    let declaration = file.root.descendants().find_map(InterfaceDeclaration::cast).unwrap();
+    assert_eq!(declaration.base_types().count(), 2);
+}
+
+#[test]
+fn class_members() {
+    let declaration = class(
+        "class C {\n\
+         public static count: number = 0;\n\
+         protected name;\n\
+         [key: string]: any;\n\
+         constructor(public a: string, private b?, protected c = 1, d) { }\n\
+         get size(): number { return 0; }\n\
+         private set size(v) { }\n\
+         static create(): C;\n\
+         static create(a?): C { return null; }\n\
+         }",
+    );
+    let members: Vec<ClassElement> = declaration.members().collect();
+    let kinds: Vec<SyntaxKind> = members.iter().map(|member| member.syntax().kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::Property,
+            SyntaxKind::Property,
+            SyntaxKind::IndexSignature,
+            SyntaxKind::Constructor,
+            SyntaxKind::GetAccessor,
+            SyntaxKind::SetAccessor,
+            SyntaxKind::Method,
+            SyntaxKind::Method,
+        ]
+    );
+    match &members[0] {
+        ClassElement::Property(property) => {
+            assert_eq!(modifier_texts(property), vec!["public", "static"]);
+            assert!(property.initializer().is_some());
+        }
+        member => panic!("{:?} is a property", member),
+    }
+    match &members[2] {
+        ClassElement::IndexSignature(signature) => {
+            assert_eq!(modifier_texts(signature), Vec::<String>::new());
+            assert_eq!(signature.parameters().count(), 1);
+        }
+        member => panic!("{:?} is an index signature", member),
+    }
+    match &members[3] {
+        ClassElement::Constructor(constructor) => {
+            let parameters: Vec<(Vec<String>, String)> = constructor
+                .parameters()
+                .map(|parameter| (modifier_texts(&parameter), parameter.name().unwrap().text().to_string()))
+                .collect();
+            assert_eq!(
+                parameters,
+                vec![
+                    (vec!["public".to_string()], "a".to_string()),
+                    (vec!["private".to_string()], "b".to_string()),
+                    (vec!["protected".to_string()], "c".to_string()),
+                    (vec![], "d".to_string()),
+                ]
+            );
+        }
+        member => panic!("{:?} is a constructor", member),
+    }
+    match &members[5] {
+        ClassElement::SetAccessor(accessor) => assert!(accessor.has_modifier(SyntaxKind::PrivateKeyword)),
+        member => panic!("{:?} is a set accessor", member),
+    }
+    // An overload is a signature without a body followed by the implementation
+    let bodies: Vec<bool> = members[6..]
+        .iter()
+        .map(|member| match member {
+            ClassElement::Method(method) => method.body().is_some(),
+            member => panic!("{:?} is a method", member),
+        })
+        .collect();
+    assert_eq!(bodies, vec![false, true]);
+}
+
+#[test]
+fn modifiers_are_identifiers_elsewhere() {
+    let declaration = class("class C { public; static() {} private\n x; constructor(public, static) {} }");
+    let names: Vec<String> = declaration
+        .members()
+        .map(|member| member.syntax().text().trim().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "public;",
+            "static() {}",
+            "private",
+            "x;",
+            "constructor(public, static) {}"
+        ]
+    );
+}
+
+#[test]
+fn interface_members() {
+    let file = parse_without_errors(
+        "interface I { (a): void; <T>(b: T): T; new (c): I; [d: number]: string; e?: any; f(): void; f(g): void; }",
+    );
+    let declaration = file.root.descendants().find_map(InterfaceDeclaration::cast).unwrap();
+    let kinds: Vec<SyntaxKind> = declaration.members().map(|member| member.syntax().kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::CallSignature,
+            SyntaxKind::CallSignature,
+            SyntaxKind::ConstructSignature,
+            SyntaxKind::IndexSignature,
+            SyntaxKind::Property,
+            SyntaxKind::Method,
+            SyntaxKind::Method,
+        ]
+    );
+    match declaration.members().nth(1) {
+        Some(TypeMember::CallSignature(signature)) => {
+            assert_eq!(signature.type_parameters().count(), 1);
+            assert_eq!(signature.return_type().unwrap().syntax().text().trim(), "T");
+        }
+        member => panic!("{:?} is a call signature", member),
+    }
+}
+
+#[test]
+fn modifier_errors() {
+    assert_eq!(
+        errors(&parse(
+            "class C { public private a; static static b; static public c; static constructor() {} }"
+        )),
+        vec![
+            (17, "Accessibility modifier already seen."),
+            (35, "'static' modifier already seen."),
+            (52, "'public' modifier must precede 'static' modifier."),
+            (62, "'static' modifier cannot appear on a constructor declaration."),
+        ]
+    );
+    assert_eq!(
+        errors(&parse("class C { constructor(static a) {} }")),
+        vec![(22, "'static' modifier cannot appear on a parameter.")]
+    );
+    assert_eq!(
+        errors(&parse("class C { private static [key: string]: any; }")),
+        vec![(10, "Modifiers cannot appear here.")]
+    );
+    let parameter_property = "A parameter property is only allowed in a constructor implementation.";
+    assert_eq!(
+        errors(&parse("function f(public x) {}")),
+        vec![(11, parameter_property)]
+    );
+    assert_eq!(
+        errors(&parse("class C { m(private y) {} }")),
+        vec![(12, parameter_property)]
+    );
+    assert_eq!(
+        errors(&parse("declare class D { constructor(public x); }")),
+        vec![(30, parameter_property)]
+    );
+    assert_eq!(
+        errors(&parse("class E { constructor(protected x); constructor(public x) {} }")),
+        vec![(22, parameter_property)]
+    );
+    assert_eq!(
+        errors(&parse("var g = (private z) => z;")),
+        vec![(9, parameter_property)]
+    );
+}
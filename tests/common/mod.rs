@@ -0,0 +1,75 @@
+// Helpers shared by the integration tests, each test uses only some of them
+#![allow(dead_code)]
+
+use typeshell::compiler::ast::Statement;
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::{SourceFile, SyntaxKind};
+
+pub fn parse_file(file_name: &str, text: &str) -> SourceFile {
+    let file = create_source_file(file_name, text);
+    assert_eq!(file.root.to_string(), text, "the tree keeps skipped tokens");
+    file
+}
+
+pub fn parse(text: &str) -> SourceFile {
+    parse_file("test.ts", text)
+}
+
+pub fn parse_without_errors(text: &str) -> SourceFile {
+    let file = parse(text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    file
+}
+
+// Start and message of each parse error
+pub fn errors(file: &SourceFile) -> Vec<(usize, &str)> {
+    file.parse_diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.start, diagnostic.message_text.as_str()))
+        .collect()
+}
+
+// Start and code of each parse error
+pub fn error_codes(file: &SourceFile) -> Vec<(usize, u32)> {
+    file.parse_diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.start, diagnostic.code))
+        .collect()
+}
+
+// Top-level statements of text, which must parse without errors
+pub fn statements(text: &str) -> Vec<Statement> {
+    parse_without_errors(text).ast().statements().collect()
+}
+
+pub fn find(root: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
+    root.descendants().find(|node| node.kind() == kind)
+}
+
+// Prints nodes as `Kind(children)`, tokens as their text and missing tokens as `<Kind>`, lists are
+// flattened
+pub fn shape(node: &SyntaxNode) -> String {
+    shape_with(node, &|token| {
+        if token.is_missing() {
+            return format!("<{:?}>", token.kind());
+        }
+        token.token_text().to_string()
+    })
+}
+
+// Like `shape`, with tokens printed by `token_text`, tokens printed as "" are left out
+pub fn shape_with(node: &SyntaxNode, token_text: &dyn Fn(&SyntaxNode) -> String) -> String {
+    if node.is_token() {
+        return token_text(node);
+    }
+    let children: Vec<String> = node
+        .children()
+        .map(|child| shape_with(&child, token_text))
+        .filter(|s| !s.is_empty())
+        .collect();
+    if node.kind() == SyntaxKind::SyntaxList {
+        return children.join(" ");
+    }
+    format!("{:?}({})", node.kind(), children.join(" "))
+}
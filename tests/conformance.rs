@@ -0,0 +1,108 @@
+use std::fs;
+use std::path::PathBuf;
+use typeshell::compiler::types::{Diagnostic, DiagnosticCategory};
+use typeshell::harness::baseline::{errors_baseline, BaselineResult};
+use typeshell::harness::conformance::{run_conformance, ConformanceOptions, Phase};
+
+// A fresh suite under the temporary directory
+fn scratch_options(name: &str) -> ConformanceOptions {
+    let root = std::env::temp_dir().join(format!("typeshell-conformance-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("cases")).unwrap();
+    ConformanceOptions {
+        cases: root.join("cases"),
+        reference: root.join("reference"),
+        local: root.join("local"),
+        accept: false,
+    }
+}
+
+fn results(options: &ConformanceOptions) -> Vec<(Phase, BaselineResult)> {
+    let summary = run_conformance(options).unwrap();
+    assert_eq!(summary.cases.len(), 1);
+    summary.cases[0].results.clone()
+}
+
+#[test]
+fn checked_in_baselines_match() {
+    let summary = run_conformance(&ConformanceOptions::default()).unwrap();
+    assert!(!summary.cases.is_empty());
+    assert!(summary.is_success(), "{}", summary.report());
+}
+
+#[test]
+fn accept_mode_updates_references() {
+    let mut options = scratch_options("accept");
+    let case = options.cases.join("a.ts");
+    fs::write(&case, "var x = ;\n").unwrap();
+    assert!(results(&options)
+        .iter()
+        .all(|(_, result)| *result == BaselineResult::Missing));
+
+    options.accept = true;
+    assert!(results(&options)
+        .iter()
+        .all(|(_, result)| *result == BaselineResult::Accepted));
+    let reference: Vec<PathBuf> = ["a.tokens", "a.ast", "a.errors.txt"]
+        .iter()
+        .map(|name| options.reference.join(name))
+        .collect();
+    assert!(reference.iter().all(|path| path.exists()));
+
+    options.accept = false;
+    let summary = run_conformance(&options).unwrap();
+    assert!(summary.is_success());
+    assert!(summary.report().contains("checker  1/1 passed (100.0%)"));
+
+    // Fixing the error changes every phase and leaves no .errors.txt behind once accepted
+    fs::write(&case, "var x = 1;\n").unwrap();
+    let summary = run_conformance(&options).unwrap();
+    assert!(!summary.is_success());
+    assert!(summary.cases[0]
+        .results
+        .iter()
+        .all(|(_, result)| matches!(result, BaselineResult::Failed(_))));
+    assert!(summary.report().contains("FAIL a.ts (checker)\n"));
+    assert!(!summary.report().contains("emitter"));
+    assert!(summary.report().contains("scanner  0/1 passed (0.0%)"));
+    options.accept = true;
+    run_conformance(&options).unwrap();
+    assert!(!options.reference.join("a.errors.txt").exists());
+    assert!(!options.local.join("a.errors.txt").exists());
+    options.accept = false;
+    assert!(run_conformance(&options).unwrap().is_success());
+
+    let _ = fs::remove_dir_all(options.cases.parent().unwrap());
+}
+
+#[test]
+fn errors_baseline_underlines_each_line() {
+    let text = "var a = 1;\nvar b = {\n    c: 2 };\n";
+    let error = |start, length| Diagnostic {
+        file_name: "e.ts".to_string(),
+        start,
+        length,
+        message_text: "Message.".to_string(),
+        category: DiagnosticCategory::Error,
+        code: 1000,
+    };
+    let baseline = errors_baseline("e.ts", text, &[error(4, 1), error(19, 14)]).unwrap();
+    assert_eq!(
+        baseline,
+        "e.ts(1,5): error TS1000: Message.\n\
+         e.ts(2,9): error TS1000: Message.\n\
+         \n\
+         \n\
+         ==== e.ts (2 errors) ====\n    \
+         var a = 1;\n        \
+         ~\n\
+         !!! error TS1000: Message.\n    \
+         var b = {\n            \
+         ~\n    \
+         \x20   c: 2 };\n    \
+         ~~~~~~~~~~~\n\
+         !!! error TS1000: Message.\n    \
+         \n"
+    );
+    assert_eq!(errors_baseline("e.ts", text, &[]), None);
+}
@@ -0,0 +1,22 @@
+// Declarations of a host API
+declare var process: Process;
+declare function exit(code?: number): void;
+interface Process {
+    argv: string[];
+    cwd(): string;
+}
+declare class Shell {
+    constructor(prompt: string);
+    static current: Shell;
+    run(command: string, args?: string[]): number;
+}
+declare module Host.Fs {
+    function readFile(path: string): string;
+    var separator: string;
+}
+declare module "child_process" {
+    export function spawn(command: string): number;
+    export enum Signal { Kill = 9, Term = 15 }
+}
+export declare var version: string;
+import fs = require("fs");
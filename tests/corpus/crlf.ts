@@ -0,0 +1,5 @@
+var crlf = 1;
+if (crlf) {
+    crlf++;
+}
+// end
@@ -0,0 +1,16 @@
+/// <reference path="x.ts" />
+var a: number = 1, b = a >> 2 >= 3;
+function f<T extends Foo>(x: T, ...rest: any[]): Array<Array<number>> { return x; }
+module A.B { export class C<T> extends D implements E, F { private x: number = 1; static y; constructor(a) { super(a); } get z() { return this.x; } m?(a: string): void; } }
+interface I extends J { a: string; b?(x): void, }
+enum E { A = 1, B, }
+import fs = require("fs");
+for (var i = 0; i < 10; i++) { if (i % 2) continue; else break; }
+for (var k in o) lbl: while (true) { do { x--; } while (x) }
+switch (x) { case 1: y(); default: z(); }
+try { throw new Error("x"); } catch (e) { } finally { }
+var o = { a: 1, "b": [1,,2,], get c() { return 1; }, m() {} };
+x = a ? b : c ? d : e;
+new new X()();
+var t = <any>typeof x;
+export = A;
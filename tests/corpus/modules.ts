@@ -0,0 +1,26 @@
+/// <reference path="shell.d.ts" />
+import fs = require("fs");
+import path = require('path');
+import "./polyfills";
+import run from "./run";
+import * as os from "os";
+import { exec, spawn as launch, } from "child_process";
+import shell, { default as sh, as } from "./shell";
+
+export import Paths = Shell.Paths;
+
+export module Shell.Paths {
+    export var home = os.homedir();
+    import join = path.join;
+    export function resolve(p: string) { return join(home, p); }
+}
+
+module Internal {
+    export * from "./internal";
+}
+
+export * from "./builtins";
+export { exec as execute, launch, run as default };
+export { readFile } from "fs";
+export {};
+export = Shell;
@@ -0,0 +1,3 @@
+  
+
+/* only trivia */
@@ -0,0 +1,14 @@
+// @target: ES5
+/* leading block comment */   var a = 1 ;	// trailing comment
+
+/**
+ * doc comment
+ */
+function   f ( x ,y )   {
+    return x   +   /* inline */ y ;
+}
+
+
+var s = 'single' + "double\n" + "unicode é中文";	
+var n = 31 + 1.5 + 0.5;
+// comment at the end without a newline
\ No newline at end of file
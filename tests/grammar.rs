@@ -0,0 +1,98 @@
+use typeshell::compiler::grammar::check_grammar;
+use typeshell::compiler::parse::create_source_file;
+
+// Code, start and text of each grammar error
+fn errors(text: &str) -> Vec<(u32, usize, String)> {
+    let file = create_source_file("test.ts", text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    check_grammar(&file)
+        .iter()
+        .map(|diagnostic| {
+            let source = &text[diagnostic.start..diagnostic.start + diagnostic.length];
+            (diagnostic.code, diagnostic.start, source.to_string())
+        })
+        .collect()
+}
+
+fn codes(text: &str) -> Vec<u32> {
+    errors(text).iter().map(|(code, _, _)| *code).collect()
+}
+
+#[test]
+fn sloppy_mode_allows_strict_mode_errors() {
+    let text = "with (o) { x; }\nvar n = 017;\neval = 1;\narguments++;\nfunction f(a, a) { delete a; }";
+    assert_eq!(errors(text), Vec::new());
+}
+
+#[test]
+fn strict_mode_errors() {
+    let text = "'use strict';\nwith (o) { x; }\nvar n = 017, m = 0, k = 0.5;\neval = 1;\n--arguments;\nfunction f(a, b, a) { delete a; delete a.b; }";
+    assert_eq!(
+        errors(text),
+        vec![
+            (1101, 14, "with (o) { x; }".to_string()),
+            (1121, 38, "017".to_string()),
+            (1100, 59, "eval".to_string()),
+            (1100, 71, "arguments".to_string()),
+            (2300, 99, "a".to_string()),
+            (1102, 111, "a".to_string()),
+        ]
+    );
+    assert_eq!(
+        codes(
+            "\"use strict\";\nvar eval;\nfunction arguments(eval) {}\ntry {} catch (eval) {}\nx => { var arguments; };"
+        ),
+        vec![1100, 1100, 1100, 1100, 1100]
+    );
+}
+
+#[test]
+fn directive_prologues() {
+    // The directive has to come before any other statement and be spelled exactly
+    assert_eq!(codes("'a'; \"use strict\"; with (o) {}"), vec![1101]);
+    assert_eq!(codes("x; 'use strict'; with (o) {}"), Vec::<u32>::new());
+    assert_eq!(codes("'use  strict'; with (o) {}"), Vec::<u32>::new());
+    assert_eq!(codes("('use strict'); with (o) {}"), Vec::<u32>::new());
+    // Functions are strict from their own prologue, and everything nested in them
+    assert_eq!(
+        codes("function f() { 'use strict'; var g = function () { with (o) {} }; }\nwith (o) {}"),
+        vec![1101]
+    );
+    assert_eq!(codes("function eval() { 'use strict'; }"), vec![1100]);
+    assert_eq!(codes("class C { m(a, a) { 'use strict'; } }"), vec![2300]);
+}
+
+#[test]
+fn labels_and_jumps() {
+    let valid = "a: for (;;) { b: while (x) { continue a; break b; } }\n\
+                 c: { break c; }\n\
+                 d: e: do { continue d; } while (x);\n\
+                 switch (x) { case 1: break; }\n\
+                 for (var k in o) { if (k) continue; else break; }\n\
+                 f: { } f: { }\n\
+                 function g() { f: for (;;) { break f; } }";
+    assert_eq!(errors(valid), Vec::new());
+    assert_eq!(errors("a: { a: for (;;) { } }"), vec![(1114, 5, "a".to_string())]);
+    assert_eq!(
+        errors("break;\ncontinue;\nswitch (x) { default: continue; }\na: { continue a; }\nbreak b;"),
+        vec![
+            (1105, 0, "break;".to_string()),
+            (1104, 7, "continue;".to_string()),
+            (1104, 39, "continue;".to_string()),
+            (1115, 56, "continue a;".to_string()),
+            (1116, 70, "break b;".to_string()),
+        ]
+    );
+    assert_eq!(
+        codes("for (;;) { function f() { break; } }\na: while (x) { var g = () => { continue a; }; }\nfunction h() { break; }"),
+        vec![1107, 1107, 1105]
+    );
+}
+
+#[test]
+fn return_outside_functions() {
+    assert_eq!(
+        errors("return;\nmodule M { return 1; }\nfunction f() { return; }\nvar g = () => { return; }, h = { get x() { return 1; } };"),
+        vec![(1108, 0, "return;".to_string()), (1108, 19, "return 1;".to_string())]
+    );
+}
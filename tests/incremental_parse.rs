@@ -0,0 +1,139 @@
+use typeshell::compiler::parse::{create_source_file, update_source_file};
+use typeshell::compiler::types::{SourceFile, SyntaxKind, TextChange};
+
+// Fragments inserted by the random edits, chosen to join and split tokens and statements
+const FRAGMENTS: &[&str] = &[
+    "",
+    " ",
+    "\n",
+    ";",
+    "{",
+    "}",
+    "(",
+    ")",
+    "x",
+    "in",
+    "/",
+    "/*",
+    "*/",
+    "//",
+    "\"",
+    "'",
+    "<",
+    ">",
+    "=>",
+    ",",
+    ":",
+    "=",
+    "+",
+    ".",
+    "1",
+    "var y = 1;",
+    "function f() {",
+    "class C {",
+    "interface I {",
+    "module M {",
+    "return",
+    "else",
+    "catch",
+    "/// <reference path=\"a.ts\" />\n",
+    "// @pragma on\n",
+    "if (a) b;",
+    "try { }",
+];
+
+// Deterministic xorshift generator, the tests must not depend on a random seed
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound.max(1) as u64) as usize
+    }
+}
+
+fn apply(file: &SourceFile, start: usize, old_length: usize, new_text: &str) -> SourceFile {
+    let text = format!(
+        "{}{}{}",
+        &file.text[..start],
+        new_text,
+        &file.text[start + old_length..]
+    );
+    let change = TextChange {
+        start,
+        old_length,
+        new_length: new_text.len(),
+    };
+    let incremental = update_source_file(file, &text, change);
+    let full = create_source_file(&file.file_name, &text);
+    assert_eq!(incremental.root.green(), full.root.green(), "{:?}", text);
+    assert_eq!(incremental.parse_diagnostics, full.parse_diagnostics, "{:?}", text);
+    assert_eq!(incremental.directives, full.directives, "{:?}", text);
+    assert_eq!(incremental.text, text);
+    incremental
+}
+
+fn check_random_edits(text: &str, seed: u64, edits: usize) {
+    let mut random = Random(seed);
+    let mut file = create_source_file("test.ts", text);
+    for _ in 0..edits {
+        let mut start = random.next(file.text.len() + 1);
+        while !file.text.is_char_boundary(start) {
+            start -= 1;
+        }
+        let mut end = start + random.next(8).min(file.text.len() - start);
+        while !file.text.is_char_boundary(end) {
+            end += 1;
+        }
+        let old_length = end - start;
+        let fragment = FRAGMENTS[random.next(FRAGMENTS.len())];
+        file = apply(&file, start, old_length, fragment);
+    }
+}
+
+#[test]
+fn random_edits_match_full_parse() {
+    for entry in std::fs::read_dir("tests/corpus").unwrap() {
+        let path = entry.unwrap().path();
+        let text = std::fs::read_to_string(&path).unwrap();
+        for seed in 1..=20 {
+            check_random_edits(&text, seed * 7919, 50);
+        }
+    }
+}
+
+#[test]
+fn unchanged_statements_are_reused() {
+    let file = create_source_file("test.ts", "var a = 1;\nfunction f() {\n    return a;\n}\nvar b = 2;\n");
+    let edited = apply(&file, 31, 1, "b");
+    let statements = |file: &SourceFile| file.root.first_child().unwrap().children().collect::<Vec<_>>();
+    let (old, new) = (statements(&file), statements(&edited));
+    assert_eq!(new.len(), 3);
+    assert!(old[0].green().ptr_eq(new[0].green()));
+    assert!(!old[1].green().ptr_eq(new[1].green()));
+    assert!(old[2].green().ptr_eq(new[2].green()));
+    assert_eq!(new[2].pos(), old[2].pos());
+
+    // Nodes after the change are shifted
+    let edited = apply(&file, 0, 0, "var c;\n");
+    let new = statements(&edited);
+    assert!(old[1].green().ptr_eq(new[2].green()));
+    assert_eq!(new[2].pos(), old[1].pos() + 7);
+}
+
+#[test]
+fn edit_that_joins_statements() {
+    // The statement before the change ends depending on the token that follows it
+    let file = create_source_file("test.ts", "x = a\ni;\n");
+    let edited = apply(&file, 7, 0, "n b");
+    assert_eq!(edited.root.first_child().unwrap().child_count(), 1);
+    let file = create_source_file("test.ts", "try { }\n");
+    let edited = apply(&file, 8, 0, "finally { }");
+    assert_eq!(edited.parse_diagnostics, Vec::new());
+    assert_eq!(
+        edited.root.first_child().unwrap().first_child().unwrap().kind(),
+        SyntaxKind::TryStatement
+    );
+}
@@ -0,0 +1,115 @@
+use typeshell::compiler::dump::{program_diagnostics_to_json, source_file_to_json, tokens_to_json};
+use typeshell::compiler::parse::create_source_file;
+
+#[test]
+fn tokens() {
+    assert_eq!(
+        tokens_to_json("a.ts", "x"),
+        r#"{
+  "schemaVersion": 1,
+  "fileName": "a.ts",
+  "tokens": [
+    {"kind": "Identifier", "pos": 0, "end": 1, "line": 1, "column": 1, "endLine": 1, "endColumn": 2, "text": "x", "value": "x"},
+    {"kind": "EndOfFileToken", "pos": 1, "end": 1, "line": 1, "column": 2, "endLine": 1, "endColumn": 2, "text": ""}
+  ],
+  "diagnostics": []
+}
+"#
+    );
+}
+
+#[test]
+fn trivia_and_escapes() {
+    let json = tokens_to_json("a.ts", "// \"q\" \\\r\n\t'é'");
+    assert!(json.contains(r#"{"kind": "SingleLineCommentTrivia", "text": "// \"q\" \\"}"#));
+    assert!(json.contains(r#"{"kind": "NewLineTrivia", "text": "\r\n"}"#));
+    assert!(json.contains(r#"{"kind": "WhitespaceTrivia", "text": "\t"}"#));
+    assert!(json.contains("\"column\": 2,\n      \"endLine\": 2,\n      \"endColumn\": 6,\n      \"text\": \"'é'\""));
+}
+
+#[test]
+fn tree() {
+    assert_eq!(
+        source_file_to_json(&create_source_file("a.ts", ";")),
+        r#"{
+  "schemaVersion": 1,
+  "fileName": "a.ts",
+  "root": {
+    "kind": "SourceFile",
+    "pos": 0,
+    "end": 1,
+    "line": 1,
+    "column": 1,
+    "endLine": 1,
+    "endColumn": 2,
+    "fullPos": 0,
+    "children": [
+      {
+        "kind": "SyntaxList",
+        "pos": 0,
+        "end": 1,
+        "line": 1,
+        "column": 1,
+        "endLine": 1,
+        "endColumn": 2,
+        "fullPos": 0,
+        "children": [
+          {
+            "kind": "EmptyStatement",
+            "pos": 0,
+            "end": 1,
+            "line": 1,
+            "column": 1,
+            "endLine": 1,
+            "endColumn": 2,
+            "fullPos": 0,
+            "children": [
+              {"kind": "SemicolonToken", "pos": 0, "end": 1, "line": 1, "column": 1, "endLine": 1, "endColumn": 2, "fullPos": 0, "text": ";"}
+            ]
+          }
+        ]
+      },
+      {"kind": "EndOfFileToken", "pos": 1, "end": 1, "line": 1, "column": 2, "endLine": 1, "endColumn": 2, "fullPos": 1, "text": ""}
+    ]
+  },
+  "diagnostics": []
+}
+"#
+    );
+}
+
+#[test]
+fn missing_tokens_and_diagnostics() {
+    let json = source_file_to_json(&create_source_file("a.ts", "f(1"));
+    assert!(json.contains(
+        r#"{"kind": "CloseParenToken", "pos": 3, "end": 3, "line": 1, "column": 4, "endLine": 1, "endColumn": 4, "fullPos": 3, "missing": true, "text": ""}"#
+    ));
+    assert!(json.contains(
+        r#"{"code": 1005, "category": "error", "message": "')' expected.", "pos": 3, "end": 3, "line": 1, "column": 4, "endLine": 1, "endColumn": 4}"#
+    ));
+}
+
+#[test]
+fn program_diagnostics() {
+    let files = vec![create_source_file("a.ts", "var x"), create_source_file("b.ts", "x +")];
+    let diagnostics: Vec<_> = files
+        .iter()
+        .flat_map(|file| file.parse_diagnostics.iter().cloned())
+        .collect();
+    assert_eq!(
+        program_diagnostics_to_json(&files, &diagnostics),
+        r#"{
+  "schemaVersion": 1,
+  "files": [
+    {"fileName": "a.ts", "diagnostics": []},
+    {
+      "fileName": "b.ts",
+      "diagnostics": [
+        {"code": 1109, "category": "error", "message": "Expression expected.", "pos": 3, "end": 3, "line": 1, "column": 4, "endLine": 1, "endColumn": 4}
+      ]
+    }
+  ]
+}
+"#
+    );
+}
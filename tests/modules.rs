@@ -0,0 +1,165 @@
+use typeshell::compiler::ast::{
+    AstNode, ExportDeclaration, ImportClause, ImportDeclaration, ImportFromDeclaration, ModuleBody, ModuleDeclaration,
+    ModuleName, NamedImportBindings, Statement,
+};
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{errors, parse, parse_without_errors, statements};
+
+fn text_of<T: AstNode>(node: Option<T>) -> String {
+    node.map(|node| node.syntax().text().trim().to_string())
+        .unwrap_or_default()
+}
+
+#[test]
+fn internal_modules() {
+    let statements = statements("export module A.B.C { var x; }\ndeclare module \"fs\" { }");
+    let outer = match &statements[0] {
+        Statement::ModuleDeclaration(declaration) => declaration.clone(),
+        statement => panic!("{:?} is a module declaration", statement),
+    };
+    let mut names = Vec::new();
+    let mut declaration: ModuleDeclaration = outer;
+    loop {
+        names.push(text_of(declaration.name()));
+        match declaration.body() {
+            Some(ModuleBody::ModuleDeclaration(inner)) => declaration = inner,
+            Some(ModuleBody::ModuleBlock(block)) => {
+                assert_eq!(block.statements().count(), 1);
+                break;
+            }
+            None => panic!("module has a body"),
+        }
+    }
+    assert_eq!(names, vec!["A", "B", "C"]);
+    match &statements[1] {
+        Statement::ModuleDeclaration(declaration) => {
+            assert!(matches!(declaration.name(), Some(ModuleName::LiteralExpression(_))));
+        }
+        statement => panic!("{:?} is a module declaration", statement),
+    }
+}
+
+#[test]
+fn import_equals_and_export_assignment() {
+    let statements = statements("import fs = require(\"fs\");\nexport import b = A.B;\nexport = fs;");
+    let imports: Vec<ImportDeclaration> = statements
+        .iter()
+        .filter_map(|statement| ImportDeclaration::cast(statement.syntax().clone()))
+        .collect();
+    assert_eq!(text_of(imports[0].name()), "fs");
+    assert_eq!(text_of(imports[0].external_module_name()), "\"fs\"");
+    assert_eq!(text_of(imports[1].entity_name()), "A.B");
+    assert_eq!(imports[1].external_module_name(), None);
+    match &statements[2] {
+        Statement::ExportAssignment(assignment) => assert_eq!(text_of(assignment.export_name()), "fs"),
+        statement => panic!("{:?} is an export assignment", statement),
+    }
+}
+
+fn import_clause(text: &str) -> (ImportFromDeclaration, Option<ImportClause>) {
+    match statements(text).pop() {
+        Some(Statement::ImportFromDeclaration(declaration)) => {
+            let clause = declaration.import_clause();
+            (declaration, clause)
+        }
+        statement => panic!("{:?} is an import declaration", statement),
+    }
+}
+
+#[test]
+fn import_declarations() {
+    let (declaration, clause) = import_clause("import \"side-effect\";");
+    assert_eq!(clause, None);
+    assert_eq!(text_of(declaration.module_specifier()), "\"side-effect\"");
+
+    let (declaration, clause) = import_clause("import a, * as b from 'x';");
+    let clause = clause.unwrap();
+    assert_eq!(text_of(clause.name()), "a");
+    match clause.named_bindings() {
+        Some(NamedImportBindings::NamespaceImport(namespace)) => assert_eq!(text_of(namespace.name()), "b"),
+        bindings => panic!("{:?} is a namespace import", bindings),
+    }
+    assert_eq!(text_of(declaration.module_specifier()), "'x'");
+
+    let (_, clause) = import_clause("import { a as b, default as c, as, d } from \"x\"");
+    let clause = clause.unwrap();
+    assert_eq!(clause.name(), None);
+    let specifiers: Vec<(String, String)> = match clause.named_bindings() {
+        Some(NamedImportBindings::NamedImports(imports)) => imports
+            .elements()
+            .map(|specifier| (text_of(specifier.property_name()), text_of(specifier.name())))
+            .collect(),
+        bindings => panic!("{:?} are named imports", bindings),
+    };
+    let expected = [("a", "b"), ("default", "c"), ("", "as"), ("", "d")];
+    let expected: Vec<(String, String)> = expected.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect();
+    assert_eq!(specifiers, expected);
+
+    // `from` is only a keyword in the position of one
+    let (_, clause) = import_clause("import from from \"x\";");
+    assert_eq!(text_of(clause.unwrap().name()), "from");
+    parse_without_errors("var as = 1, from = as;");
+}
+
+fn export_declaration(text: &str) -> ExportDeclaration {
+    match statements(text).pop() {
+        Some(Statement::ExportDeclaration(declaration)) => declaration,
+        statement => panic!("{:?} is an export declaration", statement),
+    }
+}
+
+#[test]
+fn export_declarations() {
+    let declaration = export_declaration("export * from \"y\";");
+    assert!(declaration.asterisk_token().is_some());
+    assert_eq!(declaration.export_clause(), None);
+    assert_eq!(text_of(declaration.module_specifier()), "\"y\"");
+
+    let declaration = export_declaration("export { a, b as default, c as d } from 'e';");
+    assert_eq!(declaration.asterisk_token(), None);
+    let specifiers: Vec<(String, String)> = declaration
+        .export_clause()
+        .unwrap()
+        .elements()
+        .map(|specifier| (text_of(specifier.property_name()), text_of(specifier.name())))
+        .collect();
+    let expected = [("", "a"), ("b", "default"), ("c", "d")];
+    let expected: Vec<(String, String)> = expected.iter().map(|(a, b)| (a.to_string(), b.to_string())).collect();
+    assert_eq!(specifiers, expected);
+
+    let declaration = export_declaration("var a;\nexport { a };");
+    assert_eq!(declaration.module_specifier(), None);
+    assert_eq!(declaration.export_clause().unwrap().elements().count(), 1);
+}
+
+#[test]
+fn malformed_imports_and_exports() {
+    let file = parse("import { a b } from;\nexport * \"x\";\nimport * from \"y\";\nexport var c;");
+    let errors = errors(&file);
+    assert_eq!(
+        errors,
+        vec![
+            (11, "',' expected."),
+            (19, "String literal expected."),
+            (30, "'from' expected."),
+            (44, "'as' expected."),
+            (49, "'from' expected."),
+        ]
+    );
+    let kinds: Vec<SyntaxKind> = file
+        .ast()
+        .statements()
+        .map(|statement| statement.syntax().kind())
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::ImportFromDeclaration,
+            SyntaxKind::ExportDeclaration,
+            SyntaxKind::ImportFromDeclaration,
+            SyntaxKind::VariableStatement,
+        ]
+    );
+}
@@ -0,0 +1,100 @@
+use typeshell::compiler::arena::{NodeArena, NodeId};
+use typeshell::compiler::ast::{AstNode, ClassDeclaration};
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::types::SyntaxKind;
+
+const TEXT: &str = "// shell\nclass Job {\n    run(cmd: string) {\n        return exec(cmd, 1 + 2);\n    }\n}\n";
+
+fn kinds(arena: &NodeArena, ids: impl Iterator<Item = NodeId>) -> Vec<SyntaxKind> {
+    ids.map(|id| arena.kind(id)).collect()
+}
+
+#[test]
+fn mirrors_the_syntax_tree() {
+    let file = create_source_file("job.ts", TEXT);
+    let arena = NodeArena::new(&file);
+    let nodes: Vec<_> = file.root.descendants().collect();
+    assert_eq!(arena.len(), nodes.len());
+    // Ids are handed out in source order
+    for (index, node) in nodes.iter().enumerate() {
+        let id = arena.find_node(node).unwrap();
+        assert_eq!(id.index(), index);
+        assert_eq!(arena.kind(id), node.kind());
+        assert_eq!(
+            (arena.pos(id), arena.token_pos(id), arena.end(id)),
+            (node.pos(), node.token_pos(), node.end())
+        );
+        assert_eq!(arena.node_text(id), node.text());
+        assert_eq!(
+            arena.parent(id),
+            node.parent().and_then(|parent| arena.find_node(&parent))
+        );
+        assert_eq!(arena.children(id).len(), node.child_count());
+        assert_eq!(arena.to_syntax_node(&file.root, id).as_ref(), Some(node));
+    }
+    assert_eq!(arena.parent(arena.root()), None);
+    assert_eq!(arena.kind(arena.root()), SyntaxKind::SourceFile);
+
+    let other = create_source_file("other.ts", "class Job {}");
+    let class = other.root.descendants().find_map(ClassDeclaration::cast).unwrap();
+    assert_eq!(arena.find_node(class.syntax()), None);
+}
+
+#[test]
+fn tokens_at_positions() {
+    let file = create_source_file("job.ts", TEXT);
+    let arena = NodeArena::new(&file);
+    let token_at = |position| arena.find_token_at(position).map(|id| arena.node_text(id));
+    assert_eq!(
+        token_at(0).as_deref(),
+        Some("class"),
+        "leading trivia belongs to the token after it"
+    );
+    assert_eq!(token_at(TEXT.find("Job").unwrap() + 2).as_deref(), Some("Job"));
+    assert_eq!(token_at(TEXT.find("string").unwrap()).as_deref(), Some("string"));
+    assert_eq!(token_at(TEXT.find("(cmd,").unwrap()).as_deref(), Some("("));
+    let end_of_file = arena.find_token_at(TEXT.len()).unwrap();
+    assert_eq!(arena.kind(end_of_file), SyntaxKind::EndOfFileToken);
+    assert_eq!(arena.find_token_at(TEXT.len() + 1), None);
+
+    let empty = create_source_file("empty.ts", "");
+    let arena = NodeArena::new(&empty);
+    assert_eq!(
+        arena.find_token_at(0).map(|id| arena.kind(id)),
+        Some(SyntaxKind::EndOfFileToken)
+    );
+}
+
+#[test]
+fn innermost_nodes_and_ancestors() {
+    let file = create_source_file("job.ts", TEXT);
+    let arena = NodeArena::new(&file);
+    let plus = arena.find_innermost_node_at(TEXT.find("+ 2").unwrap()).unwrap();
+    assert_eq!(arena.kind(plus), SyntaxKind::BinaryExpression);
+    assert_eq!(arena.node_text(plus), "1 + 2");
+    assert_eq!(
+        kinds(&arena, arena.ancestors(plus)),
+        vec![
+            SyntaxKind::SyntaxList,
+            SyntaxKind::CallExpression,
+            SyntaxKind::ReturnStatement,
+            SyntaxKind::SyntaxList,
+            SyntaxKind::FunctionBlock,
+            SyntaxKind::Method,
+            SyntaxKind::SyntaxList,
+            SyntaxKind::ClassDeclaration,
+            SyntaxKind::SyntaxList,
+            SyntaxKind::SourceFile,
+        ]
+    );
+    let identifier = arena.find_innermost_node_at(TEXT.find("exec").unwrap()).unwrap();
+    assert_eq!(arena.kind(identifier), SyntaxKind::CallExpression);
+    let parameter = arena.find_innermost_node_at(TEXT.find("string").unwrap()).unwrap();
+    assert_eq!(arena.kind(parameter), SyntaxKind::Parameter);
+    // Trivia before a node is not part of it
+    assert_eq!(arena.find_innermost_node_at(2), Some(arena.root()));
+    let body = arena.find_innermost_node_at(TEXT.find("    return").unwrap()).unwrap();
+    assert_eq!(arena.kind(body), SyntaxKind::FunctionBlock);
+    assert_eq!(arena.find_innermost_node_at(TEXT.len()), Some(arena.root()));
+    assert_eq!(arena.find_innermost_node_at(TEXT.len() + 1), None);
+}
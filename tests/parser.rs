@@ -0,0 +1,195 @@
+use typeshell::compiler::ast::AstNode;
+
+mod common;
+use common::{parse, shape};
+
+// Shape of each statement of text, which has no errors
+fn statements(text: &str) -> Vec<String> {
+    common::statements(text)
+        .iter()
+        .map(|statement| shape(statement.syntax()))
+        .collect()
+}
+
+fn expression(text: &str) -> String {
+    let statements = statements(&format!("{};", text));
+    assert_eq!(statements.len(), 1);
+    statements[0].clone()
+}
+
+// Shape of the file and the start, length and message of each error
+fn recover(text: &str) -> (String, Vec<(usize, usize, String)>) {
+    let file = parse(text);
+    let errors = file
+        .parse_diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.start, diagnostic.length, diagnostic.message_text.clone()))
+        .collect();
+    let statements: Vec<String> = file
+        .ast()
+        .statements()
+        .map(|statement| shape(statement.syntax()))
+        .collect();
+    (statements.join("\n"), errors)
+}
+
+fn error(start: usize, length: usize, message: &str) -> (usize, usize, String) {
+    (start, length, message.to_string())
+}
+
+#[test]
+fn variable_and_function_declarations() {
+    assert_eq!(
+        statements("var a, b: number = 1;\nfunction f(x, y?: string, ...z): void { return x; }"),
+        vec![
+            "VariableStatement(var VariableDeclaration(a) , VariableDeclaration(b : number = 1) ;)",
+            "FunctionDeclaration(function f ( Parameter(x) , Parameter(y ? : string) , Parameter(... z) ) : void FunctionBlock({ ReturnStatement(return x ;) }))",
+        ]
+    );
+}
+
+#[test]
+fn control_flow_statements() {
+    assert_eq!(
+        statements(
+            "if (a) b; else { c; }\nwhile (a) break;\ndo continue; while (a);\nfor (var i = 0; i < n; i++) ;\nfor (k in o) ;\nfor (;;) ;"
+        ),
+        vec![
+            "IfStatement(if ( a ) ExpressionStatement(b ;) else Block({ ExpressionStatement(c ;) }))",
+            "WhileStatement(while ( a ) BreakStatement(break ;))",
+            "DoStatement(do ContinueStatement(continue ;) while ( a ) ;)",
+            "ForStatement(for ( var VariableDeclaration(i = 0) ; BinaryExpression(i < n) ; PostfixOperator(i ++) ) EmptyStatement(;))",
+            "ForInStatement(for ( k in o ) EmptyStatement(;))",
+            "ForStatement(for ( ; ; ) EmptyStatement(;))",
+        ]
+    );
+    assert_eq!(
+        statements("switch (x) { case 1: a; case 2: default: b; }\ntry { } catch (e) { throw e; } finally { }\nl: with (o) debugger;"),
+        vec![
+            "SwitchStatement(switch ( x ) { CaseClause(case 1 : ExpressionStatement(a ;)) CaseClause(case 2 :) DefaultClause(default : ExpressionStatement(b ;)) })",
+            "TryStatement(TryBlock(try { }) CatchBlock(catch ( e ) { ThrowStatement(throw e ;) }) FinallyBlock(finally { }))",
+            "LabelledStatement(l : WithStatement(with ( o ) DebuggerStatement(debugger ;)))",
+        ]
+    );
+}
+
+#[test]
+fn binary_operators_follow_precedence_and_associativity() {
+    assert_eq!(
+        expression("a + b * c - d"),
+        "ExpressionStatement(BinaryExpression(BinaryExpression(a + BinaryExpression(b * c)) - d) ;)"
+    );
+    assert_eq!(
+        expression("a = b += c"),
+        "ExpressionStatement(BinaryExpression(a = BinaryExpression(b += c)) ;)"
+    );
+    assert_eq!(
+        expression("a || b && c | d"),
+        "ExpressionStatement(BinaryExpression(a || BinaryExpression(b && BinaryExpression(c | d))) ;)"
+    );
+    assert_eq!(
+        expression("a ? b : c ? d : e"),
+        "ExpressionStatement(ConditionalExpression(a ? b : ConditionalExpression(c ? d : e)) ;)"
+    );
+    assert_eq!(
+        expression("a, b = c"),
+        "ExpressionStatement(BinaryExpression(a , BinaryExpression(b = c)) ;)"
+    );
+}
+
+#[test]
+fn unary_and_member_expressions() {
+    assert_eq!(
+        expression("-!a++"),
+        "ExpressionStatement(PrefixOperator(- PrefixOperator(! PostfixOperator(a ++))) ;)"
+    );
+    assert_eq!(
+        expression("typeof new a.B(c)[0].d()"),
+        "ExpressionStatement(PrefixOperator(typeof CallExpression(PropertyAccess(IndexedAccess(NewExpression(new PropertyAccess(a . B) ( c )) [ 0 ]) . d) ( ))) ;)"
+    );
+    assert_eq!(
+        expression("<T>x"),
+        "ExpressionStatement(TypeAssertion(< TypeReference(T) > x) ;)"
+    );
+}
+
+#[test]
+fn literals_and_function_expressions() {
+    assert_eq!(
+        expression("x = [1, , 'a', { b: 2, 'c': this }]"),
+        "ExpressionStatement(BinaryExpression(x = ArrayLiteral([ 1 , OmittedExpression() , 'a' , ObjectLiteral({ PropertyAssignment(b : 2) , PropertyAssignment('c' : this) }) ])) ;)"
+    );
+    assert_eq!(
+        expression("(function g(a) { })"),
+        "ExpressionStatement(ParenExpression(( FunctionExpression(function g ( Parameter(a) ) FunctionBlock({ })) )) ;)"
+    );
+}
+
+#[test]
+fn declarations() {
+    assert_eq!(
+        statements("class C extends B implements I { x: T; }\ninterface I { m(): void; }\nenum E { A = 1, B }\nmodule M.N { }\nimport x = M.N;"),
+        vec![
+            "ClassDeclaration(class C extends TypeReference(B) implements TypeReference(I) { Property(x : TypeReference(T) ;) })",
+            "InterfaceDeclaration(interface I { Method(m ( ) : void ;) })",
+            "EnumDeclaration(enum E { EnumMember(A = 1) , EnumMember(B) })",
+            "ModuleDeclaration(module M . ModuleDeclaration(N ModuleBlock({ })))",
+            "ImportDeclaration(import x = QualifiedName(M . N) ;)",
+        ]
+    );
+}
+
+#[test]
+fn missing_tokens_are_inserted() {
+    assert_eq!(
+        recover("if (a b;"),
+        (
+            "IfStatement(if ( a <CloseParenToken> ExpressionStatement(b ;))".to_string(),
+            vec![error(6, 1, "')' expected.")]
+        )
+    );
+    assert_eq!(
+        recover("function f( { }"),
+        (
+            "FunctionDeclaration(function f ( <CloseParenToken> FunctionBlock({ }))".to_string(),
+            vec![error(12, 1, "Parameter declaration expected.")]
+        )
+    );
+    assert_eq!(
+        recover("class C { x: number"),
+        (
+            "ClassDeclaration(class C { Property(x : number) <CloseBraceToken>)".to_string(),
+            vec![error(19, 0, "'}' expected.")]
+        )
+    );
+}
+
+#[test]
+fn missing_nodes_are_inserted() {
+    assert_eq!(
+        recover("var x: = 1;"),
+        (
+            "VariableStatement(var VariableDeclaration(x : Missing() = 1) ;)".to_string(),
+            vec![error(7, 1, "Type expected.")]
+        )
+    );
+    assert_eq!(
+        recover("x = (1 + );"),
+        (
+            "ExpressionStatement(BinaryExpression(x = ParenExpression(( BinaryExpression(1 + Missing()) ))) ;)"
+                .to_string(),
+            vec![error(9, 1, "Expression expected.")]
+        )
+    );
+}
+
+#[test]
+fn errors_are_reported_at_the_unexpected_token() {
+    let (_, errors) = recover("var a = 1\nvar b = }\nvar c");
+    assert_eq!(errors, vec![error(18, 1, "Expression expected.")]);
+    let (_, errors) = recover("a.;\nf(1 2);");
+    assert_eq!(
+        errors,
+        vec![error(2, 1, "Identifier expected."), error(8, 1, "',' expected.")]
+    );
+}
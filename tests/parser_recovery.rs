@@ -0,0 +1,139 @@
+use typeshell::compiler::ast::{AstNode, Statement};
+use typeshell::compiler::types::{SourceFile, SyntaxKind};
+
+mod common;
+use common::{errors, parse};
+
+fn statement_kinds(file: &SourceFile) -> Vec<SyntaxKind> {
+    file.ast().statements().map(|statement| statement.kind()).collect()
+}
+
+#[test]
+fn missing_semicolon() {
+    let file = parse("a = 1 b = 2;");
+    assert_eq!(errors(&file), vec![(6, "';' expected.")]);
+    assert_eq!(
+        statement_kinds(&file),
+        vec![SyntaxKind::ExpressionStatement, SyntaxKind::ExpressionStatement]
+    );
+    let semicolon = file.root.descendants().find(|node| node.is_missing()).unwrap();
+    assert_eq!(semicolon.kind(), SyntaxKind::SemicolonToken);
+    assert_eq!((semicolon.pos(), semicolon.end()), (5, 5));
+}
+
+#[test]
+fn missing_expression() {
+    let file = parse("var a = ;\nvar b = 1;");
+    assert_eq!(errors(&file), vec![(8, "Expression expected.")]);
+    let missing = file
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Missing)
+        .unwrap();
+    assert_eq!(missing.width(), 0);
+    assert_eq!(missing.parent().unwrap().kind(), SyntaxKind::VariableDeclaration);
+    assert_eq!(statement_kinds(&file).len(), 2);
+}
+
+#[test]
+fn reports_all_errors_in_one_pass() {
+    let file = parse("var a = ;\nf(1 2);\nvar o = { a: 1, b 2 };\nif (a) { b = a + }\nvar z = 1;");
+    assert_eq!(
+        errors(&file),
+        vec![
+            (8, "Expression expected."),
+            (14, "',' expected."),
+            (36, "':' expected."),
+            (58, "Expression expected."),
+        ]
+    );
+    match file.ast().statements().last() {
+        Some(Statement::VariableStatement(statement)) => {
+            let declaration = statement.declarations().next().unwrap();
+            assert_eq!(declaration.name().unwrap().text(), "z");
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn skips_unexpected_statement_tokens() {
+    let file = parse("var a = 1; ) ] var b = 2;");
+    assert_eq!(errors(&file), vec![(11, "Declaration or statement expected.")]);
+    assert_eq!(
+        statement_kinds(&file),
+        vec![SyntaxKind::VariableStatement, SyntaxKind::VariableStatement]
+    );
+    let skipped = file
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Missing)
+        .unwrap();
+    assert_eq!(skipped.text(), ") ]");
+}
+
+#[test]
+fn resynchronizes_class_members() {
+    let file = parse("class C { x: number; ) y: string; m() { } }\nvar b;");
+    assert_eq!(
+        errors(&file),
+        vec![(
+            21,
+            "Unexpected token. A constructor, method, accessor or property was expected."
+        )]
+    );
+    match file.ast().statements().next() {
+        Some(Statement::ClassDeclaration(class)) => assert_eq!(class.members().count(), 3),
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(statement_kinds(&file).len(), 2);
+}
+
+#[test]
+fn aborts_list_at_enclosing_list_element() {
+    let file = parse("class C { x: number;\n(b);");
+    assert_eq!(
+        errors(&file),
+        vec![(
+            21,
+            "Unexpected token. A constructor, method, accessor or property was expected."
+        )]
+    );
+    assert_eq!(
+        statement_kinds(&file),
+        vec![SyntaxKind::ClassDeclaration, SyntaxKind::ExpressionStatement]
+    );
+}
+
+#[test]
+fn resynchronizes_arguments_and_object_literals() {
+    let file = parse("f(a, ], b);\nvar o = { a: 1, ] b: 2 };");
+    assert_eq!(
+        errors(&file),
+        vec![
+            (5, "Argument expression expected."),
+            (28, "Property assignment expected.")
+        ]
+    );
+    let call = file
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::CallExpression)
+        .unwrap();
+    assert_eq!(call.text(), "f(a, ], b)");
+}
+
+#[test]
+fn reports_scanner_errors() {
+    let file = parse("var a = 1 @ 2;\nvar s = \"abc\nvar t;");
+    assert_eq!(errors(&file)[0], (10, "Invalid character."));
+    assert!(errors(&file).contains(&(27, "Unterminated string literal.")));
+}
+
+#[test]
+fn every_prefix_of_a_file_round_trips() {
+    let text = std::fs::read_to_string("tests/corpus/declarations.ts").unwrap();
+    for end in (0..=text.len()).filter(|&end| text.is_char_boundary(end)) {
+        parse(&text[..end]);
+    }
+}
@@ -0,0 +1,118 @@
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::precedence::*;
+use typeshell::compiler::printer::{print_node, PrinterOptions};
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::SyntaxKind;
+
+// Operator expressions in parentheses, so `a + b * c` shows as `(a + (b * c))`
+fn grouped(node: &SyntaxNode) -> String {
+    match node.kind() {
+        SyntaxKind::BinaryExpression
+        | SyntaxKind::ConditionalExpression
+        | SyntaxKind::PrefixOperator
+        | SyntaxKind::PostfixOperator => {
+            let parts: Vec<String> = node.children().map(|child| grouped(&child)).collect();
+            format!("({})", parts.join(" "))
+        }
+        _ => node.text(),
+    }
+}
+
+// The expression of the statement `text`, which must parse without errors
+fn parse(text: &str) -> String {
+    let file = create_source_file("test.ts", text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{}", text);
+    let statement = file
+        .root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::ExpressionStatement)
+        .unwrap();
+    grouped(&statement.first_child().unwrap())
+}
+
+#[test]
+fn table() {
+    assert_eq!(
+        get_binary_operator_precedence(SyntaxKind::CommaToken),
+        Some(Precedence::Comma)
+    );
+    assert_eq!(
+        get_binary_operator_precedence(SyntaxKind::GreaterThanGreaterThanGreaterThanEqualsToken),
+        Some(Precedence::Assignment)
+    );
+    assert_eq!(
+        get_binary_operator_precedence(SyntaxKind::QuestionToken),
+        Some(Precedence::Conditional)
+    );
+    assert_eq!(
+        get_binary_operator_precedence(SyntaxKind::InKeyword),
+        Some(Precedence::Relational)
+    );
+    assert_eq!(
+        get_binary_operator_precedence(SyntaxKind::PercentToken),
+        Some(Precedence::Multiplicative)
+    );
+    assert_eq!(get_binary_operator_precedence(SyntaxKind::ExclamationToken), None);
+    assert_eq!(
+        get_prefix_operator_precedence(SyntaxKind::TypeOfKeyword),
+        Some(Precedence::Unary)
+    );
+    assert_eq!(
+        get_postfix_operator_precedence(SyntaxKind::MinusMinusToken),
+        Some(Precedence::Postfix)
+    );
+    assert_eq!(get_postfix_operator_precedence(SyntaxKind::TildeToken), None);
+    assert_eq!(get_associativity(Precedence::Assignment), Associativity::Right);
+    assert_eq!(get_associativity(Precedence::Additive), Associativity::Left);
+    assert!(Precedence::BitwiseAnd > Precedence::BitwiseXor && Precedence::BitwiseXor > Precedence::BitwiseOr);
+}
+
+#[test]
+fn binary_operators() {
+    assert_eq!(parse("a + b * c;"), "(a + (b * c))");
+    assert_eq!(parse("a - b - c;"), "((a - b) - c)");
+    assert_eq!(parse("a || b && c | d ^ e & f;"), "(a || (b && (c | (d ^ (e & f)))))");
+    assert_eq!(parse("a == b < c << d;"), "(a == (b < (c << d)))");
+    assert_eq!(parse("a >>> b >= c;"), "((a >>> b) >= c)");
+    assert_eq!(parse("a, b, c;"), "((a , b) , c)");
+}
+
+#[test]
+fn right_associative_operators() {
+    assert_eq!(parse("a = b += c;"), "(a = (b += c))");
+    assert_eq!(parse("a ? b : c ? d : e;"), "(a ? b : (c ? d : e))");
+    assert_eq!(parse("a || b ? c = d : e, f;"), "(((a || b) ? (c = d) : e) , f)");
+    assert_eq!(parse("x = a ? b : c;"), "(x = (a ? b : c))");
+}
+
+#[test]
+fn unary_operators() {
+    assert_eq!(parse("-a * b;"), "((- a) * b)");
+    assert_eq!(parse("!typeof a++;"), "(! (typeof (a ++)))");
+    assert_eq!(parse("a++ + --b;"), "((a ++) + (-- b))");
+}
+
+#[test]
+fn assignment_needs_left_hand_side() {
+    let file = create_source_file("test.ts", "a + b = c;");
+    assert!(!file.parse_diagnostics.is_empty());
+}
+
+#[test]
+fn printer_keeps_grouping() {
+    let options = PrinterOptions::default();
+    for text in [
+        "(a + b) * c;",
+        "a - (b - c);",
+        "(a = b) + c;",
+        "(a, b) ? c : d;",
+        "(a ? b : c) ? d : e;",
+        "-(-a);",
+        "(a || b) && c;",
+        "x = (a, b);",
+    ] {
+        let file = create_source_file("test.ts", text);
+        let printed = print_node(&file.root, &options);
+        assert_eq!(printed.trim_end(), text);
+    }
+}
@@ -0,0 +1,257 @@
+use std::fs;
+use typeshell::compiler::ast::{AstNode, Expression, ExpressionStatement, Identifier, SourceFile, Statement};
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::printer::{print_node, PrinterOptions, QuoteStyle, SemicolonPolicy};
+use typeshell::compiler::syntax::{GreenNode, SyntaxNode};
+use typeshell::compiler::types::SyntaxKind;
+use typeshell::compiler::visit::{fold, fold_children, Fold};
+
+mod common;
+use common::shape_with;
+
+const SNIPPETS: &[&str] = &[
+    "a = b = c; a + (b + c); (a + b) + c; a - (b - c); (a, b); f((a, b));",
+    "x = a ? b : c ? d : e; (a ? b : c) ? d : e; a || b && c; (a || b) && c;",
+    "-(-x); +(+x); - -x; typeof typeof x; void 0; delete a[b]; !(a && b); (-x)++; x++ + ++y;",
+    "new (f())(); new (a.b().c)(); new a.b.c(); (new X).y; new X().y; (new X)(); new new X()(); new new X; !new new x / y; new (new X)(a);",
+    "({}); ({ a: 1 }.a); (function () {})(); (function f() {}); [a, , b, ,]; [, ];",
+    "var f = x => x, g = (x, y) => ({ x: x }), h = (x: number): number => { return x; };",
+    "var r = /ab+c/gi.test(s), d = a / b / c; x = <any>y; x = <T>(<U>y);",
+    "for (var i = (a in b); i < 1; i++) {} for (x = (a in b);;) ; for (var k in o) for (p in q) ;",
+    "if (a) { if (b) x(); } else y(); if (a) b(); else if (c) d(); else { e(); }",
+    "lbl: for (;;) { continue lbl; break lbl; } do x++; while (x < 10) while (x) { x--; }",
+    "switch (x) { case 1: case 2: y(); break; default: } with (o) { a; } try { f(); } catch (e) { throw e; }",
+    "var o = { a: 1, \"b\": 2, 3: c, get d() { return 1; }, set d(v) {}, m(x) { return x; } };",
+    "class C<T> extends B<T> implements I, J { public static x: number = 1; private y; constructor(a, b?: string) { super(a); } get z() { return this.y; } set z(v) {} m?(a: string): void; }",
+    "class D { [k: string]: any; constructor(public a, private b: string, protected c = 1) {} f(): void; f(x?) {} private get g() { return 1; } }",
+    "interface I<T extends U> extends J, K { a: string; b?(x): void; c: T[]; }",
+    "interface J { (a: string): number; new (): J; [i: number]: string; c: { d: number; e(): T[]; }; f: typeof M.n; g: (x: number) => string; h: new () => J; }",
+    "var f: <T>(x: T) => { (): T; }[], g: { [k: string]: any; } = null;",
+    "export module A.B.C { export var x = 1; } module D { import e = A.B; } enum E { A = 1, B, C = A | B }",
+    "import a = require('a'); import 'b'; import c, { d as e, f } from \"g\"; import * as h from 'i'; import j, * as k from 'l';",
+    "export * from 'a'; export { b as c, d } from 'e'; export { f }; export {}; export import g = h.i; export = j;",
+    "function f(a, b = 1, ...c: any[]): Array<Array<number>> { return f.apply(this, arguments); } debugger; ;",
+];
+
+// Trivia, missing tokens and separators that the printer may add or drop do not take part in the
+// comparison, string literals are compared by their contents
+fn shape_of(node: &SyntaxNode) -> String {
+    shape_with(node, &|token| {
+        let parent = token.parent().unwrap();
+        let is_optional_separator = token.index() + 1 == parent.child_count()
+            && parent.kind() != SyntaxKind::EmptyStatement
+            && parent.kind() != SyntaxKind::ArrayLiteral
+            && matches!(token.kind(), SyntaxKind::SemicolonToken | SyntaxKind::CommaToken);
+        if token.is_missing() || is_optional_separator {
+            return String::new();
+        }
+        if token.kind() == SyntaxKind::StringLiteral {
+            return token.value().to_string();
+        }
+        token.token_text().to_string()
+    })
+}
+
+fn print(text: &str, options: &PrinterOptions) -> String {
+    let file = create_source_file("a.ts", text);
+    assert_eq!(file.parse_diagnostics, Vec::new(), "{:?} has no errors", text);
+    print_node(&file.root, options)
+}
+
+fn all_options() -> Vec<PrinterOptions> {
+    vec![
+        PrinterOptions::default(),
+        PrinterOptions {
+            indent: "\t".to_string(),
+            quote_style: QuoteStyle::Single,
+            semicolons: SemicolonPolicy::AsNeeded,
+        },
+        PrinterOptions {
+            indent: "  ".to_string(),
+            quote_style: QuoteStyle::Double,
+            semicolons: SemicolonPolicy::Always,
+        },
+    ]
+}
+
+fn check_round_trip(text: &str) {
+    let original = create_source_file("a.ts", text);
+    assert_eq!(original.parse_diagnostics, Vec::new(), "{:?} has no errors", text);
+    for options in all_options() {
+        let printed = print_node(&original.root, &options);
+        let reparsed = create_source_file("a.ts", &printed);
+        assert_eq!(reparsed.parse_diagnostics, Vec::new(), "printed {:?}", printed);
+        assert_eq!(
+            shape_of(&reparsed.root),
+            shape_of(&original.root),
+            "printed {:?}",
+            printed
+        );
+        assert_eq!(print_node(&reparsed.root, &options), printed, "printing is idempotent");
+    }
+}
+
+#[test]
+fn round_trip_corpus() {
+    for entry in fs::read_dir("tests/corpus").unwrap() {
+        check_round_trip(&fs::read_to_string(entry.unwrap().path()).unwrap());
+    }
+}
+
+#[test]
+fn round_trip_snippets() {
+    for snippet in SNIPPETS {
+        check_round_trip(snippet);
+    }
+}
+
+#[test]
+fn layout() {
+    let text = "// lead\nfunction f(a) { if (a) return 1; else { return 2 } /* end */ }\nvar o = {}, p = { a: 1 };";
+    assert_eq!(
+        print(text, &PrinterOptions::default()),
+        "// lead\nfunction f(a) {\n    if (a)\n        return 1;\n    else {\n        return 2;\n    }\n    /* end */\n}\nvar o = {}, p = { a: 1 };\n"
+    );
+    let options = PrinterOptions {
+        indent: "\t".to_string(),
+        ..PrinterOptions::default()
+    };
+    assert_eq!(
+        print("class C { x; m() { } }", &options),
+        "class C {\n\tx;\n\tm() {}\n}\n"
+    );
+    assert_eq!(print("", &options), "");
+}
+
+#[test]
+fn quote_styles() {
+    let text = "var a = 'x', b = \"y\", c = 'it\"s';";
+    let print_with = |quote_style| {
+        let options = PrinterOptions {
+            quote_style,
+            ..PrinterOptions::default()
+        };
+        print(text, &options)
+    };
+    assert_eq!(
+        print_with(QuoteStyle::Preserve),
+        "var a = 'x', b = \"y\", c = 'it\"s';\n"
+    );
+    assert_eq!(
+        print_with(QuoteStyle::Double),
+        "var a = \"x\", b = \"y\", c = 'it\"s';\n"
+    );
+    assert_eq!(print_with(QuoteStyle::Single), "var a = 'x', b = 'y', c = 'it\"s';\n");
+}
+
+#[test]
+fn semicolons_as_needed() {
+    let options = PrinterOptions {
+        semicolons: SemicolonPolicy::AsNeeded,
+        ..PrinterOptions::default()
+    };
+    let text = "a; (b); c; [d]; e; -f; g; /h/.test(i); var j = 1; function k() { return; } ;";
+    assert_eq!(
+        print(text, &options),
+        "a;\n(b)\nc;\n[d]\ne;\n-f\ng;\n/h/.test(i)\nvar j = 1\nfunction k() {\n    return\n}\n;\n"
+    );
+}
+
+// Replaces the identifiers named `name` with expression
+struct Replace {
+    name: &'static str,
+    with: GreenNode,
+}
+
+impl Fold for Replace {
+    fn fold_identifier(&mut self, node: &Identifier) -> Vec<GreenNode> {
+        if node.text() == self.name {
+            return vec![self.with.clone()];
+        }
+        vec![fold_children(self, node.syntax())]
+    }
+}
+
+fn first_statement(text: &str) -> GreenNode {
+    let file = create_source_file("a.ts", text);
+    let statement = SourceFile::cast(file.root).unwrap().statements().next().unwrap();
+    statement.syntax().green().clone()
+}
+
+// The expression text stands for, parsed in parentheses so it cannot be taken for a statement
+fn expression(text: &str) -> GreenNode {
+    let file = create_source_file("a.ts", &format!("({});", text));
+    match SourceFile::cast(file.root).unwrap().statements().next() {
+        Some(Statement::ExpressionStatement(statement)) => match statement.expression() {
+            Some(Expression::ParenExpression(paren)) => paren.expression().unwrap().syntax().green().clone(),
+            _ => panic!("{:?} is an expression", text),
+        },
+        _ => panic!("{:?} is an expression", text),
+    }
+}
+
+fn replace(text: &str, with: GreenNode) -> String {
+    let file = create_source_file("a.ts", text);
+    let root = fold(&mut Replace { name: "x", with }, &file.root);
+    let printed = print_node(&root, &PrinterOptions::default());
+    assert_eq!(
+        create_source_file("a.ts", &printed).parse_diagnostics,
+        Vec::new(),
+        "{:?}",
+        printed
+    );
+    printed
+}
+
+#[test]
+fn parenthesizes_rewritten_trees() {
+    let cases = [
+        ("a * x;", "b + c", "a * (b + c);\n"),
+        ("x * a;", "b + c", "(b + c) * a;\n"),
+        ("a - x;", "b - c", "a - (b - c);\n"),
+        ("x.y;", "new Foo", "(new Foo).y;\n"),
+        ("x.y;", "1", "(1).y;\n"),
+        ("new x();", "a().b", "new (a().b)();\n"),
+        ("f(x);", "a, b", "f((a, b));\n"),
+        ("x;", "{}", "({});\n"),
+        ("x;", "function () {}", "(function () {});\n"),
+        ("-x;", "-y", "- -y;\n"),
+        ("-x;", "a + b", "-(a + b);\n"),
+        ("x++;", "a + b", "(a + b)++;\n"),
+        ("x ? a : b;", "c ? d : e", "(c ? d : e) ? a : b;\n"),
+        ("x > (c);", "a < b", "(a < b) > (c);\n"),
+        ("f = () => x;", "{}", "f = () => ({});\n"),
+        ("a + x;", "y => y", "a + (y => y);\n"),
+        ("x();", "y => y", "(y => y)();\n"),
+        ("for (var i = x;;) ;", "a in b", "for (var i = (a in b);;)\n    ;\n"),
+        ("for (var i = f(x);;) ;", "a in b", "for (var i = f(a in b);;)\n    ;\n"),
+    ];
+    for (text, with, expected) in cases.iter() {
+        assert_eq!(replace(text, expression(with)), *expected, "{} with x = {}", text, with);
+    }
+}
+
+// Replaces the statement `x;` with statement
+struct ReplaceStatement {
+    with: GreenNode,
+}
+
+impl Fold for ReplaceStatement {
+    fn fold_expression_statement(&mut self, node: &ExpressionStatement) -> Vec<GreenNode> {
+        if node.syntax().text() == "x;" {
+            return vec![self.with.clone()];
+        }
+        vec![fold_children(self, node.syntax())]
+    }
+}
+
+#[test]
+fn braces_dangling_else() {
+    let file = create_source_file("a.ts", "if (a) x; else y;");
+    let with = first_statement("if (b) z;");
+    let printed = print_node(
+        &fold(&mut ReplaceStatement { with }, &file.root),
+        &PrinterOptions::default(),
+    );
+    assert_eq!(printed, "if (a) {\n    if (b)\n        z;\n} else\n    y;\n");
+}
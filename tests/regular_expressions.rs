@@ -0,0 +1,85 @@
+use typeshell::compiler::syntax::SyntaxNode;
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{errors, parse, parse_without_errors};
+
+fn regular_expressions(text: &str) -> Vec<String> {
+    parse_without_errors(text)
+        .root
+        .tokens()
+        .filter(|token| token.kind() == SyntaxKind::RegularExpressionLiteral)
+        .map(|token| token.value().to_string())
+        .collect()
+}
+
+fn divisions(root: &SyntaxNode) -> usize {
+    root.tokens()
+        .filter(|token| token.kind() == SyntaxKind::SlashToken || token.kind() == SyntaxKind::SlashEqualsToken)
+        .count()
+}
+
+#[test]
+fn regular_expression_in_expression_position() {
+    assert_eq!(regular_expressions("x = /ab+c/gi;"), vec!["/ab+c/gi"]);
+    assert_eq!(regular_expressions("f(/a/, /b/);"), vec!["/a/", "/b/"]);
+    assert_eq!(regular_expressions("var r = [/[/]/, /\\//];"), vec!["/[/]/", "/\\//"]);
+    assert_eq!(regular_expressions("x = /=/;"), vec!["/=/"]);
+    assert_eq!(regular_expressions("x = a ? /b/ : /c/;"), vec!["/b/", "/c/"]);
+    assert_eq!(regular_expressions("x = !/a/.test(y);"), vec!["/a/"]);
+}
+
+#[test]
+fn division_stays_division() {
+    let file = parse_without_errors("x = a / b / c;\nx /= 2;\ny = (a) / 2;\nz = f() / g[0] / {} / 1;");
+    assert_eq!(divisions(&file.root), 7);
+    assert!(!file
+        .root
+        .tokens()
+        .any(|token| token.kind() == SyntaxKind::RegularExpressionLiteral));
+}
+
+#[test]
+fn after_close_paren() {
+    // The `)` of a statement header is followed by a statement, of an expression by an operator
+    assert_eq!(regular_expressions("if (x) /a/.test(y);"), vec!["/a/"]);
+    assert_eq!(regular_expressions("while (x) /a/g.exec(y);"), vec!["/a/g"]);
+    assert_eq!(regular_expressions("x = (a) /b/ c;"), Vec::<String>::new());
+}
+
+#[test]
+fn after_close_brace() {
+    assert_eq!(regular_expressions("function f() { }\n/a/.test(y);"), vec!["/a/"]);
+    assert_eq!(regular_expressions("{ }\n/a/.test(y);"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x = { } / 2 / 1;"), Vec::<String>::new());
+}
+
+#[test]
+fn after_keywords() {
+    assert_eq!(regular_expressions("function f() { return /a/; }"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x = typeof /a/;"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x = void /a/;"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x = delete /a/.b;"), vec!["/a/"]);
+    assert_eq!(regular_expressions("throw /a/;"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x = new /a/.constructor();"), vec!["/a/"]);
+    assert_eq!(regular_expressions("switch (x) { case /a/: }"), vec!["/a/"]);
+}
+
+#[test]
+fn at_start_of_statement() {
+    assert_eq!(regular_expressions("/a/.test(y);"), vec!["/a/"]);
+    assert_eq!(regular_expressions("x;\n/=/g.exec(y);"), vec!["/=/g"]);
+    // Without a semicolon the slash continues the previous line as a division
+    let file = parse_without_errors("x\n/a/g");
+    assert_eq!(divisions(&file.root), 2);
+}
+
+#[test]
+fn unterminated_regular_expression() {
+    let text = "x = /ab\ny;";
+    let file = parse(text);
+    assert_eq!(errors(&file), vec![(7, "Unterminated regular expression literal.")]);
+
+    let file = parse("x = /[/");
+    assert_eq!(file.parse_diagnostics.len(), 1);
+}
@@ -0,0 +1,109 @@
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::scanner::Scanner;
+use typeshell::compiler::types::{Directive, DirectiveKind, SyntaxKind};
+
+fn directives(text: &str) -> Vec<Directive> {
+    let mut scanner = Scanner::create_scanner(text);
+    while scanner.scan() != SyntaxKind::EndOfFileToken {}
+    scanner.get_directives().to_vec()
+}
+
+// Kind, name and value of each directive
+fn summary(text: &str) -> Vec<(DirectiveKind, String, String)> {
+    directives(text)
+        .into_iter()
+        .map(|directive| (directive.kind, directive.name, directive.value))
+        .collect()
+}
+
+fn directive(kind: DirectiveKind, name: &str, value: &str) -> (DirectiveKind, String, String) {
+    (kind, name.to_string(), value.to_string())
+}
+
+#[test]
+fn triple_slash_directives() {
+    let text = "/// <reference path=\"a.ts\" />\n///<reference path='b.d.ts'/>\n/// <reference no-default-lib=\"true\"/>\n/// <amd-dependency path=\"dep\" name='d' />\n/// <amd-module name=\"m\" />\nvar x;";
+    assert_eq!(
+        summary(text),
+        vec![
+            directive(DirectiveKind::Reference, "reference", "a.ts"),
+            directive(DirectiveKind::Reference, "reference", "b.d.ts"),
+            directive(DirectiveKind::NoDefaultLib, "reference", "true"),
+            directive(DirectiveKind::AmdDependency, "amd-dependency", "dep"),
+            directive(DirectiveKind::AmdModule, "amd-module", "m"),
+        ]
+    );
+
+    // The span is that of the comment, without the line break
+    let first = &directives(text)[0];
+    assert_eq!((first.pos, first.end), (0, 29));
+    let second = &directives(text)[1];
+    assert_eq!(&text[second.pos..second.end], "///<reference path='b.d.ts'/>");
+}
+
+#[test]
+fn malformed_triple_slash_directives_are_comments() {
+    for text in [
+        "/// <reference path=\"a.ts\">",
+        "/// <reference path=\"a.ts\" /",
+        "/// <reference path=a.ts />",
+        "/// <reference path=\"a.ts />",
+        "/// <reference path='a.ts\" />",
+        "/// <reference />",
+        "/// <amd-module path=\"m\" />",
+        "/// <unknown path=\"a.ts\" />",
+        "/// reference path=\"a.ts\" />",
+        "//// <reference path=\"a.ts\" />",
+        "/* <reference path=\"a.ts\" /> */",
+    ] {
+        assert_eq!(summary(text), Vec::new(), "{}", text);
+    }
+    // Attributes after a malformed one are not read
+    assert_eq!(
+        summary("/// <reference no-default-lib=true path=\"a.ts\" />"),
+        Vec::new()
+    );
+    // A quote of the other kind does not end a value
+    assert_eq!(
+        summary("/// <reference path='a\"b.ts' />"),
+        vec![directive(DirectiveKind::Reference, "reference", "a\"b.ts")]
+    );
+}
+
+#[test]
+fn triple_slash_directives_only_lead_the_file() {
+    let text = "/// <reference path=\"a.ts\" />\nvar x;\n/// <reference path=\"b.ts\" />\nvar y;";
+    assert_eq!(
+        summary(text),
+        vec![directive(DirectiveKind::Reference, "reference", "a.ts")]
+    );
+    assert_eq!(summary("var x; /// <reference path=\"a.ts\" />"), Vec::new());
+    // Comments of any kind may come before
+    assert_eq!(
+        summary("/* header */\n// license\n/// <reference path=\"a.ts\" />"),
+        vec![directive(DirectiveKind::Reference, "reference", "a.ts")]
+    );
+}
+
+#[test]
+fn pragmas() {
+    let text = "// @ts-ignore\nvar x; // @target: es5\n//@module commonjs\n// @\n// not @a pragma";
+    assert_eq!(
+        summary(text),
+        vec![
+            directive(DirectiveKind::Pragma, "ts-ignore", ""),
+            directive(DirectiveKind::Pragma, "target", "es5"),
+            directive(DirectiveKind::Pragma, "module", "commonjs"),
+        ]
+    );
+    let target = &directives(text)[1];
+    assert_eq!(&text[target.pos..target.end], "// @target: es5");
+}
+
+#[test]
+fn source_files_hold_their_directives() {
+    let text = "/// <reference path=\"a.ts\" />\n// @strict\nvar x;";
+    let file = create_source_file("test.ts", text);
+    assert_eq!(file.directives, directives(text));
+    assert_eq!(file.directives.len(), 2);
+}
@@ -0,0 +1,37 @@
+use std::fs;
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::SyntaxNode;
+
+fn check_spans(node: &SyntaxNode) {
+    let mut pos = node.pos();
+    for child in node.children() {
+        assert_eq!(child.pos(), pos, "{:?} starts where its previous sibling ends", child);
+        assert_eq!(child.parent().as_ref(), Some(node));
+        check_spans(&child);
+        pos = child.end();
+    }
+    if node.child_count() > 0 {
+        assert_eq!(pos, node.end(), "{:?} ends with its last child", node);
+    }
+    assert!(node.pos() <= node.token_pos() && node.token_pos() <= node.end());
+}
+
+#[test]
+fn round_trip_corpus() {
+    let mut count = 0;
+    for entry in fs::read_dir("tests/corpus").unwrap() {
+        let path = entry.unwrap().path();
+        let text = fs::read_to_string(&path).unwrap();
+        let file_name = path.to_string_lossy().to_string();
+        let file = create_source_file(&file_name, &text);
+        assert_eq!(file.parse_diagnostics, Vec::new(), "{} has no errors", file_name);
+        assert_eq!(file.root.to_string(), text, "{} prints back byte-for-byte", file_name);
+        assert_eq!(file.root.end(), text.len());
+        check_spans(&file.root);
+        for token in file.root.tokens() {
+            assert_eq!(&text[token.token_pos()..token.end()], token.token_text());
+        }
+        count += 1;
+    }
+    assert!(count > 0);
+}
@@ -0,0 +1,115 @@
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{find, parse_without_errors, shape};
+
+// Shape of the expression of the single expression statement of text
+fn expression(text: &str) -> String {
+    let file = parse_without_errors(text);
+    let statement = find(&file.root, SyntaxKind::ExpressionStatement).unwrap();
+    shape(&statement.first_child().unwrap())
+}
+
+#[test]
+fn nested_type_arguments_in_types() {
+    let file = parse_without_errors("var a: Array<Array<number>>;\nvar m: Map<string, List<T>>;");
+    let types: Vec<String> = file
+        .root
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::VariableDeclaration)
+        .map(|node| shape(&node.last_child().unwrap()))
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            "TypeReference(Array < TypeReference(Array < number >) >)",
+            "TypeReference(Map < string , TypeReference(List < TypeReference(T) >) >)",
+        ]
+    );
+}
+
+#[test]
+fn greater_than_is_rescanned_in_expressions() {
+    assert_eq!(expression("a >> b"), "BinaryExpression(a >> b)");
+    assert_eq!(expression("a >>> b"), "BinaryExpression(a >>> b)");
+    assert_eq!(expression("a >= b"), "BinaryExpression(a >= b)");
+    assert_eq!(expression("a >>= b"), "BinaryExpression(a >>= b)");
+    assert_eq!(expression("a >>>= b"), "BinaryExpression(a >>>= b)");
+    assert_eq!(expression("a > b"), "BinaryExpression(a > b)");
+}
+
+#[test]
+fn call_with_type_arguments() {
+    assert_eq!(expression("f<T>(x)"), "CallExpression(f < TypeReference(T) > ( x ))");
+    assert_eq!(
+        expression("f<string, Array<number>>(x, y)"),
+        "CallExpression(f < string , TypeReference(Array < number >) > ( x , y ))"
+    );
+    assert_eq!(
+        expression("a.b<number[]>()"),
+        "CallExpression(PropertyAccess(a . b) < ArrayType(number [ ]) > ( ))"
+    );
+    assert_eq!(
+        expression("f<T>(x)<U>(y)"),
+        "CallExpression(CallExpression(f < TypeReference(T) > ( x )) < TypeReference(U) > ( y ))"
+    );
+    assert_eq!(
+        expression("new Foo<number>(1)"),
+        "NewExpression(new Foo < number > ( 1 ))"
+    );
+}
+
+// Like TypeScript, `a < b > (c)` is a call when `b` is a type followed by `(`
+#[test]
+fn type_arguments_followed_by_paren_are_a_call() {
+    assert_eq!(
+        expression("a < b > (c)"),
+        "CallExpression(a < TypeReference(b) > ( c ))"
+    );
+    assert_eq!(
+        expression("a < b, c > (d)"),
+        "CallExpression(a < TypeReference(b) , TypeReference(c) > ( d ))"
+    );
+}
+
+#[test]
+fn comparisons_that_look_like_type_arguments() {
+    assert_eq!(expression("a < b > c"), "BinaryExpression(BinaryExpression(a < b) > c)");
+    assert_eq!(
+        expression("a < 1 > (c)"),
+        "BinaryExpression(BinaryExpression(a < 1) > ParenExpression(( c )))"
+    );
+    assert_eq!(
+        expression("a < b + 1 > (c)"),
+        "BinaryExpression(BinaryExpression(a < BinaryExpression(b + 1)) > ParenExpression(( c )))"
+    );
+    assert_eq!(
+        expression("a < b >> (c)"),
+        "BinaryExpression(a < BinaryExpression(b >> ParenExpression(( c ))))"
+    );
+    assert_eq!(
+        expression("a < b >= (c)"),
+        "BinaryExpression(BinaryExpression(a < b) >= ParenExpression(( c )))"
+    );
+    assert_eq!(
+        expression("a < b > c(d)"),
+        "BinaryExpression(BinaryExpression(a < b) > CallExpression(c ( d )))"
+    );
+    assert_eq!(
+        expression("a < b && c > (d)"),
+        "BinaryExpression(BinaryExpression(a < b) && BinaryExpression(c > ParenExpression(( d ))))"
+    );
+    assert_eq!(
+        expression("f(a < b, c > d)"),
+        "CallExpression(f ( BinaryExpression(a < b) , BinaryExpression(c > d) ))"
+    );
+    assert_eq!(expression("a < b"), "BinaryExpression(a < b)");
+}
+
+#[test]
+fn new_without_arguments_after_comparison() {
+    assert_eq!(
+        expression("new Foo < b > c"),
+        "BinaryExpression(BinaryExpression(NewExpression(new Foo) < b) > c)"
+    );
+}
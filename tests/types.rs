@@ -0,0 +1,109 @@
+use typeshell::compiler::ast::{AstNode, SignatureDeclaration, TypeMember, TypeNode, VariableDeclaration};
+use typeshell::compiler::printer::{print_node, PrinterOptions};
+use typeshell::compiler::types::SyntaxKind;
+
+mod common;
+use common::{errors, parse, parse_without_errors, shape};
+
+// The type annotation of `var x: <type>;`
+fn annotation(type_text: &str) -> TypeNode {
+    let text = format!("var x: {};", type_text);
+    let file = parse_without_errors(&text);
+    let declaration = file.root.descendants().find_map(VariableDeclaration::cast).unwrap();
+    declaration.type_annotation().unwrap()
+}
+
+#[test]
+fn type_references_and_queries() {
+    assert_eq!(
+        shape(annotation("A.B.C<string, D[]>").syntax()),
+        "TypeReference(QualifiedName(QualifiedName(A . B) . C) < string , ArrayType(TypeReference(D) [ ]) >)"
+    );
+    assert_eq!(
+        shape(annotation("typeof a.b").syntax()),
+        "TypeQuery(typeof QualifiedName(a . b))"
+    );
+    assert_eq!(
+        shape(annotation("number[][]").syntax()),
+        "ArrayType(ArrayType(number [ ]) [ ])"
+    );
+}
+
+#[test]
+fn type_literals() {
+    let literal = match annotation("{ a: string; b?(x): void; (y: number): string; new (z): I; [k: string]: any, }") {
+        TypeNode::TypeLiteral(literal) => literal,
+        type_node => panic!("{:?} is a type literal", type_node),
+    };
+    assert!(!literal.is_function_type());
+    let kinds: Vec<SyntaxKind> = literal.members().map(|member| member.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::Property,
+            SyntaxKind::Method,
+            SyntaxKind::CallSignature,
+            SyntaxKind::ConstructSignature,
+            SyntaxKind::IndexSignature,
+        ]
+    );
+    match literal.members().nth(4) {
+        Some(TypeMember::IndexSignature(signature)) => {
+            assert_eq!(signature.parameters().count(), 1);
+            assert_eq!(signature.type_annotation().unwrap().syntax().to_string(), " any");
+        }
+        member => panic!("{:?} is an index signature", member),
+    }
+    assert_eq!(shape(annotation("{}").syntax()), "TypeLiteral({ })");
+    // `new` names a member unless parameters follow
+    assert_eq!(
+        shape(annotation("{ new: number; new?(): void }").syntax()),
+        "TypeLiteral({ Property(new : number ;) Method(new ? ( ) : void) })"
+    );
+}
+
+#[test]
+fn function_and_constructor_types() {
+    let literal = match annotation("<T>(a: T, ...b: any[]) => (c) => T[]") {
+        TypeNode::TypeLiteral(literal) => literal,
+        type_node => panic!("{:?} is a type literal", type_node),
+    };
+    assert!(literal.is_function_type());
+    let signature = match literal.members().next() {
+        Some(TypeMember::CallSignature(signature)) => signature,
+        member => panic!("{:?} is a call signature", member),
+    };
+    assert_eq!(signature.type_parameters().count(), 1);
+    assert_eq!(signature.parameters().count(), 2);
+    assert_eq!(
+        shape(signature.return_type().unwrap().syntax()),
+        "TypeLiteral(CallSignature(( Parameter(c) ) => ArrayType(TypeReference(T) [ ])))"
+    );
+    assert_eq!(
+        shape(annotation("new () => Shell").syntax()),
+        "TypeLiteral(ConstructSignature(new ( ) => TypeReference(Shell)))"
+    );
+}
+
+#[test]
+fn annotations_everywhere() {
+    let text = "function f(cb: (err: any) => void, t: typeof cb): { [k: string]: number } { return null; }\n\
+                class C { p: new () => C; m(a: { x: number }): () => void { return null; } }\n\
+                interface I { (a: string): number; new (): I; [i: number]: string; f: (x) => void; }\n\
+                var g = (x: { a: number }): Array<() => void> => null;\n\
+                var h = <(a: number) => string>null;\n";
+    let file = parse_without_errors(text);
+    let printed = print_node(&file.root, &PrinterOptions::default());
+    let reprinted = parse(&printed);
+    assert_eq!(reprinted.parse_diagnostics, Vec::new(), "{}", printed);
+    assert_eq!(print_node(&reprinted.root, &PrinterOptions::default()), printed);
+    assert!(printed.contains("function f(cb: (err: any) => void, t: typeof cb): { [k: string]: number; } {"));
+    assert!(printed.contains("    p: new () => C;"));
+    assert!(printed.contains("    new (): I;"));
+}
+
+#[test]
+fn type_errors_recover() {
+    let file = parse("var a: (x: number);\nvar b: { a: ; };\nvar c = 1;");
+    assert_eq!(errors(&file), vec![(18, "'=>' expected."), (32, "Type expected.")]);
+}
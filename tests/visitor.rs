@@ -0,0 +1,115 @@
+use typeshell::compiler::ast::{
+    AstNode, DebuggerStatement, FunctionDeclaration, Identifier, KeywordType, ModifiersOwner, VariableStatement,
+};
+use typeshell::compiler::parse::create_source_file;
+use typeshell::compiler::syntax::{GreenNode, SyntaxNode};
+use typeshell::compiler::types::SyntaxKind;
+use typeshell::compiler::visit::{fold, fold_children, for_each_child, walk, Fold, Visitor};
+
+const TEXT: &str =
+    "function f(a: number) {\n    function g() { return void a; }\n}\nvar x: string = f(1);\ndebugger;\n";
+
+#[derive(Default)]
+struct Collector {
+    identifiers: Vec<String>,
+    functions: Vec<String>,
+    keyword_types: Vec<String>,
+    tokens: Vec<SyntaxKind>,
+}
+
+impl Visitor for Collector {
+    fn visit_identifier(&mut self, node: &Identifier) {
+        self.identifiers.push(node.text().to_string());
+    }
+
+    fn visit_function_declaration(&mut self, node: &FunctionDeclaration) {
+        self.functions
+            .push(node.syntax().child(1).unwrap().token_text().to_string());
+        walk(self, node.syntax());
+    }
+
+    fn visit_keyword_type(&mut self, node: &KeywordType) {
+        self.keyword_types.push(node.syntax().token_text().to_string());
+    }
+
+    fn visit_token(&mut self, token: &SyntaxNode) {
+        self.tokens.push(token.kind());
+    }
+}
+
+#[test]
+fn for_each_child_flattens_lists() {
+    let file = create_source_file("test.ts", TEXT);
+    let mut kinds = Vec::new();
+    for_each_child(&file.root, &mut |child| kinds.push(child.kind()));
+    assert_eq!(
+        kinds,
+        vec![
+            SyntaxKind::FunctionDeclaration,
+            SyntaxKind::VariableStatement,
+            SyntaxKind::DebuggerStatement,
+            SyntaxKind::EndOfFileToken
+        ]
+    );
+}
+
+#[test]
+fn visitor_walks_the_whole_tree() {
+    let file = create_source_file("test.ts", TEXT);
+    let mut collector = Collector::default();
+    collector.visit_node(&file.root);
+    assert_eq!(collector.identifiers, vec!["f", "a", "g", "a", "x", "f"]);
+    assert_eq!(collector.functions, vec!["f", "g"]);
+    // `void a` is an operator, `: number` and `: string` are types
+    assert_eq!(collector.keyword_types, vec!["number", "string"]);
+    assert!(collector.tokens.contains(&SyntaxKind::VoidKeyword));
+    assert!(!collector.tokens.contains(&SyntaxKind::Identifier));
+    assert_eq!(collector.tokens.last(), Some(&SyntaxKind::EndOfFileToken));
+}
+
+// Renames identifiers, removes debugger statements and declares a variable in front of every
+// exported variable statement
+struct Transformer;
+
+impl Fold for Transformer {
+    fn fold_identifier(&mut self, node: &Identifier) -> Vec<GreenNode> {
+        let green = node.syntax().green();
+        let name = format!("_{}", node.text());
+        vec![GreenNode::new_token(
+            SyntaxKind::Identifier,
+            green.leading_trivia().to_vec(),
+            name.clone(),
+            name,
+        )]
+    }
+
+    fn fold_debugger_statement(&mut self, _node: &DebuggerStatement) -> Vec<GreenNode> {
+        Vec::new()
+    }
+
+    fn fold_variable_statement(&mut self, node: &VariableStatement) -> Vec<GreenNode> {
+        let folded = fold_children(self, node.syntax());
+        if !node.has_modifier(SyntaxKind::ExportKeyword) {
+            return vec![folded];
+        }
+        let inserted = create_source_file("inserted.ts", "\nvar inserted;");
+        let statement = inserted.root.first_child().unwrap().first_child().unwrap();
+        vec![folded, statement.green().clone()]
+    }
+}
+
+#[test]
+fn fold_replaces_removes_and_inserts() {
+    let file = create_source_file("test.ts", "var a = b;\ndebugger;\nexport var c;\n");
+    let folded = fold(&mut Transformer, &file.root);
+    assert_eq!(folded.to_string(), "var _a = _b;\nexport var _c;\nvar inserted;\n");
+}
+
+#[test]
+fn fold_without_changes_shares_the_tree() {
+    struct Identity;
+    impl Fold for Identity {}
+    let file = create_source_file("test.ts", TEXT);
+    let folded = fold(&mut Identity, &file.root);
+    assert!(folded.green().ptr_eq(file.root.green()));
+}